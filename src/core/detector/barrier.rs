@@ -0,0 +1,190 @@
+//! Barrier tracking and integration with the Deloxide detector
+//!
+//! This module defines all the Barrier-related hooks and Detector methods needed for
+//! deadlock detection and logging of barrier rendezvous operations. A barrier has no
+//! single "owner" the way a mutex does, so waiting threads are instead modeled as
+//! waiting on the first thread to arrive for the current generation: later arrivals
+//! add a wait-for edge to that thread, the same way a mutex waiter is modeled as
+//! waiting on the current owner. This only reports a deadlock when that pseudo-owner
+//! is itself, directly or transitively, blocked on one of the later arrivals - i.e.
+//! when the barrier can provably never fill.
+
+use crate::core::detector::GLOBAL_DETECTOR;
+use crate::core::logger;
+use crate::core::types::DeadlockInfo;
+use crate::core::{Detector, Events, get_current_thread_id};
+use crate::{LockId, ThreadId};
+use fxhash::FxHashSet;
+
+impl Detector {
+    /// Register a barrier creation
+    ///
+    /// # Arguments
+    /// * `barrier_id` - ID of the created barrier
+    /// * `parties` - Number of threads required to release a `wait()` call
+    pub fn create_barrier(&mut self, barrier_id: LockId, parties: usize) {
+        self.barrier_parties.insert(barrier_id, parties);
+        self.barrier_waiters.insert(barrier_id, FxHashSet::default());
+
+        logger::log_lock_event(barrier_id, Some(get_current_thread_id()), Events::BarrierSpawn);
+    }
+
+    /// Register barrier destruction
+    ///
+    /// # Arguments
+    /// * `barrier_id` - ID of the barrier being destroyed
+    pub fn destroy_barrier(&mut self, barrier_id: LockId) {
+        self.barrier_parties.remove(&barrier_id);
+        if let Some(waiters) = self.barrier_waiters.remove(&barrier_id) {
+            for thread_id in waiters {
+                self.thread_waits_for.remove(&thread_id);
+            }
+        }
+        self.barrier_owner.remove(&barrier_id);
+
+        logger::log_lock_event(barrier_id, None, Events::BarrierExit);
+    }
+
+    /// Register the beginning of a barrier wait
+    ///
+    /// The first thread to arrive for a generation becomes the barrier's pseudo-owner.
+    /// Every later arrival is modeled as waiting for that pseudo-owner, so the normal
+    /// wait-for cycle detection flags a stuck barrier exactly when the pseudo-owner is
+    /// itself blocked (directly or transitively) on one of the threads still to arrive.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread beginning to wait
+    /// * `barrier_id` - ID of the barrier being waited on
+    ///
+    /// # Returns
+    /// `Some(cycle)` if this arrival completes a wait-for cycle, `None` otherwise
+    pub fn barrier_wait_begin(
+        &mut self,
+        thread_id: ThreadId,
+        barrier_id: LockId,
+    ) -> Option<Vec<ThreadId>> {
+        logger::log_interaction_event(thread_id, barrier_id, Events::BarrierWaitBegin);
+
+        let waiters = self.barrier_waiters.entry(barrier_id).or_default();
+        let is_first_arrival = waiters.is_empty();
+        waiters.insert(thread_id);
+
+        if is_first_arrival {
+            self.barrier_owner.insert(barrier_id, thread_id);
+            return None;
+        }
+
+        let owner = *self.barrier_owner.get(&barrier_id)?;
+        if owner == thread_id {
+            return None;
+        }
+
+        self.thread_waits_for.insert(thread_id, barrier_id);
+
+        if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, owner) {
+            let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+            if !filtered_cycle.is_empty() {
+                return Some(cycle);
+            }
+        }
+        None
+    }
+
+    /// Check whether a barrier can provably never fill
+    ///
+    /// Called from [`Detector::on_thread_exit`], since a thread's death is
+    /// the only moment this is provable: while any thread is still alive and
+    /// unaccounted for, it might still go on to call `wait()`, so checking on
+    /// every arrival would be racy against threads that simply haven't
+    /// spawned yet. Once a thread has actually exited, though, it can be
+    /// ruled out for good - so if every *remaining* live thread has already
+    /// arrived at this barrier (`self.thread_priority` has exactly one entry
+    /// per live thread - inserted in [`Detector::on_thread_spawn`], removed
+    /// in [`Detector::on_thread_exit`] - so its length equals the live-thread
+    /// count) yet fewer than the required number of parties have, the
+    /// missing parties died, took a different branch, or were never spawned,
+    /// and no thread exists anymore that could still call `wait()`.
+    ///
+    /// This deliberately does not use `self.wait_for_graph.edges.len()` for
+    /// the live-thread count: that map loses an entry for every thread that
+    /// has ever contended for *any* tracked lock and gone on to acquire it
+    /// (see [`crate::core::graph::WaitForGraph::clear_wait_edges`]/
+    /// `remove_thread`, which delete the entry entirely rather than leaving
+    /// an empty placeholder), so its length silently undercounts live
+    /// threads that are simply not blocked on anything right now.
+    pub(crate) fn check_barrier_starvation(&self, barrier_id: LockId) -> Option<DeadlockInfo> {
+        let parties = *self.barrier_parties.get(&barrier_id)?;
+        let waiters = self.barrier_waiters.get(&barrier_id)?;
+        if waiters.is_empty() || waiters.len() >= parties || waiters.len() != self.thread_priority.len()
+        {
+            return None;
+        }
+        let missing = parties - waiters.len();
+        let arrived = waiters.iter().copied().collect();
+        Some(self.extract_barrier_starvation_info(barrier_id, arrived, missing))
+    }
+
+    /// Register the end of a barrier wait
+    ///
+    /// Called by every waiting thread once it is released, whether it was the
+    /// last arrival (the leader) or was woken up by the leader filling the barrier.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread whose wait is ending
+    /// * `barrier_id` - ID of the barrier that was waited on
+    pub fn barrier_wait_end(&mut self, thread_id: ThreadId, barrier_id: LockId) {
+        logger::log_interaction_event(thread_id, barrier_id, Events::BarrierWaitEnd);
+
+        self.thread_waits_for.remove(&thread_id);
+
+        if let Some(waiters) = self.barrier_waiters.get_mut(&barrier_id) {
+            waiters.remove(&thread_id);
+            if waiters.is_empty() {
+                self.barrier_owner.remove(&barrier_id);
+            }
+        }
+    }
+}
+
+/// Register a barrier creation with the global detector
+///
+/// # Arguments
+/// * `barrier_id` - ID of the created barrier
+/// * `parties` - Number of threads required to release a `wait()` call
+pub fn create_barrier(barrier_id: LockId, parties: usize) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.create_barrier(barrier_id, parties);
+}
+
+/// Register barrier destruction with the global detector
+///
+/// # Arguments
+/// * `barrier_id` - ID of the barrier being destroyed
+pub fn destroy_barrier(barrier_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.destroy_barrier(barrier_id);
+}
+
+/// Register the beginning of a barrier wait with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread beginning to wait
+/// * `barrier_id` - ID of the barrier being waited on
+///
+/// # Returns
+/// `Some(DeadlockInfo)` if this arrival completes a wait-for cycle, `None` otherwise
+pub fn wait_begin(thread_id: ThreadId, barrier_id: LockId) -> Option<DeadlockInfo> {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    let cycle = detector.barrier_wait_begin(thread_id, barrier_id);
+    cycle.map(|cycle| detector.extract_deadlock_info(cycle))
+}
+
+/// Register the end of a barrier wait with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread whose wait is ending
+/// * `barrier_id` - ID of the barrier that was waited on
+pub fn wait_end(thread_id: ThreadId, barrier_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.barrier_wait_end(thread_id, barrier_id);
+}
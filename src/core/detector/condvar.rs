@@ -4,9 +4,10 @@
 //! deadlock detection and logging of Condvar operations (wait, notify). It bridges
 //! condvar operations with mutex operations to ensure correct cycle detection.
 
-use crate::core::detector::GLOBAL_DETECTOR;
-use crate::core::types::{CondvarId, LockId, ThreadId};
+use crate::core::detector::{GLOBAL_DETECTOR, deadlock_handling};
+use crate::core::types::{CondvarId, DeadlockInfo, LockId, ThreadId};
 use crate::core::{Detector, Events, get_current_thread_id};
+use fxhash::FxHashSet;
 use std::collections::VecDeque;
 
 impl Detector {
@@ -18,13 +19,13 @@ impl Detector {
         // Initialize the wait queue for this condvar
         self.cv_waiters.insert(condvar_id, VecDeque::new());
 
-        self.log_if_enabled(|logger| {
+        if let Some(logger) = &self.logger {
             logger.log_lock_event(
                 condvar_id,
                 Some(get_current_thread_id()),
                 Events::CondvarSpawn,
             );
-        });
+        }
     }
 
     /// Register condvar destruction
@@ -37,7 +38,7 @@ impl Detector {
 
         // Clear any thread wait mappings for this condvar
         self.thread_wait_cv
-            .retain(|_, &mut (cv_id, _)| cv_id != condvar_id);
+            .retain(|_, &mut (cv_id, _, _)| cv_id != condvar_id);
 
         if let Some(logger) = &self.logger {
             logger.log_lock_event(condvar_id, None, Events::CondvarExit);
@@ -48,13 +49,38 @@ impl Detector {
     ///
     /// This method is called when a thread begins waiting on a condition variable.
     /// It tracks which threads are waiting on which condvars and which mutex they
-    /// will need to reacquire.
+    /// will need to reacquire, and releases the mutex in the detector's state in
+    /// the same `GLOBAL_DETECTOR` critical section.
+    ///
+    /// That release must happen here rather than via a second, separate call to
+    /// [`Detector::release_mutex`]: a thread must never be observable, however
+    /// briefly, as both still holding the mutex and not yet registered as
+    /// blocked on the condvar (or vice versa), since either gap could let a
+    /// concurrent wait-for edge or lock-order check run against a half-updated
+    /// state and build a stale edge.
     ///
     /// # Arguments
     /// * `thread_id` - ID of the thread beginning to wait
     /// * `condvar_id` - ID of the condition variable being waited on
     /// * `mutex_id` - ID of the mutex that will be reacquired after the wait
-    pub fn begin_wait(&mut self, thread_id: ThreadId, condvar_id: CondvarId, mutex_id: LockId) {
+    /// * `has_deadline` - Whether this wait came from `wait_timeout`/
+    ///   `wait_timeout_while` rather than `wait`/`wait_while`, and will
+    ///   therefore self-recover even if it's never notified; see
+    ///   [`Detector::check_condvar_stall`].
+    ///
+    /// # Returns
+    /// `Some(info)` if `thread_id` is still holding a lock that another
+    /// thread waiting on this same condvar is (transitively) blocked on -
+    /// see [`Detector::check_condvar_held_lock`] - or, failing that, if
+    /// this wait is the one that leaves every live thread blocked with no
+    /// possible notifier left - see [`Detector::check_condvar_stall`].
+    pub fn begin_wait(
+        &mut self,
+        thread_id: ThreadId,
+        condvar_id: CondvarId,
+        mutex_id: LockId,
+        has_deadline: bool,
+    ) -> Option<DeadlockInfo> {
         // Add thread to the wait queue for this condvar
         if let Some(queue) = self.cv_waiters.get_mut(&condvar_id) {
             queue.push_back((thread_id, mutex_id));
@@ -65,11 +91,124 @@ impl Detector {
 
         // Track what this thread is waiting for
         self.thread_wait_cv
-            .insert(thread_id, (condvar_id, mutex_id));
+            .insert(thread_id, (condvar_id, mutex_id, has_deadline));
 
         if let Some(logger) = &self.logger {
             logger.log_interaction_event(thread_id, condvar_id, Events::CondvarWaitBegin);
         }
+
+        // Release the mutex atomically alongside the bookkeeping above.
+        self.release_mutex(thread_id, mutex_id);
+
+        // `mutex_id` is gone now, but `thread_id` may still hold other locks
+        // across the wait - check those against everyone else already
+        // parked on this condvar before `thread_id` goes to sleep too.
+        self.check_condvar_held_lock(thread_id, condvar_id)
+            .or_else(|| self.check_condvar_stall())
+    }
+
+    /// Whether every currently-live thread is now blocked - parked on a
+    /// condvar (`thread_wait_cv`) or stuck attempting a lock
+    /// (`thread_waits_for`) - and at least one of the condvar waiters has no
+    /// deadline, so the `notify` it needs can now provably never be called:
+    /// every thread that could have called it is itself stuck.
+    ///
+    /// Unlike [`Detector::check_condvar_held_lock`], which only ever catches
+    /// a sleeper holding a lock some other *already-parked* thread needs,
+    /// this is the general case: the blocking threads on the other side of
+    /// the stall don't need to be parked on this same condvar, or even on a
+    /// condvar at all - they just need to account for every other live
+    /// thread between them.
+    ///
+    /// `self.thread_priority` has exactly one entry per live thread
+    /// (inserted by [`Detector::on_thread_spawn`], removed by
+    /// [`Detector::on_thread_exit`]); `self.wait_for_graph.edges` is not
+    /// used for the count, since its entries also disappear whenever a
+    /// thread's wait edges are cleared.
+    pub(crate) fn check_condvar_stall(&self) -> Option<DeadlockInfo> {
+        let live = self.thread_priority.len();
+        if live == 0 {
+            return None;
+        }
+
+        let mut blocked: FxHashSet<ThreadId> = self.thread_waits_for.keys().copied().collect();
+        blocked.extend(self.thread_wait_cv.keys().copied());
+
+        if blocked.len() != live {
+            return None;
+        }
+
+        // At least one condvar waiter must have no deadline - if every
+        // condvar waiter is in `wait_timeout`, the stall self-resolves once
+        // the shortest deadline passes, so it isn't a true deadlock yet.
+        if self
+            .thread_wait_cv
+            .values()
+            .all(|&(_, _, has_deadline)| has_deadline)
+        {
+            return None;
+        }
+
+        Some(self.extract_condvar_stall_info(blocked))
+    }
+
+    /// Check whether `thread_id`, about to sleep on `condvar_id`, still
+    /// holds a lock that some other thread already parked on the same
+    /// condvar is (transitively, via `wait_for_graph`) blocked waiting for
+    ///
+    /// This is the classic held-lock-across-wait hazard: if the only thread
+    /// that could ever call `notify` on this condvar is itself stuck
+    /// waiting for a lock `thread_id` is about to sleep while still
+    /// holding, neither thread can ever make progress, yet nothing in the
+    /// wait-for graph shows it - a condvar wait releases its own mutex but
+    /// leaves no wait-for edge behind for the sleeping thread.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread about to park on `condvar_id`
+    /// * `condvar_id` - ID of the condition variable being waited on
+    fn check_condvar_held_lock(
+        &mut self,
+        thread_id: ThreadId,
+        condvar_id: CondvarId,
+    ) -> Option<DeadlockInfo> {
+        let held_locks = self.thread_holds.get(&thread_id).cloned().unwrap_or_default();
+        if held_locks.is_empty() {
+            return None;
+        }
+
+        for (&other_thread, &(other_condvar, _, _)) in &self.thread_wait_cv {
+            if other_thread == thread_id || other_condvar != condvar_id {
+                continue;
+            }
+            // `other_thread` only has outgoing wait-for edges if it's
+            // currently blocked on a lock itself (e.g. re-attempting its
+            // mutex after being woken, but not yet cleared via `end_wait`).
+            if !self.wait_for_graph.edges.contains_key(&other_thread) {
+                continue;
+            }
+
+            let mut reachable = self.wait_for_graph.reachable_from(other_thread);
+            reachable.push(other_thread);
+
+            // `reachable` is every thread `other_thread` is transitively
+            // blocked behind. If any of them is directly waiting for a lock
+            // `thread_id` still holds, `thread_id` going to sleep now means
+            // that chain - and `other_thread` at the head of it - can never
+            // be woken.
+            for &blocked in &reachable {
+                if let Some(&lock_id) = self.thread_waits_for.get(&blocked)
+                    && held_locks.contains(&lock_id)
+                {
+                    return Some(self.extract_condvar_held_lock_info(
+                        thread_id,
+                        other_thread,
+                        lock_id,
+                    ));
+                }
+            }
+        }
+
+        None
     }
 
     /// Register a condvar notify_one operation
@@ -79,11 +218,13 @@ impl Detector {
     /// # Arguments
     /// * `condvar_id` - ID of the condition variable being notified
     /// * `notifier_id` - ID of the thread performing the notification
-    pub fn notify_one(&mut self, condvar_id: CondvarId, notifier_id: ThreadId) {
+    pub fn notify_one(&mut self, condvar_id: CondvarId, notifier_id: ThreadId) -> Vec<DeadlockInfo> {
         if let Some(logger) = &self.logger {
             logger.log_interaction_event(notifier_id, condvar_id, Events::CondvarNotifyOne);
         }
 
+        let mut deadlocks = Vec::new();
+
         // Wake one waiter if any exist
         if let Some(queue) = self.cv_waiters.get_mut(&condvar_id)
             && let Some((waiter_thread, mutex_id)) = queue.pop_front()
@@ -91,8 +232,10 @@ impl Detector {
             // Mark as woken (for diagnostics)
             self.cv_woken.insert(waiter_thread);
 
-            self.on_mutex_attempt_synthetic_immediate(waiter_thread, mutex_id);
+            self.on_mutex_attempt_synthetic_immediate(waiter_thread, mutex_id, &mut deadlocks);
         }
+
+        deadlocks
     }
 
     /// Register a condvar notify_all operation
@@ -102,7 +245,7 @@ impl Detector {
     /// # Arguments
     /// * `condvar_id` - ID of the condition variable being notified
     /// * `notifier_id` - ID of the thread performing the notification
-    pub fn notify_all(&mut self, condvar_id: CondvarId, notifier_id: ThreadId) {
+    pub fn notify_all(&mut self, condvar_id: CondvarId, notifier_id: ThreadId) -> Vec<DeadlockInfo> {
         if let Some(logger) = &self.logger {
             logger.log_interaction_event(notifier_id, condvar_id, Events::CondvarNotifyAll);
         }
@@ -115,12 +258,15 @@ impl Detector {
                 Vec::new()
             };
 
+        let mut deadlocks = Vec::new();
         for (waiter_thread, mutex_id) in waiters_to_wake {
             // Mark as woken (for diagnostics)
             self.cv_woken.insert(waiter_thread);
 
-            self.on_mutex_attempt_synthetic_immediate(waiter_thread, mutex_id);
+            self.on_mutex_attempt_synthetic_immediate(waiter_thread, mutex_id, &mut deadlocks);
         }
+
+        deadlocks
     }
 
     /// Register the end of a condvar wait operation
@@ -132,15 +278,39 @@ impl Detector {
     /// * `thread_id` - ID of the thread whose wait is ending
     /// * `condvar_id` - ID of the condition variable that was waited on
     /// * `mutex_id` - ID of the mutex that was reacquired
-    pub fn end_wait(&mut self, thread_id: ThreadId, condvar_id: CondvarId, _mutex_id: LockId) {
+    /// * `timed_out` - Whether the wait ended because its deadline expired rather
+    ///   than because the condvar was notified
+    pub fn end_wait(
+        &mut self,
+        thread_id: ThreadId,
+        condvar_id: CondvarId,
+        _mutex_id: LockId,
+        timed_out: bool,
+    ) {
         // Remove from thread wait tracking
         self.thread_wait_cv.remove(&thread_id);
 
         // Remove from woken set if present
         self.cv_woken.remove(&thread_id);
 
+        // A timed-out (or spuriously woken) wait never gets popped from the
+        // condvar's wait queue by a notify, so it must be pruned here.
+        // Otherwise a later notify_one/notify_all could pop this thread's
+        // stale entry and set up a synthetic wait-for edge for a wait that
+        // has already ended - incorrectly pulling a timed-out thread into a
+        // wait-for cycle it's no longer part of.
+        if let Some(queue) = self.cv_waiters.get_mut(&condvar_id) {
+            queue.retain(|&(t, _)| t != thread_id);
+        }
+
+        let event = if timed_out {
+            Events::CondvarWaitTimedOut
+        } else {
+            Events::CondvarWaitEnd
+        };
+
         if let Some(logger) = &self.logger {
-            logger.log_interaction_event(thread_id, condvar_id, Events::CondvarWaitEnd);
+            logger.log_interaction_event(thread_id, condvar_id, event);
         }
     }
 
@@ -153,8 +323,15 @@ impl Detector {
     /// # Note
     /// This method does NOT attempt actual lock acquisition - it only sets up
     /// wait-for edges and performs cycle detection. The actual acquisition will
-    /// happen when the woken thread calls the mutex wrapper's lock() method.
-    fn on_mutex_attempt_synthetic_immediate(&mut self, thread_id: ThreadId, lock_id: LockId) {
+    /// happen when the woken thread calls the mutex wrapper's lock() method. Any
+    /// deadlocks found are appended to `deadlocks` for the caller to process once
+    /// the global detector lock has been released.
+    fn on_mutex_attempt_synthetic_immediate(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+        deadlocks: &mut Vec<DeadlockInfo>,
+    ) {
         // Check for lock order violations (only if graph exists and holding other locks)
         #[cfg(feature = "lock-order-graph")]
         let lock_order_violation = if self.lock_order_graph.is_some()
@@ -164,8 +341,6 @@ impl Detector {
         } else {
             None
         };
-        #[cfg(not(feature = "lock-order-graph"))]
-        let _lock_order_violation: Option<Vec<LockId>> = None;
 
         if let Some(&owner) = self.mutex_owners.get(&lock_id) {
             // Mutex is owned - set up wait-for edge
@@ -177,7 +352,7 @@ impl Detector {
 
                 if !filtered_cycle.is_empty() {
                     // Real deadlock detected!
-                    self.handle_detected_deadlock(cycle);
+                    deadlocks.push(self.extract_deadlock_info(cycle));
                 }
             }
         }
@@ -185,7 +360,7 @@ impl Detector {
         // Report lock order violation if detected
         #[cfg(feature = "lock-order-graph")]
         if let Some(lock_cycle) = lock_order_violation {
-            self.handle_lock_order_violation(thread_id, lock_id, lock_cycle);
+            deadlocks.push(self.extract_lock_order_violation_info(thread_id, lock_id, lock_cycle));
         }
 
         // Keep thread in cv_woken set - it will be cleared when actual acquisition happens
@@ -216,9 +391,21 @@ pub fn destroy_condvar(condvar_id: CondvarId) {
 /// * `thread_id` - ID of the thread beginning to wait
 /// * `condvar_id` - ID of the condition variable being waited on
 /// * `mutex_id` - ID of the mutex that will be reacquired after the wait
-pub fn begin_wait(thread_id: ThreadId, condvar_id: CondvarId, mutex_id: LockId) {
+/// * `has_deadline` - Whether this wait came from `wait_timeout`/
+///   `wait_timeout_while` rather than `wait`/`wait_while`
+pub fn begin_wait(
+    thread_id: ThreadId,
+    condvar_id: CondvarId,
+    mutex_id: LockId,
+    has_deadline: bool,
+) {
     let mut detector = GLOBAL_DETECTOR.lock();
-    detector.begin_wait(thread_id, condvar_id, mutex_id);
+    let info = detector.begin_wait(thread_id, condvar_id, mutex_id, has_deadline);
+    drop(detector);
+
+    if let Some(info) = info {
+        deadlock_handling::process_deadlock(info);
+    }
 }
 
 /// Register a condvar notify_one with the global detector
@@ -227,8 +414,14 @@ pub fn begin_wait(thread_id: ThreadId, condvar_id: CondvarId, mutex_id: LockId)
 /// * `condvar_id` - ID of the condition variable being notified
 /// * `notifier_id` - ID of the thread performing the notification
 pub fn notify_one(condvar_id: CondvarId, notifier_id: ThreadId) {
-    let mut detector = GLOBAL_DETECTOR.lock();
-    detector.notify_one(condvar_id, notifier_id);
+    let deadlocks = {
+        let mut detector = GLOBAL_DETECTOR.lock();
+        detector.notify_one(condvar_id, notifier_id)
+    };
+
+    for info in deadlocks {
+        deadlock_handling::process_deadlock(info);
+    }
 }
 
 /// Register a condvar notify_all with the global detector
@@ -237,8 +430,14 @@ pub fn notify_one(condvar_id: CondvarId, notifier_id: ThreadId) {
 /// * `condvar_id` - ID of the condition variable being notified
 /// * `notifier_id` - ID of the thread performing the notification
 pub fn notify_all(condvar_id: CondvarId, notifier_id: ThreadId) {
-    let mut detector = GLOBAL_DETECTOR.lock();
-    detector.notify_all(condvar_id, notifier_id);
+    let deadlocks = {
+        let mut detector = GLOBAL_DETECTOR.lock();
+        detector.notify_all(condvar_id, notifier_id)
+    };
+
+    for info in deadlocks {
+        deadlock_handling::process_deadlock(info);
+    }
 }
 
 /// Register the end of a condvar wait with the global detector
@@ -247,7 +446,9 @@ pub fn notify_all(condvar_id: CondvarId, notifier_id: ThreadId) {
 /// * `thread_id` - ID of the thread whose wait is ending
 /// * `condvar_id` - ID of the condition variable that was waited on
 /// * `mutex_id` - ID of the mutex that was reacquired
-pub fn end_wait(thread_id: ThreadId, condvar_id: CondvarId, mutex_id: LockId) {
+/// * `timed_out` - Whether the wait ended because its deadline expired rather
+///   than because the condvar was notified
+pub fn end_wait(thread_id: ThreadId, condvar_id: CondvarId, mutex_id: LockId, timed_out: bool) {
     let mut detector = GLOBAL_DETECTOR.lock();
-    detector.end_wait(thread_id, condvar_id, mutex_id);
+    detector.end_wait(thread_id, condvar_id, mutex_id, timed_out);
 }
@@ -3,8 +3,15 @@ use crate::LockId;
 use crate::ThreadId;
 use crate::core::detector::DISPATCHER;
 use crate::core::logger;
+use crate::core::stacktrace;
+#[cfg(feature = "lock-order-graph")]
+use crate::core::types::LockOrderEdgeSite;
+use crate::core::types::Priority;
+use crate::core::types::ThreadLockSite;
+use crate::core::types::ThreadStall;
 use crate::core::{DeadlockSource, Detector};
 use chrono::Utc;
+use fxhash::FxHashSet;
 
 impl Detector {
     /// Filter a cycle by checking if all threads share a common lock
@@ -60,14 +67,77 @@ impl Detector {
         }
     }
 
+    /// Pick the thread to sacrifice to break a detected wait-for cycle, using
+    /// the registered recovery callback if one was set via
+    /// [`Detector::set_deadlock_recovery`], else [`Detector::default_victim`].
+    ///
+    /// Marks the chosen thread as abandoned (see [`Detector::should_abandon`])
+    /// and retracts its pending wait-for edge, the same way [`Detector::cancel_wait`]
+    /// does when a timed acquire gives up on its own.
+    pub fn select_and_abandon_victim(&mut self, info: &DeadlockInfo) {
+        let victim = crate::core::detector::RECOVERY
+            .get()
+            .and_then(|cb| cb(info))
+            .filter(|victim| info.thread_cycle.contains(victim))
+            .unwrap_or_else(|| self.default_victim(&info.thread_cycle));
+
+        self.abandoned_threads.insert(victim);
+        self.thread_waits_for.remove(&victim);
+        self.wait_for_graph.clear_wait_edges(victim);
+    }
+
+    /// Default victim-selection policy: the thread in `cycle` currently
+    /// holding the fewest locks, since it has the least unwinding to do once
+    /// it bails out.
+    ///
+    /// # Panics
+    /// Panics if `cycle` is empty; callers only reach this with a non-empty
+    /// [`DeadlockInfo::thread_cycle`].
+    pub fn default_victim(&self, cycle: &[ThreadId]) -> ThreadId {
+        *cycle
+            .iter()
+            .min_by_key(|&&t| self.thread_holds.get(&t).map_or(0, |held| held.len()))
+            .expect("cycle must be non-empty")
+    }
+
+    /// Alternative victim-selection policy: the thread in `cycle` with the
+    /// lowest [`ThreadId`], for deployments that want a simple, deterministic
+    /// tie-break independent of how many locks each thread happens to be
+    /// holding. Used by
+    /// [`crate::Deloxide::with_deadlock_recovery_lowest_thread_id`].
+    ///
+    /// # Panics
+    /// Panics if `cycle` is empty; callers only reach this with a non-empty
+    /// [`DeadlockInfo::thread_cycle`].
+    pub fn lowest_thread_id_victim(cycle: &[ThreadId]) -> ThreadId {
+        *cycle.iter().min().expect("cycle must be non-empty")
+    }
+
+    /// The stress-testing seed in effect right now, if any: an explicit
+    /// [`crate::core::StressConfig::seed`] this detector was configured
+    /// with, or - failing that - the seed a `StressMode::Pct` scheduler
+    /// auto-assigned itself from the OS RNG on first use. Used to populate
+    /// [`DeadlockInfo::stress_seed`] regardless of which constructor below
+    /// builds the report.
+    #[cfg(feature = "stress-test")]
+    fn active_stress_seed(&self) -> Option<u64> {
+        self.stress_config
+            .as_ref()
+            .and_then(|c| c.seed)
+            .or_else(crate::core::stress::active_pct_seed)
+    }
+
     pub fn extract_deadlock_info(&self, cycle: Vec<ThreadId>) -> DeadlockInfo {
         // Optimization: Only include wait-for edges for threads in the cycle.
         // This reduces the size of the info struct and speeds up verification.
-        let thread_waiting_for_locks = cycle
+        let thread_waiting_for_locks: Vec<(ThreadId, LockId)> = cycle
             .iter()
             .filter_map(|&t| self.thread_waits_for.get(&t).map(|&l| (t, l)))
             .collect();
 
+        let lock_sites = self.build_lock_sites(&cycle, &thread_waiting_for_locks);
+        let thread_vector_clocks = self.snapshot_vclocks(&cycle);
+
         DeadlockInfo {
             source: DeadlockSource::WaitForGraph,
             thread_cycle: cycle,
@@ -75,6 +145,111 @@ impl Detector {
             lock_order_cycle: None,
             timestamp: Utc::now().to_rfc3339(),
             verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites,
+            lock_order_sites: Vec::new(),
+            stalled_threads: Vec::new(),
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks,
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the per-thread lock acquisition sites for a cycle, if backtrace
+    /// capture is enabled (returns an empty vec otherwise)
+    ///
+    /// For cycle `[t0, t1, ..., tn-1]` where each `t_i` waits for `t_{i+1}`,
+    /// thread `t_i` holds the lock that `t_{i-1}` is waiting for.
+    fn build_lock_sites(
+        &self,
+        cycle: &[ThreadId],
+        thread_waiting_for_locks: &[(ThreadId, LockId)],
+    ) -> Vec<ThreadLockSite> {
+        let n = cycle.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let waiting_lock_of = |t: ThreadId| {
+            thread_waiting_for_locks
+                .iter()
+                .find(|&&(wt, _)| wt == t)
+                .map(|&(_, l)| l)
+        };
+
+        cycle
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &thread_id)| {
+                let waiting_lock = waiting_lock_of(thread_id)?;
+                let prev_thread = cycle[(i + n - 1) % n];
+                let held_lock = waiting_lock_of(prev_thread);
+
+                Some(ThreadLockSite {
+                    thread_id,
+                    held_lock,
+                    held_at: held_lock.and_then(|l| {
+                        self.lock_acquired_at
+                            .get(&l)
+                            .and_then(|&id| stacktrace::format_stacktrace(id))
+                    }),
+                    held_backtrace: held_lock.and_then(|l| {
+                        self.lock_acquired_at
+                            .get(&l)
+                            .and_then(|&id| stacktrace::format_full_stacktrace(id))
+                    }),
+                    waiting_lock,
+                    waiting_at: self
+                        .thread_waiting_at
+                        .get(&thread_id)
+                        .and_then(|&id| stacktrace::format_stacktrace(id)),
+                    waiting_backtrace: self
+                        .thread_waiting_at
+                        .get(&thread_id)
+                        .and_then(|&id| stacktrace::format_full_stacktrace(id)),
+                    waiting_lock_poisoned: self.is_lock_poisoned(waiting_lock),
+                })
+            })
+            .collect()
+    }
+
+    /// Build the `DeadlockInfo` for a thread that requested a lock it
+    /// already holds
+    ///
+    /// Unlike [`Detector::extract_deadlock_info`], this is never routed
+    /// through [`Detector::filter_cycle_by_common_locks`]: that filter treats
+    /// a lock shared by every thread in a cycle as proof the cycle is a false
+    /// positive, which is exactly backwards for a single thread waiting on a
+    /// lock it already owns, so the self-deadlock case is detected and
+    /// reported directly by the caller instead of going through the wait-for
+    /// graph's general cycle path.
+    pub fn extract_self_deadlock_info(&self, thread_id: ThreadId, lock_id: LockId) -> DeadlockInfo {
+        let cycle = vec![thread_id];
+        let thread_waiting_for_locks = vec![(thread_id, lock_id)];
+        let lock_sites = self.build_lock_sites(&cycle, &thread_waiting_for_locks);
+
+        DeadlockInfo {
+            source: DeadlockSource::SelfDeadlock,
+            thread_cycle: cycle,
+            thread_waiting_for_locks,
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites,
+            lock_order_sites: Vec::new(),
+            stalled_threads: Vec::new(),
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
         }
     }
 
@@ -86,6 +261,23 @@ impl Detector {
         lock_id: LockId,
         lock_cycle: Vec<LockId>,
     ) -> DeadlockInfo {
+        let lock_order_sites = self
+            .lock_order_graph
+            .as_ref()
+            .map(|graph| {
+                graph
+                    .edge_sites_for_cycle(&lock_cycle)
+                    .into_iter()
+                    .map(|(edge, site, conflicting_site)| LockOrderEdgeSite {
+                        before: edge.before,
+                        after: edge.after,
+                        site: site.and_then(stacktrace::format_stacktrace),
+                        conflicting_site: conflicting_site.and_then(stacktrace::format_stacktrace),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
         DeadlockInfo {
             source: DeadlockSource::LockOrderViolation,
             thread_cycle: vec![thread_id],
@@ -93,15 +285,340 @@ impl Detector {
             lock_order_cycle: Some(lock_cycle),
             timestamp: Utc::now().to_rfc3339(),
             verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites,
+            stalled_threads: Vec::new(),
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the `DeadlockInfo` for a lock whose owner is unwinding from a
+    /// panic while other threads are blocked waiting on it
+    ///
+    /// Unlike every other `DeadlockInfo`, this is never found via the
+    /// wait-for graph: a panicking thread was never waiting on anyone, so
+    /// its death can never complete a cycle, yet its waiters are stuck
+    /// exactly as if it had. `owner` becomes the lone entry in `thread_cycle`
+    /// and every thread still in `lock_waiters` for `lock_id` shows up as
+    /// `(waiter, lock_id)` in `thread_waiting_for_locks`, mirroring the
+    /// shape [`Detector::extract_self_deadlock_info`] uses for its own
+    /// single-thread case.
+    pub fn extract_abandoned_lock_info(
+        &self,
+        owner: ThreadId,
+        lock_id: LockId,
+        waiters: Vec<ThreadId>,
+        panic_message: Option<String>,
+    ) -> DeadlockInfo {
+        let thread_waiting_for_locks = waiters.into_iter().map(|w| (w, lock_id)).collect();
+
+        DeadlockInfo {
+            source: DeadlockSource::AbandonedLock,
+            thread_cycle: vec![owner],
+            thread_waiting_for_locks,
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites: Vec::new(),
+            stalled_threads: Vec::new(),
+            panic_message,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the `DeadlockInfo` for a priority-inversion hazard found in
+    /// [`Detector::check_priority_inversion`]
+    ///
+    /// Unlike every other `DeadlockInfo`, `thread_cycle` is left empty: no
+    /// thread here is actually stuck forever, so there is no cycle to
+    /// report, just the three-thread chain carried in `priority_chain`.
+    pub fn extract_priority_inversion_info(&self, chain: Vec<(ThreadId, Priority)>) -> DeadlockInfo {
+        DeadlockInfo {
+            source: DeadlockSource::PriorityInversion,
+            thread_cycle: Vec::new(),
+            thread_waiting_for_locks: Vec::new(),
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites: Vec::new(),
+            stalled_threads: Vec::new(),
+            panic_message: None,
+            priority_chain: chain,
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the `DeadlockInfo` for a barrier that can provably never fill,
+    /// found in [`Detector::check_barrier_starvation`]
+    ///
+    /// Like [`Detector::extract_priority_inversion_info`], this is detected
+    /// synchronously rather than via the wait-for graph, so there is no real
+    /// cycle: `thread_cycle` carries the threads that *did* arrive, and
+    /// `barrier_missing` carries how many more never will.
+    /// `thread_waiting_for_locks` pairs every arrived thread with
+    /// `barrier_id`, since all of them are collectively waiting on the
+    /// barrier itself to fill, the same way a mutex waiter's entry names the
+    /// lock it's blocked on.
+    pub fn extract_barrier_starvation_info(
+        &self,
+        barrier_id: LockId,
+        arrived: Vec<ThreadId>,
+        missing: usize,
+    ) -> DeadlockInfo {
+        let thread_waiting_for_locks = arrived.iter().map(|&t| (t, barrier_id)).collect();
+        DeadlockInfo {
+            source: DeadlockSource::BarrierStarvation,
+            thread_cycle: arrived,
+            thread_waiting_for_locks,
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites: Vec::new(),
+            stalled_threads: Vec::new(),
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: Some(missing),
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the `DeadlockInfo` for a held-lock-across-condvar-wait hazard
+    /// found in [`Detector::begin_wait`]
+    ///
+    /// Like [`Detector::extract_barrier_starvation_info`], this isn't a
+    /// wait-for-graph cycle in the usual sense: `waiter` is blocked on a lock
+    /// `sleeper` holds, but `sleeper` itself holds no wait-for edge while
+    /// parked on the condvar, so `thread_cycle` is just the two threads
+    /// directly implicated rather than a graph-traced path.
+    pub fn extract_condvar_held_lock_info(
+        &self,
+        sleeper: ThreadId,
+        waiter: ThreadId,
+        held_lock: LockId,
+    ) -> DeadlockInfo {
+        DeadlockInfo {
+            source: DeadlockSource::CondvarHeldLock,
+            thread_cycle: vec![sleeper, waiter],
+            thread_waiting_for_locks: vec![(waiter, held_lock)],
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites: Vec::new(),
+            stalled_threads: Vec::new(),
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the `DeadlockInfo` for a writer parked past the starvation
+    /// threshold, found in [`Detector::check_writer_starvation`]
+    ///
+    /// Like [`Detector::extract_barrier_starvation_info`], this is detected
+    /// on demand rather than via the wait-for graph - the writer isn't stuck
+    /// in a cycle, just perpetually preempted by readers - so `thread_cycle`
+    /// is the starved writer followed by the readers currently holding the
+    /// lock rather than a graph-traced path, and `stalled_threads` carries
+    /// just the one entry for how long the writer has been waiting.
+    pub fn extract_writer_starvation_info(
+        &self,
+        writer: ThreadId,
+        lock_id: LockId,
+        readers: Vec<ThreadId>,
+        waited: std::time::Duration,
+    ) -> DeadlockInfo {
+        let mut thread_cycle = vec![writer];
+        thread_cycle.extend(readers);
+
+        DeadlockInfo {
+            source: DeadlockSource::WriterStarvation,
+            thread_cycle,
+            thread_waiting_for_locks: vec![(writer, lock_id)],
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites: Vec::new(),
+            stalled_threads: vec![ThreadStall {
+                thread_id: writer,
+                blocked_ms: waited.as_millis() as u64,
+                blocked_on_condvar: false,
+                recoverable: false,
+            }],
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
+        }
+    }
+
+    /// Build the `DeadlockInfo` for a provable condvar notification stall,
+    /// found in [`Detector::check_condvar_stall`]
+    ///
+    /// Like [`Detector::extract_barrier_starvation_info`], this isn't a
+    /// wait-for-graph cycle: a parked condvar waiter holds no wait-for edge
+    /// of its own, so `thread_cycle` is just every currently-blocked thread
+    /// rather than a graph-traced path, and `stalled_threads` records which
+    /// of them are condvar waiters and whether each can still self-recover.
+    pub fn extract_condvar_stall_info(&self, blocked: FxHashSet<ThreadId>) -> DeadlockInfo {
+        let stalled_threads = blocked
+            .iter()
+            .map(|&thread_id| {
+                let (blocked_on_condvar, recoverable) = self
+                    .thread_wait_cv
+                    .get(&thread_id)
+                    .map(|&(_, _, has_deadline)| (true, has_deadline))
+                    .unwrap_or((false, false));
+                ThreadStall {
+                    thread_id,
+                    blocked_ms: 0,
+                    blocked_on_condvar,
+                    recoverable,
+                }
+            })
+            .collect();
+
+        DeadlockInfo {
+            source: DeadlockSource::CondvarNotificationStarvation,
+            thread_cycle: blocked.into_iter().collect(),
+            thread_waiting_for_locks: Vec::new(),
+            lock_order_cycle: None,
+            timestamp: Utc::now().to_rfc3339(),
+            verification_request: None,
+            #[cfg(feature = "distributed")]
+            distributed_cycle: None,
+            lock_sites: Vec::new(),
+            lock_order_sites: Vec::new(),
+            stalled_threads,
+            panic_message: None,
+            priority_chain: Vec::new(),
+            barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+            #[cfg(feature = "stress-test")]
+            stress_seed: self.active_stress_seed(),
         }
     }
 }
 
+/// Build a `DeadlockInfo` for a cross-process cycle reported by the
+/// distributed coordinator
+///
+/// Unlike [`Detector::extract_deadlock_info`], the coordinator's cycle carries
+/// no lock-ownership details, so `thread_waiting_for_locks` is left empty;
+/// `thread_cycle` is filtered down to just this process's threads, while
+/// `distributed_cycle` carries the full cross-process cycle.
+#[cfg(feature = "distributed")]
+pub fn extract_distributed_deadlock_info(
+    local_process_id: crate::core::types::ProcessId,
+    node_cycle: Vec<(crate::core::types::ProcessId, ThreadId)>,
+) -> DeadlockInfo {
+    let thread_cycle = node_cycle
+        .iter()
+        .filter(|&&(pid, _)| pid == local_process_id)
+        .map(|&(_, tid)| tid)
+        .collect();
+
+    DeadlockInfo {
+        source: DeadlockSource::WaitForGraph,
+        thread_cycle,
+        thread_waiting_for_locks: Vec::new(),
+        lock_order_cycle: None,
+        timestamp: Utc::now().to_rfc3339(),
+        verification_request: None,
+        distributed_cycle: Some(node_cycle),
+        lock_sites: Vec::new(),
+        lock_order_sites: Vec::new(),
+        stalled_threads: Vec::new(),
+        panic_message: None,
+        priority_chain: Vec::new(),
+        barrier_missing: None,
+        thread_vector_clocks: Vec::new(),
+        #[cfg(feature = "stress-test")]
+        stress_seed: crate::core::stress::active_pct_seed(),
+    }
+}
+
 /// Process a detected deadlock (log and dispatch callback)
 ///
 /// This function should be called OUTSIDE the global detector lock
 /// to avoid holding the lock while formatting messages or waiting for callbacks.
+///
+/// For a `DeadlockSource::LockOrderViolation`, this is also where the
+/// configured [`crate::core::types::LockOrderViolationPolicy`] is applied:
+/// `Panic`/`Abort` crash synchronously here, before the callback/log ever
+/// run, so the offending thread's `lock()` call never returns.
 pub fn process_deadlock(info: DeadlockInfo) {
+    #[cfg(feature = "lock-order-graph")]
+    if info.source == DeadlockSource::LockOrderViolation {
+        use crate::core::types::LockOrderViolationPolicy;
+
+        match crate::core::detector::lock_order_violation_policy() {
+            LockOrderViolationPolicy::LogOnly => {}
+            LockOrderViolationPolicy::Panic => panic!("{}", format_lock_order_violation(&info)),
+            LockOrderViolationPolicy::Abort => {
+                eprintln!("{}", format_lock_order_violation(&info));
+                std::process::abort();
+            }
+        }
+    }
+
+    // If a PCT stress scheduler provoked this, print the seed needed to
+    // replay the exact interleaving that produced it.
+    #[cfg(feature = "stress-test")]
+    if let Some(seed) = crate::core::stress::active_pct_seed() {
+        eprintln!("deloxide: deadlock detected under PCT stress scheduling, seed = {seed}");
+    }
+
+    // If recovery is enabled, pick and abandon a victim before the callback
+    // runs, so a supervisor callback observing `should_abandon` side effects
+    // (or simply racing the victim's own `lock_until` poll) sees them take
+    // effect as early as possible.
+    if info.source == DeadlockSource::WaitForGraph && !info.thread_cycle.is_empty() {
+        apply_deadlock_recovery(&info);
+    }
+
+    // If the parking_lot oracle is enabled, check whether its own independent
+    // scan corroborates this wait-for cycle; see `detector::oracle`.
+    #[cfg(feature = "parking-lot-oracle")]
+    if info.source == DeadlockSource::WaitForGraph {
+        crate::core::detector::oracle::cross_validate(&info);
+    }
+
     // Dispatch callback asynchronously
     DISPATCHER.send(info.clone());
 
@@ -109,6 +626,50 @@ pub fn process_deadlock(info: DeadlockInfo) {
     logger::log_deadlock(info);
 }
 
+/// Select and abandon a deadlock victim for `info`, if recovery is enabled.
+///
+/// Re-acquires the global detector lock separately from the one held while
+/// the cycle was detected, mirroring [`crate::core::detector::mutex::report_abandoned_lock`]'s
+/// pattern of doing post-detection bookkeeping outside that original
+/// critical section.
+fn apply_deadlock_recovery(info: &DeadlockInfo) {
+    if !crate::core::detector::recovery_configured() {
+        return;
+    }
+
+    crate::core::detector::GLOBAL_DETECTOR
+        .lock()
+        .select_and_abandon_victim(info);
+}
+
+/// Render a `LockOrderViolation`'s cycle and (if captured) its conflicting
+/// acquisition sites for a `LockOrderViolationPolicy::Panic`/`Abort` message
+#[cfg(feature = "lock-order-graph")]
+fn format_lock_order_violation(info: &DeadlockInfo) -> String {
+    let cycle = info
+        .lock_order_cycle
+        .as_ref()
+        .map(|cycle| format!("{cycle:?}"))
+        .unwrap_or_else(|| "<unknown>".to_string());
+
+    let mut message = format!("deloxide: lock order violation detected - cycle: {cycle}");
+    for site in &info.lock_order_sites {
+        if let Some(acquired_at) = &site.site {
+            message.push_str(&format!(
+                "\n  {} before {} first acquired at:\n{acquired_at}",
+                site.before, site.after
+            ));
+        }
+        if let Some(conflicting_at) = &site.conflicting_site {
+            message.push_str(&format!(
+                "\n  conflicting {} before {} first acquired at:\n{conflicting_at}",
+                site.after, site.before
+            ));
+        }
+    }
+    message
+}
+
 /// Verify if a reported deadlock is valid by checking current lock ownership
 ///
 /// This function performs "Immediate Edge Verification" to filter out stale edges
@@ -154,6 +715,13 @@ pub fn verify_deadlock_edges(
         return true;
     }
 
+    // A self-deadlock is detected synchronously from `thread_holds` at the
+    // acquisition call site, not derived from a wait-for graph cycle, so
+    // there's no incoming edge to verify here either.
+    if info.source == DeadlockSource::SelfDeadlock {
+        return true;
+    }
+
     // Find who is waiting for us in the cycle
     // The cycle is a list of threads [t1, t2, t3] where t1->t2->t3->t1
     let cycle_len = info.thread_cycle.len();
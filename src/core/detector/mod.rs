@@ -1,9 +1,17 @@
+pub mod barrier;
 pub mod condvar;
 pub mod deadlock_handling;
 pub mod mutex;
+#[cfg(feature = "parking-lot-oracle")]
+pub mod oracle;
 pub mod rwlock;
 mod stress;
 pub mod thread;
+mod watchdog;
+
+pub use watchdog::WatchdogConfig;
+#[cfg(feature = "parking-lot-oracle")]
+pub use oracle::OracleConfig;
 
 #[cfg(feature = "stress-test")]
 use crate::core::StressConfig;
@@ -14,8 +22,15 @@ use crate::core::graph::LockOrderGraph;
 use crate::core::graph::WaitForGraph;
 #[cfg(feature = "logging-and-visualization")]
 use crate::core::logger::{self, EventLogger};
+#[cfg(feature = "distributed")]
+use crate::core::distributed::DistributedClient;
 
-use crate::core::types::{CondvarId, DeadlockInfo, LockId, ThreadId};
+use crate::core::stacktrace::StackTraceId;
+#[cfg(feature = "lock-order-graph")]
+use crate::core::types::LockOrderViolationPolicy;
+use crate::core::types::{
+    CondvarId, DEFAULT_PRIORITY, DeadlockInfo, LockId, Priority, RwLockFairness, ThreadId,
+};
 #[cfg(feature = "logging-and-visualization")]
 use anyhow::Result;
 use fxhash::{FxHashMap, FxHashSet};
@@ -23,6 +38,7 @@ use parking_lot::Mutex;
 use std::collections::VecDeque;
 use std::sync::mpsc::{Sender, channel};
 use std::sync::{Arc, OnceLock};
+use std::time::Instant;
 
 /// Configuration for the deadlock detector
 pub struct DetectorConfig {
@@ -31,6 +47,10 @@ pub struct DetectorConfig {
     /// Enable lock order checking
     #[cfg(feature = "lock-order-graph")]
     pub check_lock_order: bool,
+    /// How to react to a detected lock order violation; see
+    /// [`LockOrderViolationPolicy`]
+    #[cfg(feature = "lock-order-graph")]
+    pub violation_policy: LockOrderViolationPolicy,
     /// Stress testing mode
     #[cfg(feature = "stress-test")]
     pub stress_mode: StressMode,
@@ -40,6 +60,22 @@ pub struct DetectorConfig {
     /// Logger for recording events
     #[cfg(feature = "logging-and-visualization")]
     pub logger: Option<EventLogger>,
+    /// Connection to a distributed deadlock detection coordinator
+    #[cfg(feature = "distributed")]
+    pub distributed_client: Option<Arc<DistributedClient>>,
+    /// Whether a new reader is modeled as blocked by a writer that's merely
+    /// queued; see [`RwLockFairness`]
+    pub rwlock_fairness: RwLockFairness,
+    /// Background watchdog that periodically scans for cycles and stalled
+    /// threads, independent of reactive lock-attempt checks
+    pub watchdog: Option<WatchdogConfig>,
+    /// Background parking_lot oracle that cross-validates reported cycles
+    /// against parking_lot's own independent `deadlock_detection`
+    #[cfg(feature = "parking-lot-oracle")]
+    pub oracle: Option<OracleConfig>,
+    /// Victim-selection callback for deadlock recovery, if enabled; see
+    /// [`crate::Deloxide::with_deadlock_recovery`]
+    pub recovery: Option<Box<dyn Fn(&DeadlockInfo) -> Option<ThreadId> + Send + Sync>>,
 }
 
 // Global dispatcher for asynchronous deadlock callback execution
@@ -54,6 +90,23 @@ lazy_static::lazy_static! {
 /// Stores the user-provided callback as `Arc<dyn Fn>` for thread-safe access.
 static CALLBACK: OnceLock<Arc<dyn Fn(DeadlockInfo) + Send + Sync>> = OnceLock::new();
 
+/// Optional recovery callback: given a detected cycle, picks which thread
+/// should be sacrificed to break it. Returning `None` (or leaving this unset)
+/// falls back to [`Detector::default_victim`]. See
+/// [`crate::Deloxide::with_deadlock_recovery`].
+static RECOVERY: OnceLock<Arc<dyn Fn(&DeadlockInfo) -> Option<ThreadId> + Send + Sync>> =
+    OnceLock::new();
+
+/// Whether a recovery callback has been registered, i.e. whether deadlock
+/// victims get selected and abandoned at all.
+///
+/// Cheap and lock-free, so the `lock_for`/`lock_until` hot path can check it
+/// before paying for the polling loop that lets a victim notice it's been
+/// abandoned (see [`crate::Mutex::lock_until`]).
+pub fn recovery_configured() -> bool {
+    RECOVERY.get().is_some()
+}
+
 /// Background dispatcher for asynchronous callback execution
 ///
 /// Runs a dedicated thread that receives deadlock events through a channel
@@ -106,12 +159,44 @@ impl Dispatcher {
 /// 3. When a lock is acquired or released, the graph is updated
 /// 4. Cycle detection is performed to identify potential deadlocks
 /// 5. When a cycle is detected, the deadlock callback is invoked
+///
+/// # Concurrency
+///
+/// Every tracked operation funnels through a single `GLOBAL_DETECTOR`
+/// mutex, so this struct serializes all lock traffic in the process. That's
+/// fine for the workloads Deloxide was built for, but it becomes a real
+/// contention point under lock-heavy parallel workloads (e.g. something like
+/// rayon-core or netstack3_sync), since independent, never-contending locks
+/// still fight over the same detector mutex.
+///
+/// Sharding the per-lock maps (`mutex_owners`, `thread_holds`,
+/// `thread_waits_for`) by `LockId`/`ThreadId` hash behind their own stripe
+/// locks, and keeping `wait_for_graph` behind a separate lock taken only
+/// when an edge is actually added, would fix this - but every field below is
+/// currently read and mutated together as one atomic unit by the slow paths
+/// in `mutex.rs`, `rwlock.rs`, `condvar.rs` and `barrier.rs` (e.g.
+/// `acquire_slow` reads `mutex_owners`, then writes `thread_waits_for` and
+/// `wait_for_graph` as a single step to decide whether a cycle just formed).
+/// Splitting that without reintroducing the same invariants one stripe at a
+/// time - and without a compiler and test suite to catch a broken lock
+/// ordering in the process - risks landing a real bug in the one piece of
+/// this crate that absolutely cannot afford one. Tracked as follow-up work
+/// rather than attempted as a single unverified rewrite.
 pub struct Detector {
     /// Graph representing which threads are waiting for which other threads
     wait_for_graph: WaitForGraph,
     /// Lock order graph for detecting lock ordering violations (only created if enabled)
     #[cfg(feature = "lock-order-graph")]
     lock_order_graph: Option<LockOrderGraph>,
+    /// How to react when [`Detector::check_lock_order_violation`] finds a
+    /// violation; see [`Deloxide::with_lock_order_violation_policy`](crate::Deloxide::with_lock_order_violation_policy)
+    #[cfg(feature = "lock-order-graph")]
+    violation_policy: LockOrderViolationPolicy,
+    /// Every `(before, after)` pair caught violating the recorded lock order
+    /// since the last [`Detector::take_lock_order_violations`], for a caller
+    /// that wants to poll rather than rely on the deadlock callback
+    #[cfg(feature = "lock-order-graph")]
+    lock_order_violations: Vec<(LockId, LockId)>,
     /// Maps threads to the locks they're attempting to acquire
     thread_waits_for: FxHashMap<ThreadId, LockId>,
     /// Tracks, for each thread, which locks it currently holds
@@ -119,23 +204,106 @@ pub struct Detector {
     /// Maps Mutexes to the threads that currently own them
     mutex_owners: FxHashMap<LockId, ThreadId>,
     /// Maps RwLock IDs to the set of readers (shared lock holders)
+    ///
+    /// This is the per-reader registry that lets a waiting writer in
+    /// [`Detector::acquire_write_slow`] add a wait-for edge to every current
+    /// reader individually rather than a single anonymous "someone is
+    /// reading" flag, so a multi-reader read-to-write upgrade cycle is
+    /// attributed to the exact reader threads involved instead of collapsing
+    /// to a generic two-node report. Like every other piece of per-lock
+    /// state this crate tracks (`mutex_owners`, `rwlock_writer`,
+    /// `thread_holds`, ...), the registry lives here on the detector behind
+    /// the global lock rather than as a lock-free structure on the `RwLock`
+    /// object itself - the detector already serializes all graph mutations,
+    /// so a second, independently-synchronized reader set on the lock would
+    /// just be another thing to keep consistent with this one for no
+    /// accuracy gain.
     rwlock_readers: FxHashMap<LockId, FxHashSet<ThreadId>>,
     /// Maps RwLock IDs to the current writer (if any)
     rwlock_writer: FxHashMap<LockId, ThreadId>,
+    /// Maps RwLock IDs to the current upgradable reader (at most one allowed per lock)
+    rwlock_upgradable: FxHashMap<LockId, ThreadId>,
+    /// Maps RwLock IDs to the set of threads currently blocked trying to
+    /// acquire the write lock, so a new reader can add a writer-preference
+    /// wait-for edge to them and respect queued-writer starvation avoidance
+    rwlock_write_waiters: FxHashMap<LockId, FxHashSet<ThreadId>>,
+    /// Maps a thread parked in `rwlock_write_waiters` to the lock it's
+    /// queued on, when it started waiting, and how many times a reader has
+    /// released that lock since; see [`Detector::check_writer_starvation`]
+    rwlock_writer_wait_start: FxHashMap<ThreadId, (LockId, Instant, usize)>,
+    /// Whether a new reader's `attempt_read` is modeled as blocked by a
+    /// writer merely queued in `rwlock_write_waiters`, or only by a writer
+    /// that actually holds the lock; see
+    /// [`crate::Deloxide::with_rwlock_fairness`]
+    rwlock_fairness: RwLockFairness,
+    /// Each thread's vector clock: its view, as of its own last Mutex or
+    /// RwLock acquisition, of how many times every thread it has causally
+    /// observed has released a lock. Bumped on every acquire and seeded into
+    /// a freshly spawned child from its parent's clock; see
+    /// [`Detector::record_vclock_acquire`], [`Detector::record_vclock_release`]
+    /// and [`Detector::happens_before`].
+    thread_vclocks: FxHashMap<ThreadId, FxHashMap<ThreadId, u64>>,
+    /// Each lock's stored vector clock as of its last release, merged into
+    /// the next acquirer's own clock so happens-before relations flow
+    /// through the lock the way Miri's `VClock` does.
+    lock_vclocks: FxHashMap<LockId, FxHashMap<ThreadId, u64>>,
     /// Maps condvar IDs to queues of waiting threads and their associated mutex IDs
     cv_waiters: FxHashMap<CondvarId, VecDeque<(ThreadId, LockId)>>,
-    /// Maps threads to the condvar and mutex they're waiting on
-    thread_wait_cv: FxHashMap<ThreadId, (CondvarId, LockId)>,
+    /// Maps threads to the condvar they're waiting on, the mutex they'll
+    /// reacquire, and whether this particular wait has a deadline (i.e. came
+    /// from `wait_timeout`/`wait_timeout_while` rather than `wait`/`wait_while`)
+    /// and will therefore self-recover even if it's never notified; see
+    /// [`Detector::check_condvar_stall`].
+    thread_wait_cv: FxHashMap<ThreadId, (CondvarId, LockId, bool)>,
     /// Set of threads that have been woken from condvar waits (for diagnostics)
     cv_woken: FxHashSet<ThreadId>,
     /// Maps locks to the set of threads waiting for them (for stale edge removal)
     lock_waiters: FxHashMap<LockId, FxHashSet<ThreadId>>,
+    /// Maps barrier IDs to the number of parties required to release them
+    barrier_parties: FxHashMap<LockId, usize>,
+    /// Maps barrier IDs to the set of threads currently blocked in `wait()`
+    barrier_waiters: FxHashMap<LockId, FxHashSet<ThreadId>>,
+    /// Maps barrier IDs to the first thread that started waiting on them this
+    /// generation; later arrivals are modeled as waiting on this thread, the
+    /// same way a mutex waiter is modeled as waiting on the current owner
+    barrier_owner: FxHashMap<LockId, ThreadId>,
     #[cfg(feature = "stress-test")]
     /// Stress testing mode
     stress_mode: StressMode,
     #[cfg(feature = "stress-test")]
     /// Stress testing configuration
     stress_config: Option<StressConfig>,
+    /// Connection to a distributed deadlock detection coordinator, if this
+    /// process was configured with `.with_coordinator(..)`
+    #[cfg(feature = "distributed")]
+    distributed_client: Option<Arc<DistributedClient>>,
+    /// Maps a held lock to the stack trace captured when it was acquired
+    /// (only populated when backtrace capture is enabled; see
+    /// [`crate::core::stacktrace`])
+    lock_acquired_at: FxHashMap<LockId, StackTraceId>,
+    /// Maps a lock to the stack trace captured when it was created (only
+    /// populated when backtrace capture is enabled), so a deadlock report can
+    /// point at the `Mutex::new`/`RwLock::new` call site, not just the
+    /// acquisition that's stuck
+    lock_created_at: FxHashMap<LockId, StackTraceId>,
+    /// Maps a thread to the stack trace captured when it started waiting for
+    /// a lock it hasn't acquired yet
+    thread_waiting_at: FxHashMap<ThreadId, StackTraceId>,
+    /// Set of locks (mutexes or rwlocks) a guard was dropped on while its
+    /// holder was unwinding from a panic, mirroring the per-instance
+    /// `poisoned` flag the locks themselves track, so a deadlock cycle can
+    /// report "this lock's last holder died" independently of each lock's
+    /// own `is_poisoned()`
+    poisoned_locks: FxHashSet<LockId>,
+    /// Maps threads to the priority they were spawned with (see
+    /// `crate::thread::Builder::priority`); threads with no entry are
+    /// treated as [`crate::core::types::DEFAULT_PRIORITY`]
+    thread_priority: FxHashMap<ThreadId, Priority>,
+    /// Threads chosen as the victim to break a detected deadlock cycle (see
+    /// [`Detector::select_and_abandon_victim`]) that haven't yet noticed via
+    /// [`Detector::should_abandon`]. A thread is removed from this set the
+    /// moment it's asked about, so it bails out exactly once per selection.
+    abandoned_threads: FxHashSet<ThreadId>,
 }
 
 impl Default for Detector {
@@ -153,19 +321,40 @@ impl Detector {
             wait_for_graph: WaitForGraph::new(),
             #[cfg(feature = "lock-order-graph")]
             lock_order_graph: None, // Not created by default
+            #[cfg(feature = "lock-order-graph")]
+            violation_policy: LockOrderViolationPolicy::LogOnly,
+            #[cfg(feature = "lock-order-graph")]
+            lock_order_violations: Vec::new(),
             thread_waits_for: FxHashMap::default(),
             thread_holds: FxHashMap::default(),
             mutex_owners: FxHashMap::default(),
             rwlock_readers: FxHashMap::default(),
             rwlock_writer: FxHashMap::default(),
+            rwlock_upgradable: FxHashMap::default(),
+            rwlock_write_waiters: FxHashMap::default(),
+            rwlock_writer_wait_start: FxHashMap::default(),
+            rwlock_fairness: RwLockFairness::default(),
+            thread_vclocks: FxHashMap::default(),
+            lock_vclocks: FxHashMap::default(),
             cv_waiters: FxHashMap::default(),
             thread_wait_cv: FxHashMap::default(),
             cv_woken: FxHashSet::default(),
             lock_waiters: FxHashMap::default(),
+            barrier_parties: FxHashMap::default(),
+            barrier_waiters: FxHashMap::default(),
+            barrier_owner: FxHashMap::default(),
             #[cfg(feature = "stress-test")]
             stress_mode: StressMode::None,
             #[cfg(feature = "stress-test")]
             stress_config: None,
+            #[cfg(feature = "distributed")]
+            distributed_client: None,
+            lock_acquired_at: FxHashMap::default(),
+            lock_created_at: FxHashMap::default(),
+            thread_waiting_at: FxHashMap::default(),
+            poisoned_locks: FxHashSet::default(),
+            thread_priority: FxHashMap::default(),
+            abandoned_threads: FxHashSet::default(),
         }
     }
 
@@ -181,25 +370,255 @@ impl Detector {
         CALLBACK.set(cb).ok();
     }
 
+    /// Set the callback used to pick a victim thread when a wait-for cycle is
+    /// detected, enabling deadlock recovery (see
+    /// [`crate::Deloxide::with_deadlock_recovery`]).
+    ///
+    /// # Arguments
+    /// * `callback` - Given the detected cycle, returns the `ThreadId` to
+    ///   sacrifice, or `None` to fall back to [`Detector::default_victim`]
+    pub fn set_deadlock_recovery<F>(&mut self, callback: F)
+    where
+        F: Fn(&DeadlockInfo) -> Option<ThreadId> + Send + Sync + 'static,
+    {
+        let cb: Arc<dyn Fn(&DeadlockInfo) -> Option<ThreadId> + Send + Sync> = Arc::new(callback);
+        RECOVERY.set(cb).ok();
+    }
+
+    /// Check whether `thread_id` was chosen as a deadlock victim and hasn't
+    /// been told yet.
+    ///
+    /// Consumes the flag: the second call for the same selection returns
+    /// `false`. Polled from [`crate::Mutex::lock_until`]'s wait loop so the
+    /// victim's blocked acquire can bail out instead of hanging.
+    pub fn should_abandon(&mut self, thread_id: ThreadId) -> bool {
+        self.abandoned_threads.remove(&thread_id)
+    }
+
+    /// Configure whether a new reader is modeled as blocked by a writer
+    /// that's merely queued, not just one that already holds the lock; see
+    /// [`RwLockFairness`] and [`crate::Deloxide::with_rwlock_fairness`]
+    pub fn set_rwlock_fairness(&mut self, fairness: RwLockFairness) {
+        self.rwlock_fairness = fairness;
+    }
+
+    /// Attach a connection to a distributed deadlock detection coordinator
+    ///
+    /// Once attached, the mutex slow-path forwards its wait-for edges to the
+    /// coordinator alongside the local detector (see [`Detector::acquire_slow`]).
+    #[cfg(feature = "distributed")]
+    pub fn set_distributed_client(&mut self, client: Arc<DistributedClient>) {
+        self.distributed_client = Some(client);
+    }
+
     /// Check for lock order violations when a thread attempts to acquire a lock
     #[cfg(feature = "lock-order-graph")]
     fn check_lock_order_violation(
         &mut self,
         thread_id: ThreadId,
         lock_id: LockId,
+    ) -> Option<Vec<LockId>> {
+        self.check_lock_order_violation_at(thread_id, lock_id, None)
+    }
+
+    /// Like [`Detector::check_lock_order_violation`], but reuses `site` - a
+    /// backtrace already captured at this acquisition's call site - as the
+    /// capture site for any new lock-order edge, instead of capturing a
+    /// second one. `None` falls back to capturing fresh (or recording no
+    /// site, if capture is disabled).
+    ///
+    /// Lets a reported violation point at the call sites that established
+    /// each conflicting ordering; see
+    /// [`crate::core::graph::LockOrderGraph::edge_sites_for_cycle`].
+    #[cfg(feature = "lock-order-graph")]
+    fn check_lock_order_violation_at(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+        site: Option<StackTraceId>,
     ) -> Option<Vec<LockId>> {
         // Only check if lock order graph is enabled
         let graph = self.lock_order_graph.as_mut()?;
+        let site = site.or_else(crate::core::stacktrace::capture);
+
+        // A fail-fast policy probes without recording: if the panic/abort it
+        // triggers is ever caught and the program keeps running, the same
+        // pair must be detected (and fail) again on its next attempt rather
+        // than being silently cached away. See
+        // [`LockOrderGraph::add_edge_at_with_record`].
+        let record = !matches!(
+            self.violation_policy,
+            LockOrderViolationPolicy::Panic | LockOrderViolationPolicy::Abort
+        );
 
         if let Some(held_locks) = self.thread_holds.get(&thread_id) {
             for &held_lock in held_locks {
-                if let Some(lock_cycle) = graph.add_edge(held_lock, lock_id) {
+                if let Some(lock_cycle) =
+                    graph.add_edge_at_with_record(held_lock, lock_id, site, record)
+                {
+                    self.lock_order_violations.push((held_lock, lock_id));
                     return Some(lock_cycle);
                 }
             }
         }
         None
     }
+
+    /// Drain every lock-order violation caught since the last call, as
+    /// `(before, after)` pairs: `before` is the lock this thread already held,
+    /// `after` the one it was attempting whose acquisition conflicts with an
+    /// order recorded by some other thread.
+    ///
+    /// An alternative to the deadlock callback (which also fires for
+    /// `DeadlockSource::LockOrderViolation`) for a caller that wants to poll
+    /// for violations at a moment of its own choosing; mirrors
+    /// [`Detector::check_deadlock`]'s on-demand relationship to the reactive
+    /// wait-for-graph check.
+    #[cfg(feature = "lock-order-graph")]
+    pub fn take_lock_order_violations(&mut self) -> Vec<(LockId, LockId)> {
+        std::mem::take(&mut self.lock_order_violations)
+    }
+
+    /// Audit every acquisition ordering observed so far for inversions,
+    /// using Tarjan's SCC algorithm
+    ///
+    /// Unlike [`Detector::take_lock_order_violations`], which only ever
+    /// drains pairs that were actually caught live (each a single edge that
+    /// would have closed a cycle at the moment it was attempted), this runs
+    /// a full graph pass over [`LockOrderGraph::detect_all_violations`] and
+    /// can surface a multi-lock inversion cycle even if no single attempt
+    /// ever triggered one - e.g. A->B and B->C recorded on one run, C->A
+    /// recorded on another, none of which individually looked like a
+    /// violation at insert time.
+    ///
+    /// # Returns
+    /// One `Vec<LockId>` per independent inverted group, or an empty `Vec`
+    /// if lock order checking isn't enabled (see
+    /// [`crate::Deloxide::with_lock_order_checking`]).
+    #[cfg(feature = "lock-order-graph")]
+    pub fn report_lock_order(&mut self) -> Vec<Vec<LockId>> {
+        self.lock_order_graph
+            .as_mut()
+            .map(|graph| graph.detect_all_violations())
+            .unwrap_or_default()
+    }
+
+    /// Configure how the detector reacts to a lock ordering violation; see
+    /// [`LockOrderViolationPolicy`]
+    #[cfg(feature = "lock-order-graph")]
+    pub fn set_lock_order_violation_policy(&mut self, policy: LockOrderViolationPolicy) {
+        self.violation_policy = policy;
+    }
+
+    /// The currently configured lock order violation policy
+    #[cfg(feature = "lock-order-graph")]
+    pub fn lock_order_violation_policy(&self) -> LockOrderViolationPolicy {
+        self.violation_policy
+    }
+
+    /// All threads transitively blocking `thread_id` in the wait-for graph
+    ///
+    /// Does not include `thread_id` itself.
+    pub fn reachable_from(&self, thread_id: ThreadId) -> Vec<ThreadId> {
+        self.wait_for_graph.reachable_from(thread_id)
+    }
+
+    /// Whether `thread_id` is currently part of a wait-for cycle
+    pub fn in_cycle(&mut self, thread_id: ThreadId) -> bool {
+        self.wait_for_graph.in_cycle(thread_id)
+    }
+
+    /// The threads `thread_id` is directly (not transitively) waiting for
+    pub fn blockers_of(&self, thread_id: ThreadId) -> Vec<ThreadId> {
+        self.wait_for_graph.blockers_of(thread_id)
+    }
+
+    /// Scan the whole wait-for graph right now for any cycle
+    ///
+    /// An on-demand oracle, independent of both the reactive check that runs
+    /// on every tracked lock attempt and the periodic [`crate::Deloxide::with_watchdog`]
+    /// scan: useful for a C caller that wants to poll for a deadlock at a
+    /// moment of its own choosing rather than waiting for a callback.
+    pub fn check_deadlock(&mut self) -> Option<Vec<ThreadId>> {
+        self.wait_for_graph.find_any_cycle()
+    }
+
+    /// Like [`Detector::check_deadlock`], but pairs each cycle thread with the
+    /// lock it's currently blocked attempting, the same pairing
+    /// [`Detector::extract_deadlock_info`] attaches to a reactively detected
+    /// [`DeadlockInfo::thread_waiting_for_locks`].
+    ///
+    /// # Returns
+    /// * `Some(pairs)` - The cycle, as `(thread_id, lock_id)` pairs
+    /// * `None` - No cycle currently exists in the graph
+    pub fn check_deadlock_with_locks(&mut self) -> Option<Vec<(ThreadId, LockId)>> {
+        let cycle = self.wait_for_graph.find_any_cycle()?;
+        Some(
+            cycle
+                .iter()
+                .filter_map(|&t| self.thread_waits_for.get(&t).map(|&l| (t, l)))
+                .collect(),
+        )
+    }
+
+    /// Find every independent deadlocked group in the wait-for graph right now
+    ///
+    /// Unlike [`Detector::check_deadlock`]/[`Detector::check_deadlock_with_locks`],
+    /// which stop at the first cycle found, this takes a complete snapshot
+    /// via [`crate::core::graph::WaitForGraph::detect_all_deadlocks`]: a live
+    /// system can have several independent deadlocks at once, and this
+    /// returns all of them.
+    ///
+    /// # Returns
+    /// One `Vec<ThreadId>` per deadlocked group, in no particular order
+    /// within the group; empty if the graph currently has no cycles.
+    pub fn detect_all_deadlocks(&mut self) -> Vec<Vec<ThreadId>> {
+        self.wait_for_graph.detect_all_deadlocks()
+    }
+
+    /// Record that a guard for `lock_id` was dropped while its thread was
+    /// panicking, mirroring the lock's own `poisoned` flag in the detector
+    /// so it can be surfaced in a `DeadlockInfo`
+    pub fn mark_poisoned(&mut self, lock_id: LockId) {
+        self.poisoned_locks.insert(lock_id);
+    }
+
+    /// Whether the detector has `lock_id` recorded as poisoned
+    pub fn is_lock_poisoned(&self, lock_id: LockId) -> bool {
+        self.poisoned_locks.contains(&lock_id)
+    }
+
+    /// Clear the poisoned record for `lock_id`, mirroring
+    /// `Mutex::clear_poison`/`RwLock::clear_poison`
+    pub fn clear_poisoned(&mut self, lock_id: LockId) {
+        self.poisoned_locks.remove(&lock_id);
+    }
+
+    /// Record the stack trace captured at `lock_id`'s creation, if backtrace
+    /// capture was enabled at the time; see [`Detector::create_mutex`] and
+    /// [`Detector::create_rwlock`](crate::core::detector::rwlock)
+    pub(crate) fn record_lock_created_at(&mut self, lock_id: LockId, site: Option<StackTraceId>) {
+        if let Some(site) = site {
+            self.lock_created_at.insert(lock_id, site);
+        }
+    }
+
+    /// The stack trace captured when `lock_id` was created, if backtrace
+    /// capture was enabled at that moment
+    pub fn lock_created_at(&self, lock_id: LockId) -> Option<StackTraceId> {
+        self.lock_created_at.get(&lock_id).copied()
+    }
+
+    /// The priority `thread_id` was spawned with, or
+    /// [`crate::core::types::DEFAULT_PRIORITY`] if it was never recorded
+    /// (e.g. spawned via plain `thread::spawn` rather than
+    /// `thread::spawn_with_priority`)
+    pub fn thread_priority(&self, thread_id: ThreadId) -> Priority {
+        self.thread_priority
+            .get(&thread_id)
+            .copied()
+            .unwrap_or(DEFAULT_PRIORITY)
+    }
 }
 
 // Global detector instance and logging info for ffi
@@ -218,6 +637,10 @@ pub fn init_detector(config: DetectorConfig) {
     let mut detector = GLOBAL_DETECTOR.lock();
     detector.set_deadlock_callback(config.callback);
 
+    if let Some(recovery) = config.recovery {
+        detector.set_deadlock_recovery(recovery);
+    }
+
     #[cfg(feature = "logging-and-visualization")]
     if let Some(logger) = config.logger {
         logger::init_logger(logger);
@@ -228,6 +651,8 @@ pub fn init_detector(config: DetectorConfig) {
     if config.check_lock_order {
         detector.lock_order_graph = Some(LockOrderGraph::new());
     }
+    #[cfg(feature = "lock-order-graph")]
+    detector.set_lock_order_violation_policy(config.violation_policy);
     #[cfg(not(feature = "lock-order-graph"))]
     #[cfg(feature = "lock-order-graph")]
     // Only warn if the field exists in config but feature is off? No, field doesn't exist.
@@ -238,6 +663,22 @@ pub fn init_detector(config: DetectorConfig) {
         detector.stress_mode = config.stress_mode;
         detector.stress_config = config.stress_config;
     }
+
+    #[cfg(feature = "distributed")]
+    if let Some(client) = config.distributed_client {
+        detector.set_distributed_client(client);
+    }
+
+    detector.set_rwlock_fairness(config.rwlock_fairness);
+
+    if let Some(watchdog_config) = config.watchdog {
+        watchdog::spawn(watchdog_config);
+    }
+
+    #[cfg(feature = "parking-lot-oracle")]
+    if let Some(oracle_config) = config.oracle {
+        oracle::spawn(oracle_config);
+    }
 }
 
 /// Flush all pending log entries from the global detector to disk
@@ -257,3 +698,101 @@ pub fn init_detector(config: DetectorConfig) {
 pub fn flush_global_detector_logs() -> Result<()> {
     logger::flush_logs()
 }
+
+/// All threads the global detector's wait-for graph says are transitively
+/// blocking `thread_id`
+///
+/// Lets a callback or external dashboard answer "show me every thread
+/// transitively blocked on thread N" without reaching into detector internals.
+/// Does not include `thread_id` itself.
+pub fn reachable_from(thread_id: ThreadId) -> Vec<ThreadId> {
+    GLOBAL_DETECTOR.lock().reachable_from(thread_id)
+}
+
+/// Whether `thread_id` is currently part of a wait-for cycle in the global detector
+pub fn in_cycle(thread_id: ThreadId) -> bool {
+    GLOBAL_DETECTOR.lock().in_cycle(thread_id)
+}
+
+/// The threads `thread_id` is directly (not transitively) waiting for,
+/// according to the global detector
+pub fn blockers_of(thread_id: ThreadId) -> Vec<ThreadId> {
+    GLOBAL_DETECTOR.lock().blockers_of(thread_id)
+}
+
+/// Check and consume whether `thread_id` was chosen as a deadlock victim; see
+/// [`Detector::should_abandon`].
+pub fn should_abandon(thread_id: ThreadId) -> bool {
+    GLOBAL_DETECTOR.lock().should_abandon(thread_id)
+}
+
+/// Scan the global detector's wait-for graph right now for any cycle; see
+/// [`Detector::check_deadlock`].
+pub fn check_deadlock() -> Option<Vec<ThreadId>> {
+    GLOBAL_DETECTOR.lock().check_deadlock()
+}
+
+/// Scan the global detector's wait-for graph right now for any cycle,
+/// pairing each thread with the lock it's blocked on; see
+/// [`Detector::check_deadlock_with_locks`].
+pub fn check_deadlock_with_locks() -> Option<Vec<(ThreadId, LockId)>> {
+    GLOBAL_DETECTOR.lock().check_deadlock_with_locks()
+}
+
+/// Find every independent deadlocked group in the global detector's wait-for
+/// graph right now; see [`Detector::detect_all_deadlocks`].
+pub fn detect_all_deadlocks() -> Vec<Vec<ThreadId>> {
+    GLOBAL_DETECTOR.lock().detect_all_deadlocks()
+}
+
+/// Record with the global detector that a guard for `lock_id` was dropped
+/// while its thread was panicking
+///
+/// Called from `MutexGuard`/`RwLock*Guard`'s `Drop`, alongside the lock's own
+/// per-instance `poisoned` flag, so a deadlock cycle spanning this lock can
+/// report it as poisoned rather than merely blocked.
+pub fn mark_poisoned(lock_id: LockId) {
+    GLOBAL_DETECTOR.lock().mark_poisoned(lock_id);
+}
+
+/// Whether the global detector has `lock_id` recorded as poisoned
+pub fn is_lock_poisoned(lock_id: LockId) -> bool {
+    GLOBAL_DETECTOR.lock().is_lock_poisoned(lock_id)
+}
+
+/// Clear the global detector's poisoned record for `lock_id`, called from
+/// `Mutex::clear_poison`/`RwLock::clear_poison`
+pub fn clear_poisoned(lock_id: LockId) {
+    GLOBAL_DETECTOR.lock().clear_poisoned(lock_id);
+}
+
+/// The stack trace captured when `lock_id` was created with the global
+/// detector, if backtrace capture was enabled at that moment; see
+/// [`Detector::lock_created_at`]
+pub fn lock_created_at(lock_id: LockId) -> Option<StackTraceId> {
+    GLOBAL_DETECTOR.lock().lock_created_at(lock_id)
+}
+
+/// The global detector's currently configured lock order violation policy
+///
+/// Read by [`deadlock_handling::process_deadlock`] to decide whether a
+/// reported [`crate::core::types::DeadlockSource::LockOrderViolation`]
+/// should just be logged or should panic/abort the offending thread.
+#[cfg(feature = "lock-order-graph")]
+pub(crate) fn lock_order_violation_policy() -> LockOrderViolationPolicy {
+    GLOBAL_DETECTOR.lock().lock_order_violation_policy()
+}
+
+/// Drain every lock-order violation the global detector has caught since the
+/// last call; see [`Detector::take_lock_order_violations`].
+#[cfg(feature = "lock-order-graph")]
+pub fn take_lock_order_violations() -> Vec<(LockId, LockId)> {
+    GLOBAL_DETECTOR.lock().take_lock_order_violations()
+}
+
+/// Audit the global detector's complete observed lock-ordering history for
+/// inversions right now; see [`Detector::report_lock_order`].
+#[cfg(feature = "lock-order-graph")]
+pub fn report_lock_order() -> Vec<Vec<LockId>> {
+    GLOBAL_DETECTOR.lock().report_lock_order()
+}
@@ -6,6 +6,8 @@
 use crate::core::detector::GLOBAL_DETECTOR;
 use crate::core::detector::deadlock_handling;
 use crate::core::logger;
+use crate::core::stacktrace;
+use crate::core::stacktrace::StackTraceId;
 use crate::core::types::DeadlockInfo;
 use crate::core::{Detector, Events, get_current_thread_id};
 use crate::{LockId, ThreadId};
@@ -20,6 +22,7 @@ impl Detector {
     /// * `creator_id` - Optional ID of the thread that created this mutex
     pub fn create_mutex(&mut self, lock_id: LockId, creator_id: Option<ThreadId>) {
         let creator = creator_id.unwrap_or_else(get_current_thread_id);
+        self.record_lock_created_at(lock_id, stacktrace::capture());
         logger::log_lock_event(lock_id, Some(creator), Events::MutexSpawn);
     }
 
@@ -53,6 +56,15 @@ impl Detector {
 
         // Remove from lock waiters
         self.lock_waiters.remove(&lock_id);
+
+        // Clear any poisoned record; the lock id won't be reused
+        self.poisoned_locks.remove(&lock_id);
+
+        // Clear the creation-site backtrace; the lock id won't be reused
+        self.lock_created_at.remove(&lock_id);
+
+        // Clear the stored release vector clock; the lock id won't be reused
+        self.lock_vclocks.remove(&lock_id);
     }
 
     /// Register a slow-path mutex acquisition attempt (Optimized)
@@ -66,12 +78,15 @@ impl Detector {
     /// * `thread_id` - ID of the thread attempting to acquire the mutex
     /// * `lock_id` - ID of the mutex being attempted
     /// * `potential_owner` - The thread ID observed holding the lock (if any)
+    /// * `waiting_stack_id` - Stack trace captured at the call site, if
+    ///   backtrace capture is enabled (see [`crate::core::stacktrace`])
     pub fn acquire_slow(
         &mut self,
         thread_id: ThreadId,
         lock_id: LockId,
         potential_owner: Option<ThreadId>,
-    ) -> Option<Vec<ThreadId>> {
+        waiting_stack_id: Option<StackTraceId>,
+    ) -> (Option<Vec<ThreadId>>, Option<DeadlockInfo>) {
         // Log the attempt
         logger::log_interaction_event(thread_id, lock_id, Events::MutexAttempt);
 
@@ -90,6 +105,16 @@ impl Detector {
             None
         });
 
+        // A thread requesting a lock it already holds can never actually
+        // block on anyone else, so report it immediately instead of adding a
+        // self-loop wait-for edge. This matters because
+        // `filter_cycle_by_common_locks` would otherwise treat the lock this
+        // thread holds as a "common lock" shared by every thread in the
+        // (single-thread) cycle and silently discard it as a false positive.
+        if effective_owner == Some(thread_id) {
+            return (Some(vec![thread_id]), None);
+        }
+
         if let Some(owner) = effective_owner {
             // We are waiting for this owner
             self.thread_waits_for.insert(thread_id, lock_id);
@@ -98,15 +123,69 @@ impl Detector {
                 .or_default()
                 .insert(thread_id);
 
+            if let Some(stack_id) = waiting_stack_id {
+                self.thread_waiting_at.insert(thread_id, stack_id);
+            }
+
+            // Forward the same wait-for edge to the distributed coordinator
+            // (if configured), so cycles spanning multiple processes can be
+            // caught even though this process only ever sees its own half.
+            #[cfg(feature = "distributed")]
+            if let Some(client) = &self.distributed_client {
+                client.notify_wait(thread_id, owner);
+            }
+
+            let inversion = self.check_priority_inversion(thread_id, owner);
+
             if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, owner) {
                 let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+                let filtered_cycle = if filtered_cycle.is_empty() {
+                    filtered_cycle
+                } else {
+                    self.filter_cycle_by_happens_before(&filtered_cycle)
+                };
 
                 if !filtered_cycle.is_empty() {
-                    return Some(cycle);
+                    return (Some(cycle), inversion);
                 }
             }
+            return (None, inversion);
         }
-        None
+        (None, None)
+    }
+
+    /// Check whether `thread_id` blocking on a lock held by `owner` forms a
+    /// classic unbounded priority-inversion chain: `thread_id` outranks
+    /// `owner`, and `owner` is itself blocked behind a third thread that
+    /// also outranks it.
+    ///
+    /// Only recognizes the Mutex case, since `mutex_owners` is the only
+    /// per-lock ownership map precise enough to name a single "blocker"
+    /// thread; `RwLock` can have multiple simultaneous readers, so the same
+    /// chain isn't well-defined there.
+    fn check_priority_inversion(
+        &self,
+        thread_id: ThreadId,
+        owner: ThreadId,
+    ) -> Option<DeadlockInfo> {
+        let high_priority = self.thread_priority(thread_id);
+        let low_priority = self.thread_priority(owner);
+        if high_priority <= low_priority {
+            return None;
+        }
+
+        let blocking_lock = *self.thread_waits_for.get(&owner)?;
+        let blocker = *self.mutex_owners.get(&blocking_lock)?;
+        let blocker_priority = self.thread_priority(blocker);
+        if blocker_priority <= low_priority {
+            return None;
+        }
+
+        Some(self.extract_priority_inversion_info(vec![
+            (thread_id, high_priority),
+            (owner, low_priority),
+            (blocker, blocker_priority),
+        ]))
     }
 
     /// Complete mutex acquisition after blocking
@@ -117,8 +196,10 @@ impl Detector {
         &mut self,
         thread_id: ThreadId,
         lock_id: LockId,
+        held_stack_id: Option<StackTraceId>,
     ) -> Option<DeadlockInfo> {
         self.mutex_owners.insert(lock_id, thread_id);
+        self.record_vclock_acquire(thread_id, lock_id);
 
         // Remove from lock waiters
         if let Some(waiters) = self.lock_waiters.get_mut(&lock_id) {
@@ -130,6 +211,20 @@ impl Detector {
 
         self.thread_waits_for.remove(&thread_id);
         self.wait_for_graph.clear_wait_edges(thread_id);
+        self.thread_waiting_at.remove(&thread_id);
+        match held_stack_id {
+            Some(stack_id) => {
+                self.lock_acquired_at.insert(lock_id, stack_id);
+            }
+            None => {
+                self.lock_acquired_at.remove(&lock_id);
+            }
+        }
+
+        #[cfg(feature = "distributed")]
+        if let Some(client) = &self.distributed_client {
+            client.notify_wake(thread_id);
+        }
 
         #[allow(unused_mut)]
         let mut deadlock_info = None;
@@ -137,7 +232,8 @@ impl Detector {
         #[cfg(feature = "lock-order-graph")]
         if self.lock_order_graph.is_some()
             && self.thread_holds.get(&thread_id).map_or(0, |h| h.len()) >= 1
-            && let Some(lock_cycle) = self.check_lock_order_violation(thread_id, lock_id)
+            && let Some(lock_cycle) =
+                self.check_lock_order_violation_at(thread_id, lock_id, held_stack_id)
         {
             deadlock_info =
                 Some(self.extract_lock_order_violation_info(thread_id, lock_id, lock_cycle));
@@ -153,6 +249,77 @@ impl Detector {
         deadlock_info
     }
 
+    /// Register a transient, non-blocking mutex acquisition attempt
+    ///
+    /// This method should be called by the Mutex wrapper's `try_lock` when the
+    /// optimistic fast path fails. Unlike [`Detector::acquire_slow`], the
+    /// wait-for edge is only held long enough to run cycle detection and then
+    /// immediately retracted, since a failed `try_lock` never actually blocks.
+    /// This still lets a cycle built entirely out of spinning `try_lock` calls
+    /// be caught, without leaving a stale edge behind for a wait that will
+    /// never happen.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread attempting to acquire the mutex
+    /// * `lock_id` - ID of the mutex being attempted
+    /// * `potential_owner` - The thread ID observed holding the lock (if any)
+    pub fn try_attempt(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+        potential_owner: Option<ThreadId>,
+    ) -> Option<Vec<ThreadId>> {
+        logger::log_interaction_event(thread_id, lock_id, Events::MutexAttempt);
+
+        let effective_owner = self.mutex_owners.get(&lock_id).copied().or(potential_owner)?;
+
+        let cycle = self.wait_for_graph.add_edge(thread_id, effective_owner);
+        // Retract immediately: this attempt is transient and never blocks.
+        self.wait_for_graph.remove_edge(thread_id, effective_owner);
+
+        let cycle = cycle?;
+        let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+        let filtered_cycle = if filtered_cycle.is_empty() {
+            filtered_cycle
+        } else {
+            self.filter_cycle_by_happens_before(&filtered_cycle)
+        };
+        if filtered_cycle.is_empty() {
+            None
+        } else {
+            Some(cycle)
+        }
+    }
+
+    /// Cancel a pending wait-for edge after a time-bounded acquisition gave up
+    ///
+    /// Called when `lock_for`/`lock_until` times out: the thread is no longer
+    /// waiting on this lock, so the edge added by [`Detector::acquire_slow`]
+    /// must be retracted exactly as if the thread had never blocked.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread that gave up waiting
+    /// * `lock_id` - ID of the mutex it gave up waiting for
+    pub fn cancel_wait(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        logger::log_interaction_event(thread_id, lock_id, Events::AcquireTimedOut);
+
+        if let Some(waiters) = self.lock_waiters.get_mut(&lock_id) {
+            waiters.remove(&thread_id);
+            if waiters.is_empty() {
+                self.lock_waiters.remove(&lock_id);
+            }
+        }
+
+        self.thread_waits_for.remove(&thread_id);
+        self.wait_for_graph.clear_wait_edges(thread_id);
+        self.thread_waiting_at.remove(&thread_id);
+
+        #[cfg(feature = "distributed")]
+        if let Some(client) = &self.distributed_client {
+            client.notify_wake(thread_id);
+        }
+    }
+
     /// Register mutex release by a thread
     ///
     /// # Arguments
@@ -160,9 +327,11 @@ impl Detector {
     /// * `lock_id` - ID of the mutex being released
     pub fn release_mutex(&mut self, thread_id: ThreadId, lock_id: LockId) {
         logger::log_interaction_event(thread_id, lock_id, Events::MutexReleased);
+        self.record_vclock_release(thread_id, lock_id);
         if self.mutex_owners.get(&lock_id) == Some(&thread_id) {
             self.mutex_owners.remove(&lock_id);
         }
+        self.lock_acquired_at.remove(&lock_id);
         // remove from held-locks
         if let Some(holds) = self.thread_holds.get_mut(&thread_id) {
             holds.remove(&lock_id);
@@ -188,6 +357,20 @@ impl Detector {
     }
 }
 
+/// Whether the active stress configuration forces every `FairMutex` unlock to
+/// hand off directly to the longest-waiting thread
+///
+/// Only compiled with the `stress-test` feature; returns `false` when no
+/// stress configuration has been set.
+#[cfg(feature = "stress-test")]
+pub fn fair_unlock_forced() -> bool {
+    let detector = GLOBAL_DETECTOR.lock();
+    detector
+        .stress_config
+        .as_ref()
+        .is_some_and(|config| config.fair_unlock)
+}
+
 /// Register a mutex creation with the global detector
 ///
 /// # Arguments
@@ -217,6 +400,34 @@ pub fn release_mutex(thread_id: ThreadId, lock_id: LockId) {
     detector.release_mutex(thread_id, lock_id);
 }
 
+/// Report `lock_id` as abandoned if anyone is currently blocked waiting for
+/// it, since `thread_id` is about to release it while unwinding from a panic
+///
+/// Called from the guard's `Drop` impl, before the matching [`release_mutex`]
+/// call: a panicking thread was never waiting on anyone, so its death can
+/// never complete a wait-for cycle, and without this, its waiters would be
+/// left with nothing to ever detect the hang. No-op if nobody is currently
+/// waiting on `lock_id`.
+pub fn report_abandoned_lock(thread_id: ThreadId, lock_id: LockId, panic_message: Option<String>) {
+    let info = {
+        let detector = GLOBAL_DETECTOR.lock();
+        detector.lock_waiters.get(&lock_id).and_then(|waiters| {
+            (!waiters.is_empty()).then(|| {
+                detector.extract_abandoned_lock_info(
+                    thread_id,
+                    lock_id,
+                    waiters.iter().copied().collect(),
+                    panic_message,
+                )
+            })
+        })
+    };
+
+    if let Some(info) = info {
+        deadlock_handling::process_deadlock(info);
+    }
+}
+
 /// Complete mutex acquisition after blocking
 ///
 /// Called after a blocking lock() call completes.
@@ -224,10 +435,12 @@ pub fn release_mutex(thread_id: ThreadId, lock_id: LockId) {
 /// # Arguments
 /// * `thread_id` - ID of the thread that acquired the mutex
 /// * `lock_id` - ID of the mutex that was acquired
-pub fn complete_acquire(thread_id: ThreadId, lock_id: LockId) {
+/// * `held_stack_id` - Stack trace captured at the call site, if backtrace
+///   capture is enabled
+pub fn complete_acquire(thread_id: ThreadId, lock_id: LockId, held_stack_id: Option<StackTraceId>) {
     let deadlock_info = {
         let mut detector = GLOBAL_DETECTOR.lock();
-        detector.complete_acquire(thread_id, lock_id)
+        detector.complete_acquire(thread_id, lock_id, held_stack_id)
     };
 
     if let Some(info) = deadlock_info {
@@ -235,18 +448,48 @@ pub fn complete_acquire(thread_id: ThreadId, lock_id: LockId) {
     }
 }
 
+/// Register a transient, non-blocking mutex acquisition attempt with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread attempting to acquire the mutex
+/// * `lock_id` - ID of the mutex being attempted
+/// * `potential_owner` - The thread ID observed holding the lock
+pub fn try_attempt(
+    thread_id: ThreadId,
+    lock_id: LockId,
+    potential_owner: Option<ThreadId>,
+) -> Option<DeadlockInfo> {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    let cycle = detector.try_attempt(thread_id, lock_id, potential_owner);
+    cycle.map(|cycle| detector.extract_deadlock_info(cycle))
+}
+
+/// Cancel a pending wait-for edge with the global detector after a
+/// time-bounded acquisition gave up
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread that gave up waiting
+/// * `lock_id` - ID of the mutex it gave up waiting for
+pub fn cancel_acquire(thread_id: ThreadId, lock_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.cancel_wait(thread_id, lock_id);
+}
+
 /// Register a slow-path mutex acquisition attempt with the global detector
 ///
 /// # Arguments
 /// * `thread_id` - ID of the thread attempting to acquire the mutex
 /// * `lock_id` - ID of the mutex being attempted
 /// * `potential_owner` - The thread ID observed holding the lock
+/// * `waiting_stack_id` - Stack trace captured at the call site, if
+///   backtrace capture is enabled
 pub fn acquire_slow(
     thread_id: ThreadId,
     lock_id: LockId,
     potential_owner: Option<ThreadId>,
+    waiting_stack_id: Option<StackTraceId>,
 ) -> Option<DeadlockInfo> {
-    
+
 
     // 1. Calculate stress delay (holding lock)
     #[cfg(feature = "stress-test")]
@@ -262,9 +505,36 @@ pub fn acquire_slow(
     }
 
     // 3. Proceed with detection (re-acquiring lock)
-    {
+    let (cycle, inversion, stall) = {
         let mut detector = GLOBAL_DETECTOR.lock();
-        let cycle = detector.acquire_slow(thread_id, lock_id, potential_owner);
-        cycle.map(|cycle| detector.extract_deadlock_info(cycle))
+        let (cycle, inversion) =
+            detector.acquire_slow(thread_id, lock_id, potential_owner, waiting_stack_id);
+        let cycle = cycle.map(|cycle| {
+            if cycle.as_slice() == [thread_id] {
+                detector.extract_self_deadlock_info(thread_id, lock_id)
+            } else {
+                detector.extract_deadlock_info(cycle)
+            }
+        });
+        // This attempt may be the one that leaves every live thread blocked
+        // with a condvar waiter among them that can now never be notified -
+        // check regardless of whether `cycle` itself fired, since the two
+        // are independent hazards.
+        let stall = detector.check_condvar_stall();
+        (cycle, inversion, stall)
+    };
+
+    // A priority-inversion hazard or condvar stall is independent of any
+    // wait-for cycle, so each is dispatched right away instead of folding it
+    // into the cycle's `Option<DeadlockInfo>` return (which the caller
+    // reports via the same `process_deadlock` path, just on its own
+    // schedule relative to the guard it's blocking in).
+    if let Some(info) = inversion {
+        deadlock_handling::process_deadlock(info);
     }
+    if let Some(info) = stall {
+        deadlock_handling::process_deadlock(info);
+    }
+
+    cycle
 }
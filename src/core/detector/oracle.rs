@@ -0,0 +1,104 @@
+//! Cross-validation against parking_lot's own `deadlock_detection`
+//!
+//! Every lock in this crate is already built on `parking_lot`, which ships an
+//! optional `deadlock_detection` feature that periodically scans its own
+//! parked threads for a cycle, entirely independent of deloxide's wait-for
+//! graph. Enabling this module (with the `parking-lot-oracle` cargo feature,
+//! which must also turn on `parking_lot/deadlock_detection`) spawns a
+//! background thread that polls [`parking_lot::deadlock::check_deadlock`] on
+//! an interval and records what it last saw, so a real wait-for cycle
+//! reported by deloxide can be checked against a second, independently
+//! implemented detector.
+//!
+//! # Limits of the cross-check
+//!
+//! parking_lot assigns its own internal id to each parked thread, with no
+//! public API to map it back to deloxide's [`crate::core::types::ThreadId`].
+//! That rules out comparing the exact thread *set* the request describes -
+//! all this can honestly confirm is whether parking_lot's last scan also saw
+//! *some* deadlock at all. That coarser signal is still useful: a deloxide
+//! cycle with no corroborating parking_lot scan usually means either an
+//! instrumentation gap (a lock path that never emits its attempt/acquire
+//! events, so deloxide never saw the wait) or a deloxide false positive.
+
+use crate::core::types::DeadlockInfo;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Configuration for the background parking_lot oracle thread
+///
+/// See [`crate::Deloxide::with_parking_lot_oracle`].
+#[derive(Debug, Clone, Copy)]
+pub struct OracleConfig {
+    /// How often to poll `parking_lot::deadlock::check_deadlock`
+    pub interval: Duration,
+}
+
+/// Whether [`spawn`] has been called; the runtime toggle [`cross_validate`]
+/// checks before doing any work, so a binary built with the
+/// `parking-lot-oracle` feature but that never calls
+/// [`crate::Deloxide::with_parking_lot_oracle`] pays nothing beyond this flag
+/// check.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// What parking_lot's background scan last saw
+struct Scan {
+    /// Number of distinct deadlocked thread groups parking_lot reported
+    thread_groups: usize,
+    /// When this scan ran
+    at: Instant,
+}
+
+static LAST_SCAN: OnceLock<parking_lot::Mutex<Option<Scan>>> = OnceLock::new();
+
+fn last_scan() -> &'static parking_lot::Mutex<Option<Scan>> {
+    LAST_SCAN.get_or_init(|| parking_lot::Mutex::new(None))
+}
+
+/// Spawn the background thread that periodically polls parking_lot's own
+/// deadlock detector
+///
+/// Mirrors [`super::watchdog::spawn`]'s pattern of a dedicated
+/// `std::thread::spawn` sleep loop.
+pub(crate) fn spawn(config: OracleConfig) {
+    ENABLED.store(true, Ordering::Relaxed);
+    std::thread::spawn(move || {
+        loop {
+            std::thread::sleep(config.interval);
+            let thread_groups = parking_lot::deadlock::check_deadlock().len();
+            *last_scan().lock() = Some(Scan {
+                thread_groups,
+                at: Instant::now(),
+            });
+        }
+    });
+}
+
+/// Check a deloxide-reported wait-for cycle against parking_lot's most recent
+/// scan, and print a diagnostic to stderr if they disagree
+///
+/// No-op if [`spawn`] hasn't run (see [`ENABLED`]) or parking_lot hasn't
+/// completed a first scan yet.
+pub(crate) fn cross_validate(info: &DeadlockInfo) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let Some(scan) = last_scan().lock().as_ref().map(|scan| (scan.thread_groups, scan.at))
+    else {
+        return;
+    };
+    let (thread_groups, at) = scan;
+
+    if thread_groups == 0 {
+        eprintln!(
+            "deloxide: reported a deadlock ({} threads) that parking_lot's own \
+             deadlock_detection did not see in its last scan ({:?} ago) - possible \
+             instrumentation gap (a lock path that never emits attempt/acquire events) \
+             or a deloxide false positive",
+            info.thread_cycle.len(),
+            at.elapsed(),
+        );
+    }
+}
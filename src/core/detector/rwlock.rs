@@ -6,11 +6,15 @@
 use crate::core::detector::GLOBAL_DETECTOR;
 use crate::core::detector::deadlock_handling;
 use crate::core::logger;
-use crate::core::types::DeadlockInfo;
+use crate::core::stacktrace;
+use crate::core::types::{DeadlockInfo, DeadlockSource, RwLockFairness, ThreadStall};
 use crate::core::{Detector, Events, get_current_thread_id};
 use crate::{LockId, ThreadId};
+use fxhash::FxHashMap;
 #[cfg(feature = "stress-test")]
 use std::thread;
+use std::time::{Duration, Instant};
+
 impl Detector {
     /// Register an RwLock creation
     ///
@@ -19,6 +23,7 @@ impl Detector {
     /// * `creator_id` - Optional ID of the thread that created this RwLock
     pub fn create_rwlock(&mut self, lock_id: LockId, creator_id: Option<ThreadId>) {
         let creator = creator_id.unwrap_or_else(get_current_thread_id);
+        self.record_lock_created_at(lock_id, stacktrace::capture());
         logger::log_lock_event(lock_id, Some(creator), Events::RwSpawn);
     }
 
@@ -27,9 +32,14 @@ impl Detector {
     /// # Arguments
     /// * `lock_id` - ID of the RwLock being destroyed
     pub fn destroy_rwlock(&mut self, lock_id: LockId) {
-        // Remove ownership (both read and write)
+        // Remove ownership (read, write, and upgradable)
         self.rwlock_writer.remove(&lock_id);
         self.rwlock_readers.remove(&lock_id);
+        self.rwlock_upgradable.remove(&lock_id);
+        self.rwlock_write_waiters.remove(&lock_id);
+        self.rwlock_writer_wait_start
+            .retain(|_, (waiting_on, _, _)| *waiting_on != lock_id);
+        self.lock_vclocks.remove(&lock_id);
 
         // Remove from all held-lock sets
         for holds in self.thread_holds.values_mut() {
@@ -44,26 +54,165 @@ impl Detector {
 
         // Remove from lock waiters
         self.lock_waiters.remove(&lock_id);
+
+        // Clear any poisoned record; the lock id won't be reused
+        self.poisoned_locks.remove(&lock_id);
+
+        // Clear the creation-site backtrace; the lock id won't be reused
+        self.lock_created_at.remove(&lock_id);
+
         logger::log_lock_event(lock_id, None, Events::RwExit);
     }
 
+    /// Add a wait-for edge for a blocked/busy attempt, or retract it immediately
+    ///
+    /// Non-transient callers (the blocking `read`/`write` path, or a `try_*`
+    /// call that is about to fall back to blocking) need the edge to persist
+    /// until the eventual `complete_*`/`cancel_*` call. A genuinely
+    /// non-blocking attempt (`try_read`/`try_write` returning immediately)
+    /// must not leave an edge behind for a wait that will never happen, so it
+    /// passes `transient = true` and the edge is added only long enough to
+    /// run cycle detection.
+    fn add_wait_edge(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+        blocker: ThreadId,
+        transient: bool,
+    ) -> Option<Vec<ThreadId>> {
+        if !transient {
+            self.thread_waits_for.insert(thread_id, lock_id);
+            self.lock_waiters
+                .entry(lock_id)
+                .or_default()
+                .insert(thread_id);
+        }
+
+        let cycle = self.wait_for_graph.add_edge(thread_id, blocker);
+
+        if transient {
+            self.wait_for_graph.remove_edge(thread_id, blocker);
+        }
+
+        cycle
+    }
+
+    /// Bump `thread_id`'s own vector-clock entry and merge in the clock
+    /// `lock_id` had stored from its last release, the way Miri's `VClock`
+    /// merges a mutex's stored clock into its acquirer. Called on every
+    /// Mutex or RwLock acquisition so later happens-before checks against
+    /// this thread reflect everything it has causally observed so far
+    /// through this lock.
+    pub(crate) fn record_vclock_acquire(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        let mut clock = self.thread_vclocks.remove(&thread_id).unwrap_or_default();
+
+        if let Some(lock_clock) = self.lock_vclocks.get(&lock_id) {
+            for (&thread, &count) in lock_clock {
+                let entry = clock.entry(thread).or_insert(0);
+                *entry = (*entry).max(count);
+            }
+        }
+
+        *clock.entry(thread_id).or_insert(0) += 1;
+        self.thread_vclocks.insert(thread_id, clock);
+    }
+
+    /// Store `thread_id`'s current vector clock into `lock_id`, so the next
+    /// thread to acquire it merges in everything `thread_id` had causally
+    /// observed as of this release. Called on every Mutex or RwLock release.
+    pub(crate) fn record_vclock_release(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        if let Some(clock) = self.thread_vclocks.get(&thread_id) {
+            self.lock_vclocks.insert(lock_id, clock.clone());
+        }
+    }
+
+    /// Filter a cycle by checking whether any two of its threads' vector
+    /// clocks show one happened-before the other.
+    ///
+    /// If thread A's clock is component-wise `<=` thread B's clock (A's
+    /// acquisitions are a subset of what B has already causally observed),
+    /// the two threads' conflicting operations never actually overlapped in
+    /// time - B's lock activity already depends on A's, so they couldn't
+    /// still be concurrently racing into a live deadlock - and the reported
+    /// cycle is a false positive caused by the wait-for graph's lack of a
+    /// notion of time. See [`Detector::filter_cycle_by_common_locks`] for the
+    /// sibling filter this mirrors.
+    ///
+    /// # Returns
+    /// * Empty vector if a happens-before relation was found between any two
+    ///   cycle threads (false positive)
+    /// * The original cycle if every pair is genuinely concurrent (real deadlock)
+    pub fn filter_cycle_by_happens_before(&self, cycle: &[ThreadId]) -> Vec<ThreadId> {
+        for &a in cycle {
+            let Some(clock_a) = self.thread_vclocks.get(&a) else {
+                continue;
+            };
+            for &b in cycle {
+                if a == b {
+                    continue;
+                }
+                if let Some(clock_b) = self.thread_vclocks.get(&b)
+                    && Self::happens_before(clock_a, clock_b)
+                {
+                    return Vec::new();
+                }
+            }
+        }
+        cycle.to_vec()
+    }
+
+    /// Whether `a` happened-before `b`: every entry of `a` is `<=` the
+    /// corresponding entry of `b`, and `a` is not identical to `b` (a thread's
+    /// clock never happens-before its own identical snapshot).
+    fn happens_before(
+        a: &FxHashMap<ThreadId, u64>,
+        b: &FxHashMap<ThreadId, u64>,
+    ) -> bool {
+        let at_least_as_new = a
+            .iter()
+            .all(|(thread, &count)| b.get(thread).copied().unwrap_or(0) >= count);
+        at_least_as_new && a != b
+    }
+
+    /// Snapshot the current vector clock of each thread in `cycle`, as
+    /// `(thread_id, (other_thread, count))` pairs, for inclusion in a
+    /// [`DeadlockInfo::thread_vector_clocks`] report. Threads with no
+    /// recorded clock (never acquired a lock) are omitted.
+    pub fn snapshot_vclocks(&self, cycle: &[ThreadId]) -> Vec<(ThreadId, Vec<(ThreadId, u64)>)> {
+        cycle
+            .iter()
+            .filter_map(|&t| {
+                self.thread_vclocks
+                    .get(&t)
+                    .map(|clock| (t, clock.iter().map(|(&k, &v)| (k, v)).collect()))
+            })
+            .collect()
+    }
+
     /// Read lock attempt and try-acquire operation
     ///
     /// # Arguments
     /// * `thread_id` - ID of the thread attempting to acquire the read lock
     /// * `lock_id` - ID of the RwLock being attempted
+    /// * `transient` - If `true` (a genuinely non-blocking `try_read`), any
+    ///   wait-for edge set up to check for a cycle is retracted immediately
+    ///   instead of being left for a `complete_read` that will never come.
     /// * `try_acquire_fn` - Closure that attempts non-blocking read lock acquisition
     ///
     /// # Returns
-    /// * `Some(T)` - Read lock was acquired successfully
-    /// * `None` - Lock is busy (writer exists), deadlock detected, or acquisition failed
+    /// * `Ok((Some(T), order_violation))` - Read lock was acquired successfully; `order_violation`
+    ///   is `Some` if this acquisition completed a lock-order cycle (see
+    ///   [`Detector::check_lock_order_violation`]), even though the lock itself was still granted
+    /// * `Ok((None, _))` - Lock is busy (writer exists) and the caller should fall back to blocking
+    /// * `Err(cycle)` - A wait-for deadlock was detected
     pub fn attempt_read<T, F>(
         &mut self,
         thread_id: ThreadId,
         lock_id: LockId,
         potential_writer: Option<ThreadId>,
+        transient: bool,
         try_acquire_fn: F,
-    ) -> Result<Option<T>, Vec<ThreadId>>
+    ) -> Result<(Option<T>, Option<DeadlockInfo>), Vec<ThreadId>>
     where
         F: FnOnce() -> Option<T>,
     {
@@ -80,15 +229,15 @@ impl Detector {
             .or(potential_writer);
 
         if let Some(writer) = effective_writer {
-            self.thread_waits_for.insert(thread_id, lock_id);
-            self.lock_waiters
-                .entry(lock_id)
-                .or_default()
-                .insert(thread_id);
-
-            if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, writer) {
-                // Apply common lock filter
+            if let Some(cycle) = self.add_wait_edge(thread_id, lock_id, writer, transient) {
+                // Apply common lock filter, then prune any causally-ordered
+                // (happens-before) pair the common-lock filter let through
                 let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+                let filtered_cycle = if filtered_cycle.is_empty() {
+                    filtered_cycle
+                } else {
+                    self.filter_cycle_by_happens_before(&filtered_cycle)
+                };
 
                 if !filtered_cycle.is_empty() {
                     // Real deadlock detected!
@@ -97,69 +246,200 @@ impl Detector {
             }
 
             // Writer exists but no deadlock - will need to block
-            return Ok(None);
+            return Ok((None, None));
+        }
+
+        // Recursive read-lock self-deadlock: `thread_id` already holds a read
+        // lock on this rwlock, and a writer is already queued behind it. Under
+        // `RwLockFairness::WriterPreferring`, new readers block whenever a
+        // writer is waiting, so this thread's own re-entrant read attempt
+        // would block forever behind a writer that can itself never be
+        // unblocked (it's waiting on a read lock only this same thread could
+        // drop). This is a single-thread self-block, not a multi-thread
+        // cycle, so `wait_for_graph` cycle detection would find nothing - it
+        // must be reported directly, the same way
+        // [`Detector::extract_self_deadlock_info`] handles a non-reentrant
+        // mutex re-locked by its own owner. Doesn't apply under
+        // `ReaderPreferring`, where a queued writer never blocks a reader.
+        if self.rwlock_fairness == RwLockFairness::WriterPreferring
+            && self.rwlock_readers.get(&lock_id).is_some_and(|r| r.contains(&thread_id))
+            && self
+                .rwlock_write_waiters
+                .get(&lock_id)
+                .is_some_and(|w| !w.is_empty())
+        {
+            return Err(vec![thread_id]);
         }
 
         // No writer - try to acquire read lock while still holding GLOBAL_DETECTOR
         if let Some(guard) = try_acquire_fn() {
-            // Success! Update detector state immediately
-            self.rwlock_readers
-                .entry(lock_id)
-                .or_default()
-                .insert(thread_id);
-            #[cfg(feature = "lock-order-graph")]
-            self.thread_holds
-                .entry(thread_id)
-                .or_default()
-                .insert(lock_id);
-
-            // NOTE: Read locks do NOT clear wait edges!
-            // Multiple readers can coexist, so the thread stays in the graph
-            // for potential upgrade deadlock detection.
-            self.thread_waits_for.remove(&thread_id);
+            // Success! Delegate to complete_read for the same bookkeeping (and
+            // lock-order check) a read lock acquired after blocking gets.
+            let order_violation = self.complete_read(thread_id, lock_id);
+            Ok((Some(guard), order_violation))
+        } else if let Some(&writer) = self.rwlock_writer.get(&lock_id) {
+            // try_read failed - a writer must have acquired it.
+            // Set up wait-for edges for the blocking read() that will follow
+            if let Some(cycle) = self.add_wait_edge(thread_id, lock_id, writer, transient) {
+                let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+                let filtered_cycle = if filtered_cycle.is_empty() {
+                    filtered_cycle
+                } else {
+                    self.filter_cycle_by_happens_before(&filtered_cycle)
+                };
+                if !filtered_cycle.is_empty() {
+                    return Err(cycle);
+                }
+            }
 
-            // Log acquisition
-            logger::log_interaction_event(thread_id, lock_id, Events::RwReadAcquired);
+            Ok((None, None))
+        } else if self.rwlock_fairness == RwLockFairness::WriterPreferring {
+            // No writer holds the lock, so try_read must have failed due to
+            // writer-preference: a writer is already queued ahead of us.
+            // Add the same wait-for edges to every queued writer so a cycle
+            // formed entirely through writer-starvation is still caught.
+            // Only modeled under `WriterPreferring`; under `ReaderPreferring`
+            // a queued writer can never be why `try_read` failed.
+            if let Some(writers) = self.rwlock_write_waiters.get(&lock_id).cloned() {
+                for writer in writers {
+                    if writer != thread_id
+                        && let Some(cycle) = self.add_wait_edge(thread_id, lock_id, writer, transient)
+                    {
+                        let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+                        let filtered_cycle = if filtered_cycle.is_empty() {
+                            filtered_cycle
+                        } else {
+                            self.filter_cycle_by_happens_before(&filtered_cycle)
+                        };
+                        if !filtered_cycle.is_empty() {
+                            return Err(cycle);
+                        }
+                    }
+                }
+            }
 
-            Ok(Some(guard))
+            Ok((None, None))
         } else {
-            // try_read failed - a writer must have acquired it
-            // Set up wait-for edges for the blocking read() that will follow
-            if let Some(&writer) = self.rwlock_writer.get(&lock_id) {
-                self.thread_waits_for.insert(thread_id, lock_id);
-                self.lock_waiters
-                    .entry(lock_id)
-                    .or_default()
-                    .insert(thread_id);
-
-                if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, writer) {
+            Ok((None, None))
+        }
+    }
+
+    /// Register a transient, non-blocking write lock acquisition attempt
+    ///
+    /// This method should be called by the RwLock wrapper's `try_write` when
+    /// the optimistic fast path fails. Unlike [`Detector::acquire_write_slow`],
+    /// any wait-for edge is only held long enough to run cycle detection and
+    /// then immediately retracted, since a failed `try_write` never actually
+    /// blocks. Like [`Detector::attempt_read`], any cycle found is passed
+    /// through the common-held-lock filter before being reported, since a
+    /// transient edge like this one is exactly the shape of check that
+    /// filter exists to guard: a thread merely probing with `try_write` must
+    /// not be flagged over a lock it simply happens to already hold itself.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread attempting to acquire the write lock
+    /// * `lock_id` - ID of the RwLock being attempted
+    /// * `potential_writer` - The thread ID observed holding the write lock (if any)
+    pub fn try_write_attempt(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+        potential_writer: Option<ThreadId>,
+    ) -> Option<Vec<ThreadId>> {
+        logger::log_interaction_event(thread_id, lock_id, Events::RwWriteAttempt);
+
+        if let Some(readers) = self.rwlock_readers.get(&lock_id).cloned() {
+            for reader in readers {
+                if reader != thread_id
+                    && let Some(cycle) = self.add_wait_edge(thread_id, lock_id, reader, true)
+                {
                     let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
                     if !filtered_cycle.is_empty() {
-                        return Err(cycle);
+                        return Some(cycle);
                     }
                 }
             }
+        }
 
-            Ok(None)
+        let effective_writer = self
+            .rwlock_writer
+            .get(&lock_id)
+            .copied()
+            .or(potential_writer);
+
+        if let Some(writer) = effective_writer
+            && writer != thread_id
+            && let Some(cycle) = self.add_wait_edge(thread_id, lock_id, writer, true)
+        {
+            let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+            if !filtered_cycle.is_empty() {
+                return Some(cycle);
+            }
+        }
+
+        if let Some(&holder) = self.rwlock_upgradable.get(&lock_id)
+            && holder != thread_id
+            && let Some(cycle) = self.add_wait_edge(thread_id, lock_id, holder, true)
+        {
+            let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+            if !filtered_cycle.is_empty() {
+                return Some(cycle);
+            }
+        }
+
+        None
+    }
+
+    /// Cancel a pending wait-for edge after a time-bounded acquisition gave up
+    ///
+    /// Called when `read_for`/`read_until`/`write_for`/`write_until` times
+    /// out: the thread is no longer waiting on this lock, so any edge added
+    /// while attempting it must be retracted exactly as if it had never
+    /// blocked.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread that gave up waiting
+    /// * `lock_id` - ID of the RwLock it gave up waiting for
+    pub fn cancel_wait(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        logger::log_interaction_event(thread_id, lock_id, Events::AcquireTimedOut);
+
+        if let Some(waiters) = self.lock_waiters.get_mut(&lock_id) {
+            waiters.remove(&thread_id);
+            if waiters.is_empty() {
+                self.lock_waiters.remove(&lock_id);
+            }
+        }
+        if let Some(writers) = self.rwlock_write_waiters.get_mut(&lock_id) {
+            writers.remove(&thread_id);
+            if writers.is_empty() {
+                self.rwlock_write_waiters.remove(&lock_id);
+            }
         }
+        self.rwlock_writer_wait_start.remove(&thread_id);
+
+        self.thread_waits_for.remove(&thread_id);
+        self.wait_for_graph.clear_wait_edges(thread_id);
     }
 
-    /// Update detector state after blocking read lock acquisition
+    /// Update detector state after a read lock acquisition (whether it blocked or not)
     ///
     /// # Arguments
     /// * `thread_id` - ID of the thread that acquired the read lock
     /// * `lock_id` - ID of the RwLock
-    pub fn complete_read(&mut self, thread_id: ThreadId, lock_id: LockId) {
+    ///
+    /// # Returns
+    /// `Some(DeadlockInfo)` if this acquisition completes a lock-order cycle
+    /// (see [`Detector::check_lock_order_violation`]), even though the read
+    /// lock itself was still granted; `None` otherwise.
+    pub fn complete_read(&mut self, thread_id: ThreadId, lock_id: LockId) -> Option<DeadlockInfo> {
         self.rwlock_readers
             .entry(lock_id)
             .or_default()
             .insert(thread_id);
-        #[cfg(feature = "lock-order-graph")]
-        self.thread_holds
-            .entry(thread_id)
-            .or_default()
-            .insert(lock_id);
 
+        // NOTE: Read locks do NOT clear wait edges! Multiple readers can
+        // coexist, so the thread stays in the graph for potential upgrade
+        // deadlock detection.
         self.thread_waits_for.remove(&thread_id);
         if let Some(waiters) = self.lock_waiters.get_mut(&lock_id) {
             waiters.remove(&thread_id);
@@ -168,8 +448,30 @@ impl Detector {
             }
         }
 
+        #[allow(unused_mut)]
+        let mut deadlock_info = None;
+
+        #[cfg(feature = "lock-order-graph")]
+        if self.lock_order_graph.is_some()
+            && self.thread_holds.get(&thread_id).map_or(0, |h| h.len()) >= 1
+            && let Some(lock_cycle) = self.check_lock_order_violation(thread_id, lock_id)
+        {
+            deadlock_info =
+                Some(self.extract_lock_order_violation_info(thread_id, lock_id, lock_cycle));
+        }
+
+        #[cfg(feature = "lock-order-graph")]
+        self.thread_holds
+            .entry(thread_id)
+            .or_default()
+            .insert(lock_id);
+
+        self.record_vclock_acquire(thread_id, lock_id);
+
         // Log acquisition
         logger::log_interaction_event(thread_id, lock_id, Events::RwReadAcquired);
+
+        deadlock_info
     }
 
     /// Register a read lock release by a thread
@@ -179,6 +481,7 @@ impl Detector {
     /// * `lock_id` - ID of the RwLock being released
     pub fn release_read(&mut self, thread_id: ThreadId, lock_id: LockId) {
         logger::log_interaction_event(thread_id, lock_id, Events::RwReadReleased);
+        self.record_vclock_release(thread_id, lock_id);
         if let Some(readers) = self.rwlock_readers.get_mut(&lock_id) {
             readers.remove(&thread_id);
             if readers.is_empty() {
@@ -202,6 +505,22 @@ impl Detector {
             }
         }
 
+        // A reader just came and went while at least one writer is still
+        // queued on this lock: count it as churn against every writer
+        // waiting here, for `check_writer_starvation` to notice the lock
+        // kept turning over without ever reaching them.
+        if self
+            .rwlock_write_waiters
+            .get(&lock_id)
+            .is_some_and(|w| !w.is_empty())
+        {
+            for (waiting_on, _, churn) in self.rwlock_writer_wait_start.values_mut() {
+                if *waiting_on == lock_id {
+                    *churn += 1;
+                }
+            }
+        }
+
         #[cfg(feature = "stress-test")]
         self.stress_on_lock_release(thread_id, lock_id);
     }
@@ -213,6 +532,7 @@ impl Detector {
     /// * `lock_id` - ID of the RwLock being released
     pub fn release_write(&mut self, thread_id: ThreadId, lock_id: LockId) {
         logger::log_interaction_event(thread_id, lock_id, Events::RwWriteReleased);
+        self.record_vclock_release(thread_id, lock_id);
         if self.rwlock_writer.get(&lock_id) == Some(&thread_id) {
             self.rwlock_writer.remove(&lock_id);
         }
@@ -261,6 +581,16 @@ impl Detector {
             return Some(self.extract_lock_order_violation_info(thread_id, lock_id, lock_cycle));
         }
 
+        // Register as a queued writer so new readers respect writer
+        // preference and don't starve us out indefinitely.
+        self.rwlock_write_waiters
+            .entry(lock_id)
+            .or_default()
+            .insert(thread_id);
+        self.rwlock_writer_wait_start
+            .entry(thread_id)
+            .or_insert_with(|| (lock_id, Instant::now(), 0));
+
         // Check for conflicting readers (Global State)
         if let Some(readers) = self.rwlock_readers.get(&lock_id) {
             for &reader in readers {
@@ -271,8 +601,12 @@ impl Detector {
                         .or_default()
                         .insert(thread_id);
                     if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, reader) {
-                        // No common lock filtering for upgrades (Reader->Writer deps)
-                        return Some(self.extract_deadlock_info(cycle));
+                        // No common lock filtering for upgrades (Reader->Writer deps),
+                        // but still prune a pair already causally ordered by a
+                        // happens-before relation.
+                        if !self.filter_cycle_by_happens_before(&cycle).is_empty() {
+                            return Some(self.extract_deadlock_info(cycle));
+                        }
                     }
                 }
             }
@@ -296,7 +630,25 @@ impl Detector {
                 .entry(lock_id)
                 .or_default()
                 .insert(thread_id);
-            if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, writer) {
+            if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, writer)
+                && !self.filter_cycle_by_happens_before(&cycle).is_empty()
+            {
+                return Some(self.extract_deadlock_info(cycle));
+            }
+        }
+
+        // Check for a conflicting upgradable reader (it may itself be about to upgrade)
+        if let Some(&holder) = self.rwlock_upgradable.get(&lock_id)
+            && holder != thread_id
+        {
+            self.thread_waits_for.insert(thread_id, lock_id);
+            self.lock_waiters
+                .entry(lock_id)
+                .or_default()
+                .insert(thread_id);
+            if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, holder)
+                && !self.filter_cycle_by_happens_before(&cycle).is_empty()
+            {
                 return Some(self.extract_deadlock_info(cycle));
             }
         }
@@ -310,6 +662,13 @@ impl Detector {
     /// * `lock_id` - ID of the RwLock
     pub fn complete_write(&mut self, thread_id: ThreadId, lock_id: LockId) -> Option<DeadlockInfo> {
         self.rwlock_writer.insert(lock_id, thread_id);
+        if let Some(writers) = self.rwlock_write_waiters.get_mut(&lock_id) {
+            writers.remove(&thread_id);
+            if writers.is_empty() {
+                self.rwlock_write_waiters.remove(&lock_id);
+            }
+        }
+        self.rwlock_writer_wait_start.remove(&thread_id);
 
         #[allow(unused_mut)]
         let mut deadlock_info = None;
@@ -338,11 +697,238 @@ impl Detector {
         }
         self.wait_for_graph.clear_wait_edges(thread_id);
 
+        self.record_vclock_acquire(thread_id, lock_id);
+
         // Log acquisition
         logger::log_interaction_event(thread_id, lock_id, Events::RwWriteAcquired);
 
         deadlock_info
     }
+
+    /// Check every writer currently queued on an RwLock for starvation
+    ///
+    /// A fair `write()` blocks new readers once a writer is queued (see the
+    /// writer-preference branch of [`Detector::attempt_read`]), but provides
+    /// no bound on how long the writer itself can be made to wait if the
+    /// *already-admitted* readers keep overlapping one another indefinitely.
+    /// This has nothing to do with the wait-for graph - every reader keeps
+    /// making progress, so there's no cycle - so unlike the rest of this
+    /// module it isn't checked at every lock event: call it periodically (or
+    /// after a suspiciously long write attempt) with the threshold past
+    /// which a wait is considered starved.
+    ///
+    /// # Returns
+    /// The first writer found waiting at least `threshold` with at least one
+    /// reader having churned through the lock since it started waiting, or
+    /// `None` if no writer is currently starved.
+    pub fn check_writer_starvation(&mut self, threshold: Duration) -> Option<DeadlockInfo> {
+        let now = Instant::now();
+        let (writer, lock_id, waited) = self.rwlock_writer_wait_start.iter().find_map(
+            |(&writer, &(lock_id, start, churn))| {
+                let waited = now.duration_since(start);
+                (churn > 0 && waited >= threshold).then_some((writer, lock_id, waited))
+            },
+        )?;
+
+        let readers = self
+            .rwlock_readers
+            .get(&lock_id)
+            .map(|r| r.iter().copied().collect())
+            .unwrap_or_default();
+
+        Some(self.extract_writer_starvation_info(writer, lock_id, readers, waited))
+    }
+
+    /// Upgradable read lock attempt and try-acquire operation
+    ///
+    /// Mutually exclusive with both the writer and any other upgradable reader,
+    /// but coexists with ordinary readers.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread attempting to acquire the upgradable read lock
+    /// * `lock_id` - ID of the RwLock being attempted
+    /// * `try_acquire_fn` - Closure that attempts non-blocking upgradable read acquisition
+    pub fn attempt_upgradable_read<T, F>(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+        try_acquire_fn: F,
+    ) -> Result<Option<T>, Vec<ThreadId>>
+    where
+        F: FnOnce() -> Option<T>,
+    {
+        logger::log_interaction_event(thread_id, lock_id, Events::RwUpgradableAttempt);
+
+        // Check for a conflicting writer or upgradable reader (Global State)
+        let blocker = self
+            .rwlock_writer
+            .get(&lock_id)
+            .copied()
+            .or_else(|| self.rwlock_upgradable.get(&lock_id).copied());
+
+        if let Some(blocker) = blocker
+            && blocker != thread_id
+        {
+            self.thread_waits_for.insert(thread_id, lock_id);
+            self.lock_waiters
+                .entry(lock_id)
+                .or_default()
+                .insert(thread_id);
+
+            if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, blocker) {
+                let filtered_cycle = self.filter_cycle_by_common_locks(&cycle);
+                if !filtered_cycle.is_empty() {
+                    return Err(cycle);
+                }
+            }
+
+            return Ok(None);
+        }
+
+        if let Some(guard) = try_acquire_fn() {
+            self.rwlock_upgradable.insert(lock_id, thread_id);
+            #[cfg(feature = "lock-order-graph")]
+            self.thread_holds
+                .entry(thread_id)
+                .or_default()
+                .insert(lock_id);
+
+            self.thread_waits_for.remove(&thread_id);
+            logger::log_interaction_event(thread_id, lock_id, Events::RwUpgradableAcquired);
+
+            Ok(Some(guard))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Update detector state after blocking upgradable read lock acquisition
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread that acquired the upgradable read lock
+    /// * `lock_id` - ID of the RwLock
+    pub fn complete_upgradable_read(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        self.rwlock_upgradable.insert(lock_id, thread_id);
+        #[cfg(feature = "lock-order-graph")]
+        self.thread_holds
+            .entry(thread_id)
+            .or_default()
+            .insert(lock_id);
+
+        self.thread_waits_for.remove(&thread_id);
+        if let Some(waiters) = self.lock_waiters.get_mut(&lock_id) {
+            waiters.remove(&thread_id);
+            if waiters.is_empty() {
+                self.lock_waiters.remove(&lock_id);
+            }
+        }
+
+        logger::log_interaction_event(thread_id, lock_id, Events::RwUpgradableAcquired);
+    }
+
+    /// Register an upgradable read lock release (without upgrading) by a thread
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread releasing the upgradable read lock
+    /// * `lock_id` - ID of the RwLock being released
+    pub fn release_upgradable_read(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        logger::log_interaction_event(thread_id, lock_id, Events::RwUpgradableReleased);
+        if self.rwlock_upgradable.get(&lock_id) == Some(&thread_id) {
+            self.rwlock_upgradable.remove(&lock_id);
+        }
+
+        #[cfg(feature = "lock-order-graph")]
+        if let Some(holds) = self.thread_holds.get_mut(&thread_id) {
+            holds.remove(&lock_id);
+            if holds.is_empty() {
+                self.thread_holds.remove(&thread_id);
+            }
+        }
+
+        // Remove stale edges for all threads waiting on this lock
+        if let Some(waiters) = self.lock_waiters.get(&lock_id) {
+            for &waiter in waiters {
+                self.wait_for_graph.remove_edge(waiter, thread_id);
+            }
+        }
+
+        #[cfg(feature = "stress-test")]
+        self.stress_on_lock_release(thread_id, lock_id);
+    }
+
+    /// Register an in-progress upgrade from an upgradable read lock to a write lock
+    ///
+    /// The upgrading thread is modeled in the wait-for graph as waiting on *every*
+    /// current reader thread, so two threads that each hold an upgradable read and
+    /// try to upgrade simultaneously are correctly reported as a deadlock cycle.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread upgrading its upgradable read lock
+    /// * `lock_id` - ID of the RwLock being upgraded
+    pub fn acquire_upgrade_slow(
+        &mut self,
+        thread_id: ThreadId,
+        lock_id: LockId,
+    ) -> Option<DeadlockInfo> {
+        logger::log_interaction_event(thread_id, lock_id, Events::RwUpgradableAttempt);
+
+        if let Some(readers) = self.rwlock_readers.get(&lock_id) {
+            for &reader in readers {
+                if reader != thread_id {
+                    self.thread_waits_for.insert(thread_id, lock_id);
+                    self.lock_waiters
+                        .entry(lock_id)
+                        .or_default()
+                        .insert(thread_id);
+                    if let Some(cycle) = self.wait_for_graph.add_edge(thread_id, reader) {
+                        return Some(self.extract_deadlock_info(cycle));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Update detector state after an upgradable read lock finishes upgrading to a write lock
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread that completed the upgrade
+    /// * `lock_id` - ID of the RwLock
+    pub fn complete_upgrade(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        self.rwlock_upgradable.remove(&lock_id);
+        self.rwlock_writer.insert(lock_id, thread_id);
+
+        self.thread_waits_for.remove(&thread_id);
+        if let Some(waiters) = self.lock_waiters.get_mut(&lock_id) {
+            waiters.remove(&thread_id);
+            if waiters.is_empty() {
+                self.lock_waiters.remove(&lock_id);
+            }
+        }
+        self.wait_for_graph.clear_wait_edges(thread_id);
+
+        logger::log_interaction_event(thread_id, lock_id, Events::RwUpgradeAcquired);
+    }
+
+    /// Transition a write lock down to an upgradable read lock, without
+    /// releasing shared access
+    ///
+    /// The reverse of [`Detector::complete_upgrade`]: the thread keeps
+    /// holding the lock (so it's never modeled as waiting on anyone), it
+    /// just changes *kind* from exclusive writer to upgradable reader, which
+    /// matters the next time some other thread checks
+    /// [`Detector::acquire_write_slow`]/[`Detector::acquire_upgrade_slow`]
+    /// against this lock.
+    ///
+    /// # Arguments
+    /// * `thread_id` - ID of the thread downgrading its write lock
+    /// * `lock_id` - ID of the RwLock
+    pub fn downgrade_to_upgradable(&mut self, thread_id: ThreadId, lock_id: LockId) {
+        self.rwlock_writer.remove(&lock_id);
+        self.rwlock_upgradable.insert(lock_id, thread_id);
+
+        logger::log_interaction_event(thread_id, lock_id, Events::RwDowngradedToUpgradable);
+    }
 }
 
 /// Register an RwLock creation with the global detector
@@ -359,12 +945,18 @@ pub fn destroy_rwlock(lock_id: LockId) {
 
 /// Register an RwLock read release with the global detector
 pub fn release_read(thread_id: ThreadId, lock_id: LockId) {
+    #[cfg(feature = "schedule-explore")]
+    crate::core::explore::decision_point(thread_id, lock_id);
+
     let mut detector = GLOBAL_DETECTOR.lock();
     detector.release_read(thread_id, lock_id);
 }
 
 /// Register a RwLock write release with the global detector
 pub fn release_write(thread_id: ThreadId, lock_id: LockId) {
+    #[cfg(feature = "schedule-explore")]
+    crate::core::explore::decision_point(thread_id, lock_id);
+
     let mut detector = GLOBAL_DETECTOR.lock();
     detector.release_write(thread_id, lock_id);
 }
@@ -374,15 +966,28 @@ pub fn release_write(thread_id: ThreadId, lock_id: LockId) {
 /// # Arguments
 /// * `thread_id` - ID of the thread attempting to acquire the read lock
 /// * `lock_id` - ID of the RwLock being attempted
+/// * `transient` - If `true` (a genuinely non-blocking `try_read`), any
+///   wait-for edge set up to check for a cycle is retracted immediately
+///   instead of being left for a `complete_read` that will never come.
 /// * `try_acquire_fn` - Closure that attempts non-blocking read lock acquisition
 ///
 /// # Returns
-/// * `Some(T)` - Read lock was acquired successfully
-/// * `None` - Lock is busy, deadlock detected, or acquisition failed
-pub fn attempt_read<T, F>(thread_id: ThreadId, lock_id: LockId, try_acquire_fn: F) -> Option<T>
+/// * `Some(T)` - Read lock was acquired successfully (a lock-order violation, if any, is
+///   dispatched to the deadlock callback directly rather than affecting the return value)
+/// * `None` - Lock is busy, a wait-for deadlock was detected, or acquisition failed
+pub fn attempt_read<T, F>(
+    thread_id: ThreadId,
+    lock_id: LockId,
+    transient: bool,
+    try_acquire_fn: F,
+) -> Option<T>
 where
     F: FnOnce() -> Option<T>,
 {
+    // 0. Consult the schedule explorer, if enabled
+    #[cfg(feature = "schedule-explore")]
+    crate::core::explore::decision_point(thread_id, lock_id);
+
     // 1. Calculate stress delay (holding lock)
     #[cfg(feature = "stress-test")]
     let delay = {
@@ -399,8 +1004,11 @@ where
     // 3. Proceed with detection (re-acquiring lock)
     let (result, deadlock_info) = {
         let mut detector = GLOBAL_DETECTOR.lock();
-        match detector.attempt_read(thread_id, lock_id, None, try_acquire_fn) {
-            Ok(val) => (val, None),
+        match detector.attempt_read(thread_id, lock_id, None, transient, try_acquire_fn) {
+            Ok((val, order_violation)) => (val, order_violation),
+            Err(cycle) if cycle.as_slice() == [thread_id] => {
+                (None, Some(detector.extract_self_deadlock_info(thread_id, lock_id)))
+            }
             Err(cycle) => (None, Some(detector.extract_deadlock_info(cycle))),
         }
     };
@@ -412,14 +1020,47 @@ where
     result
 }
 
+/// Register a transient, non-blocking write lock acquisition attempt with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread attempting to acquire the write lock
+/// * `lock_id` - ID of the RwLock being attempted
+/// * `potential_writer` - The thread ID observed holding the write lock
+pub fn try_write_attempt(
+    thread_id: ThreadId,
+    lock_id: LockId,
+    potential_writer: Option<ThreadId>,
+) -> Option<DeadlockInfo> {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    let cycle = detector.try_write_attempt(thread_id, lock_id, potential_writer);
+    cycle.map(|cycle| detector.extract_deadlock_info(cycle))
+}
+
+/// Cancel a pending wait-for edge with the global detector after a
+/// time-bounded acquisition gave up
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread that gave up waiting
+/// * `lock_id` - ID of the RwLock it gave up waiting for
+pub fn cancel_acquire(thread_id: ThreadId, lock_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.cancel_wait(thread_id, lock_id);
+}
+
 /// Complete read lock acquisition after blocking
 ///
 /// # Arguments
 /// * `thread_id` - ID of the thread that acquired the read lock
 /// * `lock_id` - ID of the RwLock
 pub fn complete_read(thread_id: ThreadId, lock_id: LockId) {
-    let mut detector = GLOBAL_DETECTOR.lock();
-    detector.complete_read(thread_id, lock_id);
+    let deadlock_info = {
+        let mut detector = GLOBAL_DETECTOR.lock();
+        detector.complete_read(thread_id, lock_id)
+    };
+
+    if let Some(info) = deadlock_info {
+        deadlock_handling::process_deadlock(info);
+    }
 }
 
 /// Register a slow-path write lock acquisition attempt with the global detector
@@ -433,6 +1074,10 @@ pub fn acquire_write_slow(
     lock_id: LockId,
     potential_writer: Option<ThreadId>,
 ) -> Option<DeadlockInfo> {
+    // 0. Consult the schedule explorer, if enabled
+    #[cfg(feature = "schedule-explore")]
+    crate::core::explore::decision_point(thread_id, lock_id);
+
     // 1. Calculate stress delay (holding lock)
     #[cfg(feature = "stress-test")]
     let delay = {
@@ -468,3 +1113,98 @@ pub fn complete_write(thread_id: ThreadId, lock_id: LockId) {
         deadlock_handling::process_deadlock(info);
     }
 }
+
+/// Register an RwLock upgradable-read release with the global detector
+pub fn release_upgradable_read(thread_id: ThreadId, lock_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.release_upgradable_read(thread_id, lock_id);
+}
+
+/// Upgradable read lock attempt and try-acquire with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread attempting to acquire the upgradable read lock
+/// * `lock_id` - ID of the RwLock being attempted
+/// * `try_acquire_fn` - Closure that attempts non-blocking upgradable read acquisition
+pub fn attempt_upgradable_read<T, F>(
+    thread_id: ThreadId,
+    lock_id: LockId,
+    try_acquire_fn: F,
+) -> Option<T>
+where
+    F: FnOnce() -> Option<T>,
+{
+    let (result, deadlock_info) = {
+        let mut detector = GLOBAL_DETECTOR.lock();
+        match detector.attempt_upgradable_read(thread_id, lock_id, try_acquire_fn) {
+            Ok(val) => (val, None),
+            Err(cycle) => (None, Some(detector.extract_deadlock_info(cycle))),
+        }
+    };
+
+    if let Some(info) = deadlock_info {
+        deadlock_handling::process_deadlock(info);
+    }
+
+    result
+}
+
+/// Complete upgradable read lock acquisition after blocking
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread that acquired the upgradable read lock
+/// * `lock_id` - ID of the RwLock
+pub fn complete_upgradable_read(thread_id: ThreadId, lock_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.complete_upgradable_read(thread_id, lock_id);
+}
+
+/// Register an in-progress upgrade with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread upgrading its upgradable read lock
+/// * `lock_id` - ID of the RwLock being upgraded
+pub fn acquire_upgrade_slow(thread_id: ThreadId, lock_id: LockId) -> Option<DeadlockInfo> {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.acquire_upgrade_slow(thread_id, lock_id)
+}
+
+/// Complete an upgrade from an upgradable read lock to a write lock with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread that completed the upgrade
+/// * `lock_id` - ID of the RwLock
+pub fn complete_upgrade(thread_id: ThreadId, lock_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.complete_upgrade(thread_id, lock_id);
+}
+
+/// Register a write-to-upgradable-read downgrade with the global detector
+///
+/// # Arguments
+/// * `thread_id` - ID of the thread downgrading its write lock
+/// * `lock_id` - ID of the RwLock
+pub fn downgrade_to_upgradable(thread_id: ThreadId, lock_id: LockId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.downgrade_to_upgradable(thread_id, lock_id);
+}
+
+/// Check every writer currently queued on an RwLock for starvation with the
+/// global detector, dispatching a `DeadlockSource::WriterStarvation` report
+/// the same way a detected deadlock is dispatched
+///
+/// # Arguments
+/// * `threshold` - How long a writer must have been waiting, with at least
+///   one reader churning through the lock meanwhile, to be reported
+pub fn check_writer_starvation(threshold: Duration) -> Option<DeadlockInfo> {
+    let info = {
+        let mut detector = GLOBAL_DETECTOR.lock();
+        detector.check_writer_starvation(threshold)
+    };
+
+    if let Some(info) = &info {
+        deadlock_handling::process_deadlock(info.clone());
+    }
+
+    info
+}
@@ -1,5 +1,7 @@
 use crate::ThreadId;
 use crate::core::detector::GLOBAL_DETECTOR;
+use crate::core::detector::deadlock_handling;
+use crate::core::types::{DeadlockInfo, Priority};
 use crate::core::{Detector, Events};
 
 impl Detector {
@@ -12,13 +14,32 @@ impl Detector {
     /// # Arguments
     /// * `thread_id` - ID of the newly spawned thread
     /// * `parent_id` - Optional ID of the parent thread that created this thread
-    pub fn on_thread_spawn(&mut self, thread_id: ThreadId, parent_id: Option<ThreadId>) {
+    /// * `priority` - Scheduling priority to record for this thread (see
+    ///   [`Detector::thread_priority`]), normally [`crate::core::types::DEFAULT_PRIORITY`]
+    ///   unless spawned via `crate::thread::spawn_with_priority`
+    pub fn on_thread_spawn(
+        &mut self,
+        thread_id: ThreadId,
+        parent_id: Option<ThreadId>,
+        priority: Priority,
+    ) {
         if let Some(logger) = &self.logger {
             logger.log_thread_event(thread_id, parent_id, Events::Spawn);
         }
 
         // Ensure node exists in the wait-for graph
         self.wait_for_graph.edges.entry(thread_id).or_default();
+        self.thread_priority.insert(thread_id, priority);
+
+        // Seed the child's vector clock from its parent's, so a lock this
+        // child later acquires is correctly seen as happening-after
+        // everything the parent had already observed at spawn time - mirrors
+        // how `record_vclock_acquire` joins in a lock's stored clock.
+        if let Some(parent_id) = parent_id
+            && let Some(parent_clock) = self.thread_vclocks.get(&parent_id).cloned()
+        {
+            self.thread_vclocks.insert(thread_id, parent_clock);
+        }
     }
 
     /// Register a thread exit
@@ -28,15 +49,85 @@ impl Detector {
     ///
     /// # Arguments
     /// * `thread_id` - ID of the exiting thread
-    pub fn on_thread_exit(&mut self, thread_id: ThreadId) {
+    ///
+    /// # Returns
+    /// `Some(info)` if this thread's exit proves some barrier it never
+    /// reached can now never fill (see
+    /// [`Detector::check_barrier_starvation`]), or, failing that, that every
+    /// remaining live thread is now blocked with no possible condvar
+    /// notifier left (see [`Detector::check_condvar_stall`]): a thread's
+    /// death, not just an arrival or a new wait, can be what first makes
+    /// either shortfall provable - this thread may not have been a condvar
+    /// waiter itself, just the last one still free to call `notify`.
+    pub fn on_thread_exit(&mut self, thread_id: ThreadId) -> Option<DeadlockInfo> {
         if let Some(logger) = &self.logger {
             logger.log_thread_event(thread_id, None, Events::Exit);
         }
 
         // remove thread and its edges from the wait-for graph
         self.wait_for_graph.remove_thread(thread_id);
+        #[cfg(feature = "distributed")]
+        if let Some(client) = &self.distributed_client {
+            client.notify_wake(thread_id);
+        }
         // no more held locks
         self.thread_holds.remove(&thread_id);
+        self.thread_priority.remove(&thread_id);
+
+        self.barrier_waiters
+            .keys()
+            .copied()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .find_map(|barrier_id| self.check_barrier_starvation(barrier_id))
+            .or_else(|| self.check_condvar_stall())
+    }
+
+    /// Register a thread blocking on `JoinHandle::join` for another thread
+    ///
+    /// Inserts a `joiner -> target` edge into the same `wait_for_graph` used
+    /// for lock contention, so a cycle spanning a join and a lock wait (e.g.
+    /// "A holds L, A joins B, B waits for L") is caught by the same BFS that
+    /// catches purely lock-based cycles - lock edges and join edges compose
+    /// naturally since the graph is already thread-to-thread.
+    ///
+    /// Unlike a lock wait-for edge, a join edge has no associated lock, so
+    /// any cycle it completes is never run through
+    /// [`Detector::filter_cycle_by_common_locks`]: that filter only exists
+    /// to rule out threads that merely happen to already share a lock, which
+    /// can't apply to a dependency that isn't about a lock at all.
+    ///
+    /// # Arguments
+    /// * `joiner` - ID of the thread calling `join`
+    /// * `target` - ID of the thread being joined on
+    ///
+    /// # Returns
+    /// `Some(cycle)` if this join completes a real wait-for cycle
+    pub fn on_thread_join(&mut self, joiner: ThreadId, target: ThreadId) -> Option<Vec<ThreadId>> {
+        if let Some(logger) = &self.logger {
+            logger.log_thread_event(joiner, Some(target), Events::JoinBegin);
+        }
+
+        self.wait_for_graph.add_edge(joiner, target)
+    }
+
+    /// Retract a thread-join wait-for edge once the join call returns
+    ///
+    /// `on_thread_exit` already removes every edge touching `target` when it
+    /// actually exits, so by the time a successful `join()` returns this is
+    /// normally a no-op; it exists as a safety net for the (currently
+    /// unreachable, but cheap to guard against) case of a join edge whose
+    /// target never reports exiting.
+    ///
+    /// # Arguments
+    /// * `joiner` - ID of the thread that was blocked in `join`
+    /// * `target` - ID of the thread it was joining on
+    pub fn on_thread_join_complete(&mut self, joiner: ThreadId, target: ThreadId) {
+        if let Some(logger) = &self.logger {
+            logger.log_thread_event(joiner, Some(target), Events::JoinEnd);
+        }
+
+        self.wait_for_graph.remove_edge(joiner, target);
     }
 }
 
@@ -45,16 +136,58 @@ impl Detector {
 /// # Arguments
 /// * `thread_id` - ID of the spawned thread
 /// * `parent_id` - Optional ID of the parent thread that created this thread
-pub fn on_thread_spawn(thread_id: ThreadId, parent_id: Option<ThreadId>) {
+/// * `priority` - Scheduling priority to record for this thread (see
+///   [`Detector::thread_priority`])
+pub fn on_thread_spawn(thread_id: ThreadId, parent_id: Option<ThreadId>, priority: Priority) {
     let mut detector = GLOBAL_DETECTOR.lock();
-    detector.on_thread_spawn(thread_id, parent_id);
+    detector.on_thread_spawn(thread_id, parent_id, priority);
 }
 
 /// Register a thread exit with the global detector
 ///
+/// If this thread's exit proves some barrier it never reached can now never
+/// fill (see [`Detector::check_barrier_starvation`]), or that every
+/// remaining live thread is blocked with no possible condvar notifier left
+/// (see [`Detector::check_condvar_stall`]), dispatches the resulting
+/// `DeadlockInfo` through the callback immediately.
+///
 /// # Arguments
 /// * `thread_id` - ID of the exiting thread
 pub fn on_thread_exit(thread_id: ThreadId) {
     let mut detector = GLOBAL_DETECTOR.lock();
-    detector.on_thread_exit(thread_id);
+    let finding = detector.on_thread_exit(thread_id);
+    drop(detector);
+
+    if let Some(info) = finding {
+        deadlock_handling::process_deadlock(info);
+    }
+}
+
+/// Register a thread blocking on `JoinHandle::join` with the global detector
+///
+/// Dispatches the resulting `DeadlockInfo` through the callback immediately
+/// if this join completes a real wait-for cycle.
+///
+/// # Arguments
+/// * `joiner` - ID of the thread calling `join`
+/// * `target` - ID of the thread being joined on
+pub fn on_thread_join(joiner: ThreadId, target: ThreadId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    let cycle = detector.on_thread_join(joiner, target);
+    let info = cycle.map(|cycle| detector.extract_deadlock_info(cycle));
+    drop(detector);
+
+    if let Some(info) = info {
+        deadlock_handling::process_deadlock(info);
+    }
+}
+
+/// Retract a thread-join wait-for edge with the global detector
+///
+/// # Arguments
+/// * `joiner` - ID of the thread that was blocked in `join`
+/// * `target` - ID of the thread it was joining on
+pub fn on_thread_join_complete(joiner: ThreadId, target: ThreadId) {
+    let mut detector = GLOBAL_DETECTOR.lock();
+    detector.on_thread_join_complete(joiner, target);
 }
@@ -0,0 +1,140 @@
+//! Background Watchdog for Cycle Scans and Stuck-Thread Reporting
+//!
+//! The detector normally only runs cycle detection reactively, at the moment
+//! a thread attempts a tracked lock operation. That misses a deadlock where
+//! the last blocking thread never reaches another tracked acquisition site
+//! (e.g. stuck in a condvar wait that lost its notification, or waiting on a
+//! resource deloxide doesn't model at all): nothing ever fires the event that
+//! would trigger a fresh check.
+//!
+//! This module runs a dedicated background thread, much like
+//! [`super::Dispatcher`], that periodically locks the global detector, scans
+//! the full wait-for graph for a cycle, and separately tracks how long each
+//! currently-blocked thread has been waiting so a stall past a configurable
+//! threshold is reported even without a strict cycle.
+
+use crate::core::detector::GLOBAL_DETECTOR;
+use crate::core::detector::deadlock_handling;
+use crate::core::types::{DeadlockInfo, DeadlockSource, ThreadId, ThreadStall};
+use fxhash::FxHashMap;
+use std::time::{Duration, Instant};
+
+/// Configuration for the background watchdog thread
+///
+/// See [`crate::Deloxide::with_watchdog`].
+#[derive(Debug, Clone, Copy)]
+pub struct WatchdogConfig {
+    /// How often the watchdog scans the global detector
+    pub interval: Duration,
+    /// How long a thread must be continuously observed blocked before it's
+    /// reported as stalled
+    pub stall_threshold: Duration,
+}
+
+impl crate::core::Detector {
+    /// Every thread currently blocked waiting for a lock, rwlock, barrier, or
+    /// condvar notification, alongside whether it's parked on a condvar
+    /// rather than blocked acquiring a lock, and - if it is - whether it's in
+    /// `wait_timeout`/`wait_timeout_while` and will therefore wake on its own
+    /// even without a notify
+    ///
+    /// Unions `thread_waits_for` (mutex/rwlock/barrier waiters) with
+    /// `thread_wait_cv` (condvar waiters), since both are populated by
+    /// different call sites for what is, from the watchdog's point of view,
+    /// the same "this thread is blocked" fact.
+    pub(crate) fn currently_waiting_threads(&self) -> Vec<(ThreadId, bool, bool)> {
+        let mut threads: Vec<(ThreadId, bool, bool)> = self
+            .thread_waits_for
+            .keys()
+            .map(|&thread_id| (thread_id, false, false))
+            .collect();
+        for (&thread_id, &(_, _, has_deadline)) in &self.thread_wait_cv {
+            if let Some(entry) = threads.iter_mut().find(|(id, _, _)| *id == thread_id) {
+                entry.1 = true;
+                entry.2 = has_deadline;
+            } else {
+                threads.push((thread_id, true, has_deadline));
+            }
+        }
+        threads
+    }
+}
+
+/// Spawn the watchdog's background thread
+///
+/// Mirrors [`super::Dispatcher::new`]'s pattern of a dedicated
+/// `std::thread::spawn` loop, except driven by a sleep interval instead of a
+/// channel: there's no event to react to, only the passage of time.
+pub(crate) fn spawn(config: WatchdogConfig) {
+    std::thread::spawn(move || {
+        let mut blocked_since: FxHashMap<ThreadId, Instant> = FxHashMap::default();
+        loop {
+            std::thread::sleep(config.interval);
+            scan(&config, &mut blocked_since);
+        }
+    });
+}
+
+/// Run a single watchdog scan: check for a whole-graph cycle, update
+/// per-thread stall tracking, and dispatch a `DeadlockSource::Watchdog`
+/// report if either found something worth reporting
+fn scan(config: &WatchdogConfig, blocked_since: &mut FxHashMap<ThreadId, Instant>) {
+    let now = Instant::now();
+
+    let (cycle, stalled_threads) = {
+        let mut detector = GLOBAL_DETECTOR.lock();
+        let cycle = detector.wait_for_graph.find_any_cycle();
+        let waiting = detector.currently_waiting_threads();
+        drop(detector);
+
+        // Age out threads that are no longer blocked, and start the clock
+        // for any newly-observed waiter. The watchdog only ever sees
+        // snapshots at `interval` granularity, so "blocked" here means
+        // "blocked every time we've looked since we first noticed it".
+        blocked_since.retain(|thread_id, _| waiting.iter().any(|(id, _, _)| id == thread_id));
+        for &(thread_id, _, _) in &waiting {
+            blocked_since.entry(thread_id).or_insert(now);
+        }
+
+        let stalled_threads: Vec<ThreadStall> = waiting
+            .into_iter()
+            .filter_map(|(thread_id, blocked_on_condvar, recoverable)| {
+                let elapsed = now.duration_since(blocked_since[&thread_id]);
+                (elapsed >= config.stall_threshold).then_some(ThreadStall {
+                    thread_id,
+                    blocked_ms: elapsed.as_millis() as u64,
+                    blocked_on_condvar,
+                    recoverable,
+                })
+            })
+            .collect();
+
+        (cycle, stalled_threads)
+    };
+
+    if cycle.is_none() && stalled_threads.is_empty() {
+        return;
+    }
+
+    let info = DeadlockInfo {
+        source: DeadlockSource::Watchdog,
+        thread_cycle: cycle.unwrap_or_default(),
+        thread_waiting_for_locks: Vec::new(),
+        lock_order_cycle: None,
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        verification_request: None,
+        #[cfg(feature = "distributed")]
+        distributed_cycle: None,
+        lock_sites: Vec::new(),
+        lock_order_sites: Vec::new(),
+        stalled_threads,
+        panic_message: None,
+        priority_chain: Vec::new(),
+        barrier_missing: None,
+            thread_vector_clocks: Vec::new(),
+        #[cfg(feature = "stress-test")]
+        stress_seed: crate::core::stress::active_pct_seed(),
+    };
+
+    deadlock_handling::process_deadlock(info);
+}
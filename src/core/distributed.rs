@@ -0,0 +1,310 @@
+//! Cross-process distributed deadlock detection (`distributed` feature)
+//!
+//! Each participating process keeps tracking deadlocks locally exactly as
+//! before, but also forwards its wait-for edges to a central coordinator
+//! process, modeled loosely on TiKV's central deadlock detector. The
+//! coordinator maintains a single merged [`WaitForGraph`] keyed by a
+//! composite `(ProcessId, ThreadId)` node and runs the same incremental BFS
+//! cycle detection over it, so deadlocks spanning processes that share
+//! OS-level or IPC resources (e.g. a lock file, a named pipe, a database
+//! advisory lock) are caught even though no single process can see the whole
+//! cycle on its own.
+//!
+//! # Wire protocol
+//!
+//! A participant and the coordinator exchange [`WireMessage`] values over a
+//! plain `TcpStream`, each frame length-prefixed with a 4-byte big-endian
+//! length followed by a `serde_json`-encoded payload. Edges are sent as
+//! "wait"/"wake" deltas: a `Wait` message for every local wait-for edge (a
+//! thread starts waiting on a mutex held by another thread of this
+//! process), and a `Wake` message when it stops waiting (lock acquired,
+//! attempt abandoned, or the thread exits).
+//! This lets the coordinator incrementally `add_edge`/`remove_thread` its
+//! merged graph exactly like the in-process detector does with its own.
+//!
+//! # Liveness
+//!
+//! If a participant's connection closes (it crashed or shut down), the
+//! coordinator retracts every node it contributed via `remove_thread`, so a
+//! crashed process can never leave behind a phantom wait-for edge that looks
+//! like a deadlock to the processes still running.
+//!
+//! # Forwarding doesn't block the detector
+//!
+//! `notify_wait`/`notify_wake` are called from `Detector::acquire_slow`/
+//! `complete_acquire` while `GLOBAL_DETECTOR` is held, so they must never
+//! block on the network themselves. [`DistributedClient`] instead mirrors the
+//! `Dispatcher`/watchdog pattern used elsewhere in this crate (see
+//! `crate::core::detector::Dispatcher`): the actual `TcpStream` is owned by a
+//! dedicated background thread that drains an `mpsc` channel and does the
+//! blocking `write_message` call itself, so `notify_wait`/`notify_wake` only
+//! ever do a non-blocking channel send, and are dropped - not queued forever -
+//! if that thread has already exited because the connection broke.
+//!
+//! Incoming frames are bounded too: `read_message` rejects any length prefix
+//! over [`MAX_FRAME_LEN`] before allocating, so a corrupted or malicious
+//! stream can't force an unbounded allocation.
+
+use crate::core::graph::WaitForGraph;
+use crate::core::types::{ProcessId, ThreadId};
+use fxhash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, OnceLock};
+
+/// A node in the coordinator's merged wait-for graph: a thread within a
+/// specific process
+pub type DistributedNodeId = (ProcessId, ThreadId);
+
+/// A length-prefixed message exchanged between a participant and the
+/// coordinator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum WireMessage {
+    /// Sent once, right after connecting, to identify the participant
+    Hello { process_id: ProcessId },
+    /// `from` started waiting for the lock-holding thread `to`
+    Wait {
+        from: DistributedNodeId,
+        to: DistributedNodeId,
+    },
+    /// `thread` is no longer waiting for anything
+    Wake { thread: DistributedNodeId },
+    /// Sent by the coordinator back to every process with a node in the cycle
+    Deadlock { node_cycle: Vec<DistributedNodeId> },
+}
+
+fn write_message(stream: &mut TcpStream, message: &WireMessage) -> io::Result<()> {
+    let payload = serde_json::to_vec(message).map_err(io::Error::other)?;
+    stream.write_all(&(payload.len() as u32).to_be_bytes())?;
+    stream.write_all(&payload)
+}
+
+/// Largest payload `read_message` will allocate for, in bytes
+///
+/// The length prefix comes straight off the wire before the payload is
+/// authenticated in any way, so without a cap a single corrupted or
+/// malicious frame could claim a length near `u32::MAX` and force a
+/// multi-gigabyte allocation. No real [`WireMessage`] comes anywhere close to
+/// this; it's sized generously above the largest plausible `Deadlock` cycle.
+const MAX_FRAME_LEN: u32 = 1 << 20;
+
+fn read_message(stream: &mut TcpStream) -> io::Result<WireMessage> {
+    let mut len_buf = [0u8; 4];
+    stream.read_exact(&mut len_buf)?;
+    let len = u32::from_be_bytes(len_buf);
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame length {len} exceeds MAX_FRAME_LEN ({MAX_FRAME_LEN})"),
+        ));
+    }
+    let mut payload = vec![0u8; len as usize];
+    stream.read_exact(&mut payload)?;
+    serde_json::from_slice(&payload).map_err(io::Error::other)
+}
+
+/// A stable identifier for this OS process, used as the `ProcessId` part of
+/// every node this process contributes to a distributed wait-for graph
+///
+/// Derived once per process from the OS process id, mixed with the address
+/// of a process-local static. The value is cached, so every coordinator and
+/// client started within the same process (as happens in tests) shares it.
+pub fn local_process_id() -> ProcessId {
+    static PROCESS_ID: OnceLock<ProcessId> = OnceLock::new();
+    *PROCESS_ID.get_or_init(|| {
+        let pid = std::process::id() as u64;
+        let nonce = std::ptr::addr_of!(PROCESS_ID) as u64;
+        pid ^ nonce.rotate_left(32)
+    })
+}
+
+/// The central coordinator for cross-process deadlock detection
+///
+/// Accepts a TCP connection from each participating process, merges their
+/// wait-for edges into a single [`WaitForGraph<DistributedNodeId>`], and runs
+/// the same incremental cycle detection the in-process detector uses.
+pub struct Coordinator {
+    graph: std::sync::Mutex<WaitForGraph<DistributedNodeId>>,
+    /// Threads contributed by each connected process, so a dropped connection
+    /// can retract exactly the nodes it owns
+    process_threads: std::sync::Mutex<FxHashMap<ProcessId, FxHashSet<ThreadId>>>,
+    /// Live connections, used to deliver a detected cycle back to every
+    /// process that participates in it
+    connections: std::sync::Mutex<FxHashMap<ProcessId, TcpStream>>,
+}
+
+impl Coordinator {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            graph: std::sync::Mutex::new(WaitForGraph::new()),
+            process_threads: std::sync::Mutex::new(FxHashMap::default()),
+            connections: std::sync::Mutex::new(FxHashMap::default()),
+        })
+    }
+
+    /// Start a coordinator listening for participant connections on `bind_addr`
+    ///
+    /// Spawns a background thread that accepts connections (one more
+    /// background thread per connection) and returns immediately.
+    pub fn start<A: ToSocketAddrs>(bind_addr: A) -> io::Result<Arc<Self>> {
+        let listener = TcpListener::bind(bind_addr)?;
+        let coordinator = Self::new();
+
+        let accept_coordinator = Arc::clone(&coordinator);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let coordinator = Arc::clone(&accept_coordinator);
+                std::thread::spawn(move || coordinator.handle_connection(stream));
+            }
+        });
+
+        Ok(coordinator)
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream) {
+        let process_id = match read_message(&mut stream) {
+            Ok(WireMessage::Hello { process_id }) => process_id,
+            _ => return,
+        };
+
+        if let Ok(clone) = stream.try_clone() {
+            self.connections.lock().unwrap().insert(process_id, clone);
+        }
+
+        loop {
+            match read_message(&mut stream) {
+                Ok(WireMessage::Wait { from, to }) => self.on_wait(from, to),
+                Ok(WireMessage::Wake { thread }) => self.on_wake(thread),
+                Ok(WireMessage::Hello { .. }) | Ok(WireMessage::Deadlock { .. }) | Err(_) => break,
+            }
+        }
+
+        self.on_process_disconnected(process_id);
+    }
+
+    fn on_wait(&self, from: DistributedNodeId, to: DistributedNodeId) {
+        self.process_threads
+            .lock()
+            .unwrap()
+            .entry(from.0)
+            .or_default()
+            .insert(from.1);
+
+        let cycle = self.graph.lock().unwrap().add_edge(from, to);
+        if let Some(node_cycle) = cycle {
+            self.broadcast_deadlock(node_cycle);
+        }
+    }
+
+    fn on_wake(&self, thread: DistributedNodeId) {
+        self.graph.lock().unwrap().clear_wait_edges(thread);
+    }
+
+    fn on_process_disconnected(&self, process_id: ProcessId) {
+        self.connections.lock().unwrap().remove(&process_id);
+
+        if let Some(threads) = self.process_threads.lock().unwrap().remove(&process_id) {
+            let mut graph = self.graph.lock().unwrap();
+            for thread_id in threads {
+                graph.remove_thread((process_id, thread_id));
+            }
+        }
+    }
+
+    fn broadcast_deadlock(&self, node_cycle: Vec<DistributedNodeId>) {
+        let participants: FxHashSet<ProcessId> = node_cycle.iter().map(|&(pid, _)| pid).collect();
+        let message = WireMessage::Deadlock { node_cycle };
+
+        let mut connections = self.connections.lock().unwrap();
+        for process_id in participants {
+            if let Some(stream) = connections.get_mut(&process_id) {
+                let _ = write_message(stream, &message);
+            }
+        }
+    }
+}
+
+/// A connection from one participating process to the distributed
+/// coordinator
+///
+/// Forwards this process's local wait-for edges as they happen, and invokes
+/// a callback whenever the coordinator reports a cross-process cycle.
+///
+/// The `TcpStream` half used for writing is owned entirely by a background
+/// writer thread (see [`Self::connect`]); `notify_wait`/`notify_wake` only
+/// ever hand a message to that thread over `writer`, an unbounded channel, so
+/// they can be called from `Detector::acquire_slow`/`complete_acquire` while
+/// `GLOBAL_DETECTOR` is held without risking blocking every tracked thread in
+/// the process on a slow or wedged socket.
+pub struct DistributedClient {
+    process_id: ProcessId,
+    writer: Sender<WireMessage>,
+}
+
+impl DistributedClient {
+    /// Connect to a coordinator at `coordinator_addr`
+    ///
+    /// Spawns a background thread that listens for `Deadlock` reports from
+    /// the coordinator and invokes `on_deadlock` for each one, and a second
+    /// background thread that owns the write half of the connection and
+    /// drains outgoing `Wait`/`Wake` messages from a channel - see the module
+    /// docs for why the write side needs its own thread.
+    pub fn connect<A, F>(coordinator_addr: A, on_deadlock: F) -> io::Result<Arc<Self>>
+    where
+        A: ToSocketAddrs,
+        F: Fn(Vec<DistributedNodeId>) + Send + Sync + 'static,
+    {
+        let mut stream = TcpStream::connect(coordinator_addr)?;
+        let process_id = local_process_id();
+        write_message(&mut stream, &WireMessage::Hello { process_id })?;
+
+        let mut reader_stream = stream.try_clone()?;
+        std::thread::spawn(move || {
+            while let Ok(WireMessage::Deadlock { node_cycle }) = read_message(&mut reader_stream) {
+                on_deadlock(node_cycle);
+            }
+        });
+
+        let (tx, rx) = mpsc::channel::<WireMessage>();
+        std::thread::spawn(move || {
+            while let Ok(message) = rx.recv() {
+                let _ = write_message(&mut stream, &message);
+            }
+        });
+
+        Ok(Arc::new(Self {
+            process_id,
+            writer: tx,
+        }))
+    }
+
+    /// Forward a local wait-for edge to the coordinator: `thread_id` started
+    /// waiting for the thread (in this same process) currently holding the lock
+    ///
+    /// Only ever hands `message` to the background writer thread; never
+    /// touches the socket itself, so this can't block the caller. The send
+    /// only fails if that thread has already exited (the connection broke),
+    /// in which case the edge is silently dropped rather than queued forever.
+    pub fn notify_wait(&self, thread_id: ThreadId, owner_thread_id: ThreadId) {
+        let message = WireMessage::Wait {
+            from: (self.process_id, thread_id),
+            to: (self.process_id, owner_thread_id),
+        };
+        let _ = self.writer.send(message);
+    }
+
+    /// Forward the retraction of a local wait-for edge: `thread_id` is no
+    /// longer waiting for anything (lock acquired, attempt abandoned, or the
+    /// thread exited)
+    ///
+    /// See [`Self::notify_wait`]: non-blocking, best-effort delivery via the
+    /// background writer thread.
+    pub fn notify_wake(&self, thread_id: ThreadId) {
+        let message = WireMessage::Wake {
+            thread: (self.process_id, thread_id),
+        };
+        let _ = self.writer.send(message);
+    }
+}
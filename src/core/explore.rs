@@ -0,0 +1,249 @@
+// src/core/explore.rs
+// Deterministic, systematically-varied schedule exploration for RwLock
+// decision points. Only compiled when the "schedule-explore" feature is
+// enabled.
+//
+// Unlike `stress-test`'s `StressMode`, which perturbs timing randomly (or
+// from a single seeded PRNG stream) to *increase the odds* of hitting a rare
+// interleaving, this module drives the same decision points through every
+// combination of "yield here or don't" up to a configurable bound, the way
+// loom explores a program's schedule space: each run is a fixed sequence of
+// binary choices, and the next run is obtained by incrementing that sequence
+// like a binary counter, so repeated calls to [`explore`] eventually cover
+// every reachable choice combination within the bound instead of relying on
+// chance to revisit one.
+
+use crate::core::types::{LockId, ThreadId};
+use parking_lot::Mutex;
+use std::thread;
+
+/// One decision made at an instrumented RwLock decision point: which thread
+/// was asking, which lock it was asking about, and whether the scheduler
+/// chose to yield it there.
+pub type Choice = (ThreadId, LockId, bool);
+
+struct ExploreState {
+    /// The fixed choice, for each decision point index already decided by
+    /// the current run, of whether to yield. `None` once the run has walked
+    /// past the end of this path - those decision points default to `false`
+    /// (no yield) and get appended to the path as they're made, the same way
+    /// loom's scheduler extends a partial schedule it is still exploring.
+    path: Vec<bool>,
+    /// How many decision points the current run has hit so far; indexes into
+    /// `path`.
+    cursor: usize,
+    /// The full `(ThreadId, LockId, yielded)` trace of the current (or most
+    /// recently finished) run, recorded so it can be persisted verbatim if
+    /// that run turns out to hit a deadlock.
+    trace: Vec<Choice>,
+    /// When `Some`, decisions are drawn from this externally-supplied trace
+    /// instead of `path`, reproducing a previously recorded run bit-for-bit
+    /// regardless of the thread/lock IDs it was recorded against.
+    replay: Option<(Vec<Choice>, usize)>,
+}
+
+impl ExploreState {
+    fn new() -> Self {
+        ExploreState {
+            path: Vec::new(),
+            cursor: 0,
+            trace: Vec::new(),
+            replay: None,
+        }
+    }
+
+    fn decide(&mut self, thread_id: ThreadId, lock_id: LockId) -> bool {
+        let yielded = if let Some((recorded, idx)) = &mut self.replay {
+            let choice = recorded.get(*idx).map(|&(_, _, y)| y).unwrap_or(false);
+            *idx += 1;
+            choice
+        } else {
+            let choice = match self.path.get(self.cursor) {
+                Some(&choice) => choice,
+                None => {
+                    self.path.push(false);
+                    false
+                }
+            };
+            self.cursor += 1;
+            choice
+        };
+
+        self.trace.push((thread_id, lock_id, yielded));
+        yielded
+    }
+
+    /// Reset per-run state ahead of a fresh call to the explored closure,
+    /// keeping `path` (and `replay`, if installed) so the next run continues
+    /// either the systematic walk or the externally-forced replay.
+    fn start_run(&mut self) {
+        self.cursor = 0;
+        self.trace.clear();
+        if let Some((_, idx)) = &mut self.replay {
+            *idx = 0;
+        }
+    }
+
+    /// Advance `path` to the next combination in the enumeration, the same
+    /// way one increments a binary counter from its least-significant digit:
+    /// flip trailing `true`s to `false` and the first `false` found (from the
+    /// end) to `true`. Once every bit is `true`, the path is exhausted and
+    /// `false` is returned - the caller should stop exploring.
+    fn advance(&mut self) -> bool {
+        let mut i = self.path.len();
+        while i > 0 {
+            i -= 1;
+            if !self.path[i] {
+                self.path[i] = true;
+                return true;
+            }
+            self.path[i] = false;
+        }
+        false
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref EXPLORE_STATE: Mutex<ExploreState> = Mutex::new(ExploreState::new());
+}
+
+/// Consult the scheduler at an instrumented decision point (`attempt_read`,
+/// `acquire_write_slow`, and the release paths) and, if it chooses to, yield
+/// the calling thread so a waiting peer gets a chance to run first. Returns
+/// whether a yield was inserted, purely so callers already holding a value
+/// for logging can report it; the yield itself is performed here.
+pub fn decision_point(thread_id: ThreadId, lock_id: LockId) -> bool {
+    let yielded = EXPLORE_STATE.lock().decide(thread_id, lock_id);
+    if yielded {
+        thread::yield_now();
+    }
+    yielded
+}
+
+/// The `(ThreadId, LockId, yielded)` trace of decisions made so far in the
+/// run currently in progress (or just finished). Call this immediately after
+/// a run that turned out to hit a deadlock, to persist the exact choice
+/// sequence that produced it.
+pub fn current_trace() -> Vec<Choice> {
+    EXPLORE_STATE.lock().trace.clone()
+}
+
+/// Install `trace` as the decision source for every subsequent call to
+/// [`decision_point`], replaying it verbatim (in recorded order, regardless
+/// of which thread or lock a given entry was originally recorded against) so
+/// the exact interleaving that produced it can be reproduced bit-for-bit.
+/// Overrides the systematic walk driven by [`explore`] until the next
+/// [`reset`].
+pub fn replay(trace: Vec<Choice>) {
+    let mut state = EXPLORE_STATE.lock();
+    state.replay = Some((trace, 0));
+}
+
+/// Drop any installed [`replay`] trace and restart the systematic walk from
+/// its beginning.
+pub fn reset() {
+    let mut state = EXPLORE_STATE.lock();
+    *state = ExploreState::new();
+}
+
+/// The outcome of a bounded [`explore`] call.
+pub struct ExploreOutcome {
+    /// How many distinct interleavings were actually run before either a
+    /// deadlock was found or the bound was hit.
+    pub runs_explored: usize,
+    /// `true` if every reachable combination of yield/no-yield decisions was
+    /// exhausted before `max_interleavings` was reached, i.e. the bound
+    /// didn't cut the search short.
+    pub exhausted: bool,
+    /// The `(ThreadId, LockId, yielded)` choice sequence of the run that hit
+    /// a deadlock, if `run` ever returned `true`.
+    pub deadlock_trace: Option<Vec<Choice>>,
+}
+
+/// Systematically vary the branch taken at each decision point across up to
+/// `max_interleavings` runs of `run`, depth-first over the choice tree: the
+/// first run takes the default (no-yield) path everywhere, and each
+/// following run is the next combination in the enumeration, obtained by
+/// incrementing the previous one like a binary counter. `run` should drive
+/// one full execution of the code under test and return `true` if it
+/// observed a deadlock.
+///
+/// Stops as soon as `run` reports a deadlock, as soon as the choice tree is
+/// exhausted, or once `max_interleavings` runs have been performed, whichever
+/// comes first.
+pub fn explore(max_interleavings: usize, mut run: impl FnMut() -> bool) -> ExploreOutcome {
+    reset();
+
+    let mut runs_explored = 0;
+    let mut exhausted = false;
+    let mut deadlock_trace = None;
+
+    while runs_explored < max_interleavings {
+        EXPLORE_STATE.lock().start_run();
+        runs_explored += 1;
+
+        if run() {
+            deadlock_trace = Some(current_trace());
+            break;
+        }
+
+        if !EXPLORE_STATE.lock().advance() {
+            exhausted = true;
+            break;
+        }
+    }
+
+    ExploreOutcome {
+        runs_explored,
+        exhausted,
+        deadlock_trace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explore_enumerates_every_combination_until_exhausted() {
+        let mut seen = std::collections::HashSet::new();
+        let outcome = explore(100, || {
+            let a = decision_point(1, 1);
+            let b = decision_point(1, 2);
+            seen.insert((a, b));
+            false
+        });
+
+        assert!(outcome.exhausted);
+        assert_eq!(outcome.runs_explored, 4);
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[test]
+    fn test_explore_stops_early_and_captures_the_deadlock_trace() {
+        let mut run_number = 0;
+        let outcome = explore(100, || {
+            run_number += 1;
+            let yielded = decision_point(7, 3);
+            yielded && run_number == 2
+        });
+
+        assert!(!outcome.exhausted);
+        assert_eq!(outcome.runs_explored, 2);
+        assert_eq!(outcome.deadlock_trace, Some(vec![(7, 3, true)]));
+    }
+
+    #[test]
+    fn test_replay_forces_the_recorded_choice_sequence() {
+        reset();
+        replay(vec![(1, 1, true), (1, 1, false), (1, 1, true)]);
+
+        assert!(decision_point(1, 1));
+        assert!(!decision_point(1, 1));
+        assert!(decision_point(1, 1));
+        // Exhausted replay defaults to "don't yield" rather than panicking.
+        assert!(!decision_point(1, 1));
+
+        reset();
+    }
+}
@@ -9,20 +9,22 @@
 //! When a thread holds lock A and then acquires lock B, we record that A < B.
 //! If later we see an attempt to acquire A while holding B (B < A), this creates
 //! a cycle in the lock order and indicates a potential deadlock.
-
+//!
+//! # Incremental cycle detection
+//!
+//! Rather than re-running a graph search from scratch on every insert, the
+//! graph maintains `ord`: a valid topological order of every lock touched so
+//! far, using the Pearce-Kelly dynamic topological sort algorithm. Inserting
+//! `before -> after` is O(1) whenever it's already consistent with `ord`
+//! (`ord[before] < ord[after]`); otherwise a search bounded to the region
+//! between the two positions either finds a cycle or lets just that region
+//! be reordered. See [`LockOrderGraph::add_edge_at_with_record`].
+
+use crate::core::stacktrace::{self, StackTraceId};
 use crate::core::types::LockId;
 use fxhash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 
-/// Cache entry for cycle detection results
-#[derive(Debug, Clone)]
-struct CacheEntry {
-    /// Generation when this entry was created
-    generation: u64,
-    /// The cycle found, or None if no cycle
-    result: Option<Vec<LockId>>,
-}
-
 /// Represents a directed edge in the lock order graph
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LockOrderEdge {
@@ -50,13 +52,46 @@ pub struct LockOrderGraph {
     /// All recorded edges for debugging and reporting
     all_edges: FxHashSet<LockOrderEdge>,
 
-    /// Optimization 2: Cache for cycle detection results
-    /// Key: (before, after), Value: cached result with generation
-    cycle_cache: FxHashMap<(LockId, LockId), CacheEntry>,
-
-    /// Generation counter, incremented on each edge addition
-    /// Used to invalidate stale cache entries
-    generation: u64,
+    /// Backtrace captured at the moment each edge was first inserted, if
+    /// backtrace capture was enabled with `Deloxide::with_backtraces()` at
+    /// the time. Lets a reported cycle point at the call sites that
+    /// established each conflicting ordering. The site of a recorded
+    /// cycle-closing edge is kept here too, even though the edge itself is
+    /// never inserted into `edges`.
+    edge_sites: FxHashMap<LockOrderEdge, StackTraceId>,
+
+    /// Pearce-Kelly dynamic topological order: the position of each lock
+    /// seen so far in a valid topological order of all recorded edges
+    /// (i.e. for every recorded edge `u -> v`, `ord[u] < ord[v]`). Updated
+    /// incrementally on every insert instead of being recomputed from
+    /// scratch; see [`LockOrderGraph::add_edge_at_with_record`].
+    ord: FxHashMap<LockId, usize>,
+
+    /// The next never-before-used position to hand a lock seen for the
+    /// first time. Monotonically increasing - a removed lock's position is
+    /// never reclaimed (see [`LockOrderGraph::remove_lock`]), it just
+    /// leaves a gap, which is harmless since `ord` only needs to preserve
+    /// relative order, not be contiguous.
+    next_ord: usize,
+
+    /// Every ordered acquisition pair ever observed, independent of `edges`
+    ///
+    /// `edges` is kept provably acyclic by construction: whenever an insert
+    /// would close a cycle, [`LockOrderGraph::add_edge_at_with_record`]
+    /// reports it but never actually inserts the closing edge, so a Tarjan
+    /// pass over `edges` itself could never find anything. This mirrors the
+    /// "record everything, analyze later" style of tools like wgpu-core's
+    /// `observe_locks` instead: it's populated unconditionally, including
+    /// pairs that would have closed a cycle, so [`LockOrderGraph::detect_all_violations`]
+    /// has the complete observed history to run a real SCC pass over.
+    raw_adjacency: FxHashMap<LockId, FxHashSet<LockId>>,
+
+    // Cached buffers for Tarjan's SCC algorithm (see `detect_all_violations`),
+    // reused across calls instead of allocated fresh each time.
+    tarjan_index: FxHashMap<LockId, usize>,
+    tarjan_lowlink: FxHashMap<LockId, usize>,
+    tarjan_on_stack: FxHashSet<LockId>,
+    tarjan_stack: Vec<LockId>,
 }
 
 impl LockOrderGraph {
@@ -66,9 +101,29 @@ impl LockOrderGraph {
             edges: FxHashMap::default(),
             reverse_edges: FxHashMap::default(),
             all_edges: FxHashSet::default(),
-            cycle_cache: FxHashMap::default(),
-            generation: 0,
+            edge_sites: FxHashMap::default(),
+            ord: FxHashMap::default(),
+            next_ord: 0,
+            raw_adjacency: FxHashMap::default(),
+            tarjan_index: FxHashMap::default(),
+            tarjan_lowlink: FxHashMap::default(),
+            tarjan_on_stack: FxHashSet::default(),
+            tarjan_stack: Vec::with_capacity(64),
+        }
+    }
+
+    /// The position of `lock` in the topological order, assigning it the
+    /// next never-before-used position if this is the first time it's been
+    /// seen. A lock with no recorded edges yet is free to go anywhere, so
+    /// appending it at the end is always valid.
+    fn ord_of(&mut self, lock: LockId) -> usize {
+        if let Some(&position) = self.ord.get(&lock) {
+            return position;
         }
+        let position = self.next_ord;
+        self.next_ord += 1;
+        self.ord.insert(lock, position);
+        position
     }
 
     /// Add an edge to the lock order graph indicating that `before` must be acquired before `after`
@@ -81,89 +136,120 @@ impl LockOrderGraph {
     /// `Some(Vec<LockId>)` containing a cycle if adding this edge creates a lock order violation,
     /// `None` if the edge is valid and doesn't create a cycle
     pub fn add_edge(&mut self, before: LockId, after: LockId) -> Option<Vec<LockId>> {
+        self.add_edge_at(before, after, stacktrace::capture())
+    }
+
+    /// Like [`LockOrderGraph::add_edge`], but with an explicit capture site
+    /// instead of capturing one itself
+    ///
+    /// Lets a caller that's already captured a backtrace for this acquisition
+    /// (e.g. to attach to [`ThreadLockSite`](crate::core::types::ThreadLockSite))
+    /// reuse it here instead of capturing twice.
+    pub fn add_edge_at(
+        &mut self,
+        before: LockId,
+        after: LockId,
+        site: Option<StackTraceId>,
+    ) -> Option<Vec<LockId>> {
+        self.add_edge_at_with_record(before, after, site, true)
+    }
+
+    /// Like [`LockOrderGraph::add_edge_at`], but `record` controls whether a
+    /// *non-cyclic* edge (and any topological reordering it requires) is
+    /// actually applied to the graph, or this call is a pure probe.
+    ///
+    /// A cyclic edge is never recorded regardless of `record` - see below -
+    /// so this only matters for the fail-fast
+    /// [`LockOrderViolationPolicy`](crate::core::types::LockOrderViolationPolicy)
+    /// `Panic`/`Abort` policies: they probe with `record: false` so that if
+    /// the resulting panic is caught higher up and the program keeps
+    /// running, no edge, edge site or reordering from the offending
+    /// acquisition is applied, and the same pair is detected (and panics)
+    /// again on its next attempt instead of being silently cached away.
+    /// Even a probe still adds the pair to `raw_adjacency` and gives a
+    /// newly seen lock its position in `ord`.
+    pub(crate) fn add_edge_at_with_record(
+        &mut self,
+        before: LockId,
+        after: LockId,
+        site: Option<StackTraceId>,
+        record: bool,
+    ) -> Option<Vec<LockId>> {
         // Don't add self-edges
         if before == after {
             return None;
         }
 
-        // Optimization 2: Check cache first
-        let cache_key = (before, after);
-        if let Some(cached) = self.cycle_cache.get(&cache_key) {
-            // Cache hit! Check if still valid (same generation means no new edges since)
-            if cached.generation == self.generation {
-                return cached.result.clone();
+        // Recorded unconditionally (even for a pair that will turn out to
+        // close a cycle below) so `detect_all_violations` has the complete
+        // observed ordering history to audit, independent of what `edges`
+        // itself ends up holding.
+        self.raw_adjacency.entry(before).or_default().insert(after);
+
+        let ord_before = self.ord_of(before);
+        let ord_after = self.ord_of(after);
+
+        // Already consistent with the existing topological order: no search
+        // needed at all.
+        if ord_before < ord_after {
+            if record {
+                self.insert_edge(before, after, site);
             }
+            return None;
         }
 
-        // Check if adding this edge would create a cycle (i.e., if there's already a path from `after` to `before`)
-        let cycle_result = if let Some(cycle) = self.find_path(after, before) {
-            // Found a cycle: there's already a path after -> ... -> before
-            // Adding before -> after would complete the cycle
-            let mut full_cycle = cycle;
-            full_cycle.push(after); // Close the cycle
-            Some(full_cycle)
-        } else {
-            None
-        };
-
-        // Cache the result before modifying the graph
-        self.cycle_cache.insert(
-            cache_key,
-            CacheEntry {
-                generation: self.generation,
-                result: cycle_result.clone(),
-            },
-        );
-
-        // If no cycle, record the edge
-        if cycle_result.is_none() {
-            let edge = LockOrderEdge { before, after };
-            if self.all_edges.insert(edge) {
-                // This is a new edge, add it to the adjacency lists
-                self.edges.entry(before).or_default().insert(after);
-                self.reverse_edges.entry(after).or_default().insert(before);
-
-                // Increment generation to invalidate cache entries
-                // (they're based on the old graph state)
-                self.generation = self.generation.wrapping_add(1);
-
-                // Optimization 3: Incremental cache invalidation
-                // Only invalidate cache entries that might be affected
-                // For now, we invalidate all (future: only invalidate paths through new edge)
-                if self.cycle_cache.len() > 1000 {
-                    // Clear cache if it gets too large
-                    self.cycle_cache.clear();
+        // `before` and `after` are out of order. Search only the region that
+        // could possibly contain a path back from `after` to `before`: since
+        // `ord` was valid before this insert, any such path must stay within
+        // positions `(ord_after, ord_before]`.
+        match self.find_path_bounded(after, before, ord_before) {
+            Ok(mut cycle) => {
+                // Found a path after -> ... -> before; adding before -> after
+                // would close the cycle.
+                cycle.push(after);
+                // The closing edge is never inserted, but its capture site is
+                // kept so `edge_sites_for_cycle` can report it.
+                if record && let Some(site) = site {
+                    self.edge_sites
+                        .entry(LockOrderEdge { before, after })
+                        .or_insert(site);
                 }
+                Some(cycle)
+            }
+            Err(forward_set) => {
+                if record {
+                    let backward_set = self.collect_backward(before, ord_after);
+                    self.reorder_affected_region(backward_set, forward_set);
+                    self.insert_edge(before, after, site);
+                }
+                None
             }
         }
-
-        cycle_result
     }
 
-    /// Find a path from `start` to `end` in the lock order graph using BFS
+    /// Forward search from `start` toward `target`, restricted to positions
+    /// strictly less than `ord_limit` (`target`'s position)
     ///
-    /// Optimization 3: Early termination and edge existence check
-    /// - Returns immediately if start has no outgoing edges
-    /// - Stops as soon as end is found (no need to explore further)
-    ///
-    /// # Arguments
-    /// * `start` - Starting lock
-    /// * `end` - Target lock
+    /// Any existing path from `start` to `target` must stay entirely within
+    /// that bound: `ord` was a valid topological order before this insert,
+    /// so every edge on such a path strictly increases position, and the
+    /// path ends at `target`.
     ///
     /// # Returns
-    /// `Some(Vec<LockId>)` containing the path from start to end if one exists,
-    /// `None` if no path exists
-    fn find_path(&self, start: LockId, end: LockId) -> Option<Vec<LockId>> {
-        if start == end {
-            return Some(vec![start]);
-        }
-
-        // Optimization 3a: Early termination - check if there are any edges from start
-        if !self.edges.contains_key(&start) {
-            return None;
+    /// `Ok(path)` from `start` to `target` (inclusive) if one exists -
+    /// meaning a new edge `target -> start` would close a cycle. Otherwise
+    /// `Err(visited)` with every node reached, which the caller reorders to
+    /// fit the new edge in.
+    fn find_path_bounded(
+        &self,
+        start: LockId,
+        target: LockId,
+        ord_limit: usize,
+    ) -> Result<Vec<LockId>, FxHashSet<LockId>> {
+        if start == target {
+            return Ok(vec![start]);
         }
 
-        // Standard BFS with early termination
         let mut queue = VecDeque::new();
         let mut visited = FxHashSet::default();
         let mut parent: FxHashMap<LockId, LockId> = FxHashMap::default();
@@ -172,32 +258,227 @@ impl LockOrderGraph {
         visited.insert(start);
 
         while let Some(current) = queue.pop_front() {
-            if let Some(neighbors) = self.edges.get(&current) {
-                for &neighbor in neighbors {
-                    if !visited.contains(&neighbor) {
-                        visited.insert(neighbor);
-                        parent.insert(neighbor, current);
-
-                        // Optimization 3b: Early termination - found the target
-                        if neighbor == end {
-                            // Reconstruct path immediately
-                            let mut path = vec![end];
-                            let mut node = end;
-                            while let Some(&prev) = parent.get(&node) {
-                                path.push(prev);
-                                node = prev;
-                            }
-                            path.reverse();
-                            return Some(path);
+            let Some(neighbors) = self.edges.get(&current) else {
+                continue;
+            };
+            for &neighbor in neighbors {
+                if visited.contains(&neighbor) {
+                    continue;
+                }
+                if neighbor == target {
+                    parent.insert(neighbor, current);
+                    let mut path = vec![neighbor];
+                    let mut node = neighbor;
+                    while let Some(&prev) = parent.get(&node) {
+                        path.push(prev);
+                        node = prev;
+                    }
+                    path.reverse();
+                    return Ok(path);
+                }
+                if self.ord.get(&neighbor).copied().unwrap_or(usize::MAX) >= ord_limit {
+                    // Outside the affected region: can't be on a path back
+                    // to `target` without an already-invalid order.
+                    continue;
+                }
+                visited.insert(neighbor);
+                parent.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+
+        Err(visited)
+    }
+
+    /// Backward search from `start` along recorded edges (i.e. through
+    /// predecessors), restricted to positions strictly greater than
+    /// `ord_limit`
+    ///
+    /// Collects the ancestors of `start` that have drifted past `ord_limit`
+    /// - exactly the nodes [`LockOrderGraph::reorder_affected_region`] needs
+    /// to move back before the forward set once the new edge is confirmed
+    /// cycle-free.
+    fn collect_backward(&self, start: LockId, ord_limit: usize) -> FxHashSet<LockId> {
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![start];
+        visited.insert(start);
+
+        while let Some(current) = stack.pop() {
+            let Some(predecessors) = self.reverse_edges.get(&current) else {
+                continue;
+            };
+            for &pred in predecessors {
+                if visited.contains(&pred) {
+                    continue;
+                }
+                if self.ord.get(&pred).copied().unwrap_or(0) <= ord_limit {
+                    continue;
+                }
+                visited.insert(pred);
+                stack.push(pred);
+            }
+        }
+
+        visited
+    }
+
+    /// Reassign positions for `backward_set` and `forward_set` - the two
+    /// regions [`LockOrderGraph::add_edge_at_with_record`] found touched by
+    /// a new, cycle-free edge - so every node in `backward_set` ends up
+    /// before every node in `forward_set`, while each set keeps its own
+    /// current relative order
+    ///
+    /// The two sets are disjoint: a lock in both would mean `after` can
+    /// reach `before`, i.e. the cycle case already handled separately.
+    fn reorder_affected_region(
+        &mut self,
+        backward_set: FxHashSet<LockId>,
+        forward_set: FxHashSet<LockId>,
+    ) {
+        let mut backward: Vec<LockId> = backward_set.into_iter().collect();
+        let mut forward: Vec<LockId> = forward_set.into_iter().collect();
+        backward.sort_unstable_by_key(|lock| self.ord[lock]);
+        forward.sort_unstable_by_key(|lock| self.ord[lock]);
+
+        let mut pool: Vec<usize> = backward
+            .iter()
+            .chain(forward.iter())
+            .map(|lock| self.ord[lock])
+            .collect();
+        pool.sort_unstable();
+
+        for (lock, position) in backward.into_iter().chain(forward).zip(pool) {
+            self.ord.insert(lock, position);
+        }
+    }
+
+    /// Insert `before -> after` into the adjacency lists and backtrace map
+    /// (not the topological order, which the caller is responsible for
+    /// keeping valid beforehand)
+    fn insert_edge(&mut self, before: LockId, after: LockId, site: Option<StackTraceId>) {
+        let edge = LockOrderEdge { before, after };
+        if self.all_edges.insert(edge) {
+            self.edges.entry(before).or_default().insert(after);
+            self.reverse_edges.entry(after).or_default().insert(before);
+            if let Some(site) = site {
+                self.edge_sites.insert(edge, site);
+            }
+        }
+    }
+
+    /// Find every independent lock-order inversion among all acquisition
+    /// pairs ever observed
+    ///
+    /// Runs Tarjan's strongly-connected-components algorithm over
+    /// [`LockOrderGraph::raw_adjacency`] - the complete observed-pair
+    /// history, not [`LockOrderGraph::edges`] (which can never contain a
+    /// cycle by construction) - and returns every strongly connected
+    /// component with more than one lock. Each is a set of locks acquired in
+    /// conflicting orders across different call sites: a potential deadlock
+    /// under some other scheduling, even if this run never actually hit one.
+    ///
+    /// Mirrors [`crate::core::graph::WaitForGraph::detect_all_deadlocks`]'s
+    /// iterative (non-recursive) Tarjan implementation and cached-buffer
+    /// reuse, applied to a different graph.
+    ///
+    /// # Returns
+    /// One `Vec<LockId>` per inverted group, each listing the locks involved
+    /// in no particular order within the group; empty if no inversion has
+    /// been observed.
+    pub fn detect_all_violations(&mut self) -> Vec<Vec<LockId>> {
+        self.tarjan_index.clear();
+        self.tarjan_lowlink.clear();
+        self.tarjan_on_stack.clear();
+        self.tarjan_stack.clear();
+
+        let mut next_index: usize = 0;
+        let mut sccs = Vec::new();
+
+        let roots: Vec<LockId> = self.raw_adjacency.keys().copied().collect();
+        for root in roots {
+            if !self.tarjan_index.contains_key(&root) {
+                self.tarjan_strongconnect(root, &mut next_index, &mut sccs);
+            }
+        }
+
+        sccs
+    }
+
+    /// Iterative Tarjan strongconnect over `raw_adjacency`, using an
+    /// explicit work stack of `(node, not-yet-visited neighbors)` frames
+    /// instead of recursing
+    fn tarjan_strongconnect(
+        &mut self,
+        root: LockId,
+        next_index: &mut usize,
+        sccs: &mut Vec<Vec<LockId>>,
+    ) {
+        let mut work: Vec<(LockId, std::vec::IntoIter<LockId>)> = Vec::new();
+
+        self.tarjan_index.insert(root, *next_index);
+        self.tarjan_lowlink.insert(root, *next_index);
+        *next_index += 1;
+        self.tarjan_stack.push(root);
+        self.tarjan_on_stack.insert(root);
+        work.push((root, self.neighbors_of(root).into_iter()));
+
+        while let Some((node, neighbors)) = work.last_mut() {
+            let node = *node;
+
+            if let Some(neighbor) = neighbors.next() {
+                if !self.tarjan_index.contains_key(&neighbor) {
+                    // Tree edge: descend into the unvisited neighbor
+                    self.tarjan_index.insert(neighbor, *next_index);
+                    self.tarjan_lowlink.insert(neighbor, *next_index);
+                    *next_index += 1;
+                    self.tarjan_stack.push(neighbor);
+                    self.tarjan_on_stack.insert(neighbor);
+                    work.push((neighbor, self.neighbors_of(neighbor).into_iter()));
+                } else if self.tarjan_on_stack.contains(&neighbor) {
+                    // Back/cross edge to a node still on the stack: fold its
+                    // index into this node's lowlink.
+                    let neighbor_index = self.tarjan_index[&neighbor];
+                    let lowlink = self.tarjan_lowlink.get_mut(&node).unwrap();
+                    *lowlink = (*lowlink).min(neighbor_index);
+                }
+            } else {
+                // Finished exploring every neighbor of `node` - propagate its
+                // lowlink up to the parent frame, then pop its SCC if it's a root.
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    let node_lowlink = self.tarjan_lowlink[&node];
+                    let parent_lowlink = self.tarjan_lowlink.get_mut(&parent).unwrap();
+                    *parent_lowlink = (*parent_lowlink).min(node_lowlink);
+                }
+
+                if self.tarjan_lowlink[&node] == self.tarjan_index[&node] {
+                    let mut scc = Vec::new();
+                    loop {
+                        let w = self.tarjan_stack.pop().expect("node must be on the stack");
+                        self.tarjan_on_stack.remove(&w);
+                        scc.push(w);
+                        if w == node {
+                            break;
                         }
+                    }
 
-                        queue.push_back(neighbor);
+                    if scc.len() > 1 {
+                        sccs.push(scc);
                     }
                 }
             }
         }
+    }
 
-        None
+    /// Snapshot of `node`'s current outgoing neighbors in `raw_adjacency`,
+    /// used by [`LockOrderGraph::tarjan_strongconnect`] so it isn't holding
+    /// a borrow of `self.raw_adjacency` while also mutating `self.tarjan_*`
+    /// fields
+    fn neighbors_of(&self, node: LockId) -> Vec<LockId> {
+        self.raw_adjacency
+            .get(&node)
+            .map(|targets| targets.iter().copied().collect())
+            .unwrap_or_default()
     }
 
     /// Remove all edges involving a specific lock
@@ -213,10 +494,12 @@ impl LockOrderGraph {
                 if let Some(preds) = self.reverse_edges.get_mut(&successor) {
                     preds.remove(&lock_id);
                 }
-                self.all_edges.remove(&LockOrderEdge {
+                let edge = LockOrderEdge {
                     before: lock_id,
                     after: successor,
-                });
+                };
+                self.all_edges.remove(&edge);
+                self.edge_sites.remove(&edge);
             }
         }
 
@@ -226,12 +509,55 @@ impl LockOrderGraph {
                 if let Some(succs) = self.edges.get_mut(&predecessor) {
                     succs.remove(&lock_id);
                 }
-                self.all_edges.remove(&LockOrderEdge {
+                let edge = LockOrderEdge {
                     before: predecessor,
                     after: lock_id,
-                });
+                };
+                self.all_edges.remove(&edge);
+                self.edge_sites.remove(&edge);
             }
         }
+
+        // Leave a gap in `ord` rather than reclaiming the position: if this
+        // `lock_id` is reused for an unrelated, later-created lock, it must
+        // start fresh with no ordering relative to anything that touched
+        // the destroyed lock.
+        self.ord.remove(&lock_id);
+    }
+
+    /// Pair each edge in a reported cycle with the backtrace that created it
+    /// and, if the graph also recorded the opposite ordering, the backtrace
+    /// that created that conflicting edge
+    ///
+    /// `cycle` is a path of locks as returned by [`LockOrderGraph::add_edge`],
+    /// e.g. `[A, B, C, A]`. Each consecutive pair `(A, B)` is one edge; lets
+    /// the caller print "lock A before B acquired here ... conflicting B
+    /// before A acquired here ..." for a reported violation.
+    ///
+    /// # Returns
+    /// One entry per consecutive pair in `cycle`, in cycle order. Either
+    /// backtrace is `None` if capture wasn't enabled when that edge (or its
+    /// conflicting counterpart) was recorded.
+    pub fn edge_sites_for_cycle(
+        &self,
+        cycle: &[LockId],
+    ) -> Vec<(LockOrderEdge, Option<StackTraceId>, Option<StackTraceId>)> {
+        cycle
+            .windows(2)
+            .map(|pair| {
+                let (before, after) = (pair[0], pair[1]);
+                let edge = LockOrderEdge { before, after };
+                let reverse = LockOrderEdge {
+                    before: after,
+                    after: before,
+                };
+                (
+                    edge,
+                    self.edge_sites.get(&edge).copied(),
+                    self.edge_sites.get(&reverse).copied(),
+                )
+            })
+            .collect()
     }
 
     /// Get all edges in the graph
@@ -265,8 +591,9 @@ impl LockOrderGraph {
         self.edges.clear();
         self.reverse_edges.clear();
         self.all_edges.clear();
-        self.cycle_cache.clear();
-        self.generation = 0;
+        self.edge_sites.clear();
+        self.ord.clear();
+        self.next_ord = 0;
     }
 }
 
@@ -317,19 +644,128 @@ mod tests {
     }
 
     #[test]
-    fn test_cache_behavior() {
+    fn test_repeated_edge_is_idempotent() {
         let mut graph = LockOrderGraph::new();
 
-        // First check should miss cache and do BFS
+        // Inserting the same already-consistent edge repeatedly must keep
+        // reporting no cycle and must not corrupt the topological order.
         assert!(graph.add_edge(1, 2).is_none());
-
-        // Same check should hit cache
         assert!(graph.add_edge(1, 2).is_none());
 
-        // Adding new edge should invalidate cache
         assert!(graph.add_edge(3, 4).is_none());
+        assert!(graph.add_edge(3, 4).is_none());
+    }
+
+    #[test]
+    fn test_reordering_preserves_topological_order_under_diamond() {
+        let mut graph = LockOrderGraph::new();
+
+        // Build a diamond: 1 -> 2 -> 4 and 1 -> 3 -> 4. Lock 3 is first seen
+        // after 4, so the last insert (3 -> 4) already forces a reorder.
+        assert!(graph.add_edge(1, 2).is_none());
+        assert!(graph.add_edge(2, 4).is_none());
+        assert!(graph.add_edge(1, 3).is_none());
+        assert!(graph.add_edge(3, 4).is_none());
+
+        // Now insert 4 -> 1 out of order from the other direction: this
+        // must be detected as a cycle, not silently reordered.
+        let cycle = graph.add_edge(4, 1).expect("should detect cycle");
+        assert!(cycle.contains(&1) && cycle.contains(&4));
+
+        // A genuinely new, out-of-order but non-cyclic edge (5 acquired
+        // before 1, i.e. must sort before everything above) forces the
+        // affected region to reorder; the graph must still agree 1 -> 2
+        // and 1 -> 3 remain valid afterwards.
+        assert!(graph.add_edge(5, 1).is_none());
+        assert!(graph.add_edge(1, 2).is_none());
+        assert!(graph.add_edge(5, 2).is_none());
+    }
+
+    #[test]
+    fn test_probe_with_record_false_does_not_mutate_graph() {
+        let mut graph = LockOrderGraph::new();
+
+        // A non-cyclic probe must not be inserted: has_edge and a later real
+        // add_edge of the same pair should behave as if the probe never
+        // happened.
+        assert!(graph.add_edge_at_with_record(1, 2, None, false).is_none());
+        assert!(!graph.has_edge(1, 2));
+        assert!(graph.get_all_edges().is_empty());
+
+        // The real (recording) call for the same pair still behaves normally.
+        assert!(graph.add_edge(1, 2).is_none());
+        assert!(graph.has_edge(1, 2));
+
+        // A cyclic probe still reports the cycle...
+        let cycle = graph
+            .add_edge_at_with_record(2, 1, None, false)
+            .expect("should detect cycle");
+        assert!(cycle.contains(&1) && cycle.contains(&2));
+
+        // ...and, since it wasn't recorded, detecting it again (e.g. after a
+        // caught panic) re-runs detection instead of serving a stale result.
+        let cycle_again = graph
+            .add_edge_at_with_record(2, 1, None, false)
+            .expect("should detect cycle again, not be suppressed by a cache entry");
+        assert_eq!(cycle, cycle_again);
+    }
+
+    #[test]
+    fn test_edge_sites_for_cycle_pairs_conflicting_orderings() {
+        let mut graph = LockOrderGraph::new();
+
+        assert!(graph.add_edge_at(1, 2, Some(100)).is_none());
+        let cycle = graph
+            .add_edge_at(2, 1, Some(200))
+            .expect("Should have detected cycle");
+
+        let sites = graph.edge_sites_for_cycle(&cycle);
+        assert_eq!(sites.len(), cycle.len() - 1);
+        for (edge, site, conflicting_site) in sites {
+            if edge.before == 2 && edge.after == 1 {
+                assert_eq!(site, Some(200));
+                assert_eq!(conflicting_site, Some(100));
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_sites_for_cycle_without_capture() {
+        let mut graph = LockOrderGraph::new();
 
-        // Cache should work for new queries
+        assert!(graph.add_edge(1, 2).is_none());
+        let cycle = graph.add_edge(2, 1).expect("Should have detected cycle");
+
+        // No explicit site was ever given, and capture is disabled by default
+        // in tests, so every edge should report no backtrace.
+        for (_, site, conflicting_site) in graph.edge_sites_for_cycle(&cycle) {
+            assert_eq!(site, None);
+            assert_eq!(conflicting_site, None);
+        }
+    }
+
+    #[test]
+    fn test_detect_all_violations_finds_independent_inverted_groups() {
+        let mut graph = LockOrderGraph::new();
+
+        // Group one: 1 -> 2, then 2 -> 1 - rejected by `edges` as a cycle,
+        // but both directions land in `raw_adjacency`.
+        assert!(graph.add_edge(1, 2).is_none());
+        assert!(graph.add_edge(2, 1).is_some());
+
+        // Group two, on entirely unrelated locks: same shape.
         assert!(graph.add_edge(3, 4).is_none());
+        assert!(graph.add_edge(4, 3).is_some());
+
+        // A plain non-cyclic edge must not show up as a violation.
+        assert!(graph.add_edge(5, 6).is_none());
+
+        let mut violations = graph.detect_all_violations();
+        for group in &mut violations {
+            group.sort();
+        }
+        violations.sort();
+
+        assert_eq!(violations, vec![vec![1, 2], vec![3, 4]]);
     }
 }
@@ -19,30 +19,52 @@
 use crate::core::types::ThreadId;
 use fxhash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
+use std::hash::Hash;
 
-/// Represents a directed graph of thread wait relationships
-pub struct WaitForGraph {
-    /// Maps a thread to all the threads it is waiting for (outgoing edges).
+/// Represents a directed graph of wait relationships between nodes
+///
+/// The node type defaults to a plain in-process [`ThreadId`], which is all the
+/// in-process detector needs. The `distributed` feature reuses this same graph
+/// with a composite `(ProcessId, ThreadId)` node so the coordinator can run the
+/// identical incremental cycle detection over a graph merged from many processes.
+pub struct WaitForGraph<N = ThreadId>
+where
+    N: Copy + Eq + Hash,
+{
+    /// Maps a node to all the nodes it is waiting for (outgoing edges).
     /// Primary source for cycle detection.
-    pub(crate) edges: FxHashMap<ThreadId, FxHashSet<ThreadId>>,
+    pub(crate) edges: FxHashMap<N, FxHashSet<N>>,
 
-    /// Maps a thread to all threads that are waiting for it (incoming edges).
-    /// Used for O(1) cleanup when a thread exits.
-    pub(crate) incoming_edges: FxHashMap<ThreadId, FxHashSet<ThreadId>>,
+    /// Maps a node to all nodes that are waiting for it (incoming edges).
+    /// Used for O(1) cleanup when a node exits.
+    pub(crate) incoming_edges: FxHashMap<N, FxHashSet<N>>,
 
     // Cached buffers for BFS to avoid repeated allocations
-    bfs_queue: VecDeque<ThreadId>,
-    bfs_visited: FxHashSet<ThreadId>,
-    bfs_parent: FxHashMap<ThreadId, ThreadId>,
+    bfs_queue: VecDeque<N>,
+    bfs_visited: FxHashSet<N>,
+    bfs_parent: FxHashMap<N, N>,
+
+    // Cached buffers for Tarjan's SCC algorithm (see `detect_all_deadlocks`),
+    // cleared and reused on every call the same way the BFS buffers above are.
+    tarjan_index: FxHashMap<N, usize>,
+    tarjan_lowlink: FxHashMap<N, usize>,
+    tarjan_on_stack: FxHashSet<N>,
+    tarjan_stack: Vec<N>,
 }
 
-impl Default for WaitForGraph {
+impl<N> Default for WaitForGraph<N>
+where
+    N: Copy + Eq + Hash,
+{
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl WaitForGraph {
+impl<N> WaitForGraph<N>
+where
+    N: Copy + Eq + Hash,
+{
     /// Create a new empty wait-for graph
     ///
     /// # Returns
@@ -54,6 +76,10 @@ impl WaitForGraph {
             bfs_queue: VecDeque::with_capacity(64),
             bfs_visited: FxHashSet::default(),
             bfs_parent: FxHashMap::default(),
+            tarjan_index: FxHashMap::default(),
+            tarjan_lowlink: FxHashMap::default(),
+            tarjan_on_stack: FxHashSet::default(),
+            tarjan_stack: Vec::with_capacity(64),
         }
     }
 
@@ -66,9 +92,9 @@ impl WaitForGraph {
     /// * ⁠ to ⁠ - The thread ID that holds the resource
     ///
     /// # Returns
-    /// * ⁠ Some(Vec<ThreadId>) ⁠ - The cycle if adding this edge would create one
+    /// * ⁠ Some(Vec<N>) ⁠ - The cycle if adding this edge would create one
     /// * ⁠ None ⁠ - If no cycle would be created
-    pub fn add_edge(&mut self, from: ThreadId, to: ThreadId) -> Option<Vec<ThreadId>> {
+    pub fn add_edge(&mut self, from: N, to: N) -> Option<Vec<N>> {
         // Optimization: Do not perform BFS if the edge already exists
         if let Some(targets) = self.edges.get(&from)
             && targets.contains(&to)
@@ -98,7 +124,7 @@ impl WaitForGraph {
     ///
     /// # Arguments
     /// * ⁠ thread_id ⁠ - The thread that stopped waiting
-    pub fn clear_wait_edges(&mut self, thread_id: ThreadId) {
+    pub fn clear_wait_edges(&mut self, thread_id: N) {
         // Remove the forward edges
         if let Some(targets) = self.edges.remove(&thread_id) {
             // Update the reverse mapping for every thread we were waiting on
@@ -119,7 +145,7 @@ impl WaitForGraph {
     /// # Arguments
     /// * ⁠ from ⁠ - The waiting thread
     /// * ⁠ to ⁠ - The target thread
-    pub fn remove_edge(&mut self, from: ThreadId, to: ThreadId) {
+    pub fn remove_edge(&mut self, from: N, to: N) {
         // Remove from forward graph
         if let Some(neighbors) = self.edges.get_mut(&from)
             && neighbors.remove(&to)
@@ -146,7 +172,7 @@ impl WaitForGraph {
     ///
     /// # Arguments
     /// * ⁠ thread_id ⁠ - ID of the thread being removed
-    pub fn remove_thread(&mut self, thread_id: ThreadId) {
+    pub fn remove_thread(&mut self, thread_id: N) {
         // 1. Remove outgoing edges (Who was this thread waiting for?)
         self.clear_wait_edges(thread_id);
 
@@ -165,10 +191,203 @@ impl WaitForGraph {
         }
     }
 
+    /// All nodes transitively reachable from `node` by following wait-for edges
+    ///
+    /// This is everyone `node` is, directly or indirectly, blocked on. Does
+    /// not include `node` itself.
+    ///
+    /// # Arguments
+    /// * `node` - The node to start the search from
+    pub fn reachable_from(&self, node: N) -> Vec<N> {
+        let mut visited = FxHashSet::default();
+        let mut queue = VecDeque::new();
+        queue.push_back(node);
+        visited.insert(node);
+
+        while let Some(current) = queue.pop_front() {
+            if let Some(neighbors) = self.edges.get(&current) {
+                for &neighbor in neighbors {
+                    if visited.insert(neighbor) {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        visited.remove(&node);
+        visited.into_iter().collect()
+    }
+
+    /// Whether `node` is currently part of a wait-for cycle
+    ///
+    /// True if following outgoing edges from `node` can lead back to `node`.
+    ///
+    /// # Arguments
+    /// * `node` - The node to check
+    pub fn in_cycle(&mut self, node: N) -> bool {
+        let Some(neighbors) = self.edges.get(&node).cloned() else {
+            return false;
+        };
+        neighbors
+            .into_iter()
+            .any(|neighbor| self.find_path(neighbor, node).is_some())
+    }
+
+    /// The nodes `node` is directly (not transitively) waiting for
+    ///
+    /// # Arguments
+    /// * `node` - The node to query
+    pub fn blockers_of(&self, node: N) -> Vec<N> {
+        self.edges
+            .get(&node)
+            .map(|neighbors| neighbors.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Scan the whole graph for any cycle, not just one touching a specific node
+    ///
+    /// Unlike [`WaitForGraph::in_cycle`], which only checks whether `node`
+    /// itself sits on a cycle, this walks every node with outgoing edges and
+    /// looks for a path back to it. Intended for a periodic background scan
+    /// (see [`crate::Deloxide::with_watchdog`]) that needs to catch a cycle
+    /// even when nothing triggered a fresh edge insertion to notice it.
+    ///
+    /// # Returns
+    /// * `Some(Vec<N>)` - The first cycle found, in wait-for order
+    /// * `None` - No cycle currently exists in the graph
+    pub fn find_any_cycle(&mut self) -> Option<Vec<N>> {
+        let nodes: Vec<N> = self.edges.keys().copied().collect();
+        for node in nodes {
+            let Some(neighbors) = self.edges.get(&node).cloned() else {
+                continue;
+            };
+            for neighbor in neighbors {
+                if let Some(path) = self.find_path(neighbor, node) {
+                    return Some(path);
+                }
+            }
+        }
+        None
+    }
+
+    /// Find every independent deadlocked group currently in the graph
+    ///
+    /// Unlike [`WaitForGraph::add_edge`], which reports at most one cycle (the
+    /// BFS path found when the single edge it's inserting closes a loop), or
+    /// [`WaitForGraph::find_any_cycle`], which stops at the first cycle it
+    /// finds, this takes a complete snapshot: it runs Tarjan's
+    /// strongly-connected-components algorithm over [`WaitForGraph::edges`]
+    /// and returns every strongly connected component with more than one
+    /// node, plus any singleton node with a self-edge, since a live system
+    /// can have several independent deadlocks at once.
+    ///
+    /// The DFS is iterative (an explicit work stack, not native recursion) so
+    /// a deep wait chain can't overflow the stack, and the index/lowlink/
+    /// on-stack bookkeeping reuses the same cached buffers across calls that
+    /// [`WaitForGraph::find_path`] reuses for its BFS.
+    ///
+    /// # Returns
+    /// One `Vec<N>` per deadlocked group, each listing the threads involved
+    /// in no particular order within the group; empty if the graph currently
+    /// has no cycles.
+    pub fn detect_all_deadlocks(&mut self) -> Vec<Vec<N>> {
+        self.tarjan_index.clear();
+        self.tarjan_lowlink.clear();
+        self.tarjan_on_stack.clear();
+        self.tarjan_stack.clear();
+
+        let mut next_index: usize = 0;
+        let mut sccs = Vec::new();
+
+        let roots: Vec<N> = self.edges.keys().copied().collect();
+        for root in roots {
+            if !self.tarjan_index.contains_key(&root) {
+                self.tarjan_strongconnect(root, &mut next_index, &mut sccs);
+            }
+        }
+
+        sccs
+    }
+
+    /// Iterative Tarjan strongconnect, using an explicit work stack of
+    /// `(node, not-yet-visited neighbors)` frames instead of recursing
+    fn tarjan_strongconnect(&mut self, root: N, next_index: &mut usize, sccs: &mut Vec<Vec<N>>) {
+        let mut work: Vec<(N, std::vec::IntoIter<N>)> = Vec::new();
+
+        self.tarjan_index.insert(root, *next_index);
+        self.tarjan_lowlink.insert(root, *next_index);
+        *next_index += 1;
+        self.tarjan_stack.push(root);
+        self.tarjan_on_stack.insert(root);
+        work.push((root, self.neighbors_of(root).into_iter()));
+
+        while let Some((node, neighbors)) = work.last_mut() {
+            let node = *node;
+
+            if let Some(neighbor) = neighbors.next() {
+                if !self.tarjan_index.contains_key(&neighbor) {
+                    // Tree edge: descend into the unvisited neighbor
+                    self.tarjan_index.insert(neighbor, *next_index);
+                    self.tarjan_lowlink.insert(neighbor, *next_index);
+                    *next_index += 1;
+                    self.tarjan_stack.push(neighbor);
+                    self.tarjan_on_stack.insert(neighbor);
+                    work.push((neighbor, self.neighbors_of(neighbor).into_iter()));
+                } else if self.tarjan_on_stack.contains(&neighbor) {
+                    // Back/cross edge to a node still on the stack: fold its
+                    // index into this node's lowlink.
+                    let neighbor_index = self.tarjan_index[&neighbor];
+                    let lowlink = self.tarjan_lowlink.get_mut(&node).unwrap();
+                    *lowlink = (*lowlink).min(neighbor_index);
+                }
+            } else {
+                // Finished exploring every neighbor of `node` - propagate its
+                // lowlink up to the parent frame, then pop its SCC if it's a root.
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    let node_lowlink = self.tarjan_lowlink[&node];
+                    let parent_lowlink = self.tarjan_lowlink.get_mut(&parent).unwrap();
+                    *parent_lowlink = (*parent_lowlink).min(node_lowlink);
+                }
+
+                if self.tarjan_lowlink[&node] == self.tarjan_index[&node] {
+                    let mut scc = Vec::new();
+                    loop {
+                        let w = self.tarjan_stack.pop().expect("node must be on the stack");
+                        self.tarjan_on_stack.remove(&w);
+                        scc.push(w);
+                        if w == node {
+                            break;
+                        }
+                    }
+
+                    let is_deadlocked = scc.len() > 1
+                        || self
+                            .edges
+                            .get(&scc[0])
+                            .is_some_and(|targets| targets.contains(&scc[0]));
+                    if is_deadlocked {
+                        sccs.push(scc);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Snapshot of `node`'s current outgoing neighbors, used by
+    /// [`WaitForGraph::tarjan_strongconnect`] so it isn't holding a borrow of
+    /// `self.edges` while also mutating `self.tarjan_*` fields
+    fn neighbors_of(&self, node: N) -> Vec<N> {
+        self.edges
+            .get(&node)
+            .map(|targets| targets.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
     /// Find a path from start to target using BFS
     ///
     /// Used internally for cycle detection.
-    fn find_path(&mut self, start: ThreadId, target: ThreadId) -> Option<Vec<ThreadId>> {
+    fn find_path(&mut self, start: N, target: N) -> Option<Vec<N>> {
         if start == target {
             return Some(vec![start]);
         }
@@ -0,0 +1,317 @@
+//! Futures-aware mutex for `async` code
+//!
+//! [`locks::mutex::Mutex`](crate::core::locks::mutex::Mutex) keys every
+//! acquisition on [`get_current_thread_id`], which is the right identity for a
+//! blocking lock but the wrong one for an `async fn`: a task suspended on
+//! `.await` can be resumed by the executor on a completely different OS
+//! thread, so recording the OS thread as the "owner" would make the detector
+//! lose track of who actually holds the lock the moment the executor moves
+//! work around.
+//!
+//! `AsyncMutex` instead assigns each lock attempt its own [`TaskId`] (see
+//! [`TaskId`] for why this is safe to feed straight into
+//! `acquire_slow`/`complete_acquire`/`release_mutex`, which already treat the
+//! owner as an opaque id) and drives acquisition through a
+//! [`Future`](std::future::Future) that registers the awaiting task's
+//! [`Waker`] as a waiter instead of blocking the OS thread. This mirrors
+//! `futures-util`'s `lock::Mutex`, including its unfairness: a woken waiter
+//! races every other poller for ownership rather than being guaranteed it,
+//! so a badly timed wakeup can still starve a waiter - exactly the kind of
+//! ordering the detector should be able to see across tasks instead of
+//! attributing it all to whatever thread happened to poll.
+
+use crate::core::detector;
+use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::stacktrace;
+use crate::core::types::{LockId, TaskId, ThreadId, get_current_thread_id};
+use parking_lot::Mutex as ParkingLotMutex;
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Waker};
+
+// Global counter for assigning unique task ids, mirroring `NEXT_LOCK_ID`.
+// Unlike `get_current_thread_id`, this is not thread-local: a task id is
+// assigned once per lock-acquisition future, not once per OS thread, since a
+// task has no fixed OS thread to key a thread-local on.
+static NEXT_TASK_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Assign a fresh [`TaskId`] to a new lock-acquisition future
+fn next_task_id() -> TaskId {
+    NEXT_TASK_ID.fetch_add(1, Ordering::SeqCst)
+}
+
+struct Waiter {
+    task_id: TaskId,
+    waker: Waker,
+}
+
+struct Inner {
+    /// Task currently holding the lock, if any
+    owner: Option<TaskId>,
+    /// Tasks waiting for the lock, in the order they first registered
+    waiters: VecDeque<Waiter>,
+}
+
+/// A futures-aware mutex that tracks lock operations for deadlock detection
+///
+/// `AsyncMutex` provides the same deadlock detection as
+/// [`Mutex`](crate::core::locks::mutex::Mutex), but its [`lock`](AsyncMutex::lock)
+/// method returns a [`Future`] instead of blocking, so it can be `.await`ed
+/// from `async fn` code without tying up an OS thread while contended.
+///
+/// # Example
+///
+/// ```rust
+/// # async fn run() {
+/// use deloxide::AsyncMutex;
+/// use std::sync::Arc;
+///
+/// let mutex = Arc::new(AsyncMutex::new(42));
+/// let guard = mutex.lock().await;
+/// assert_eq!(*guard, 42);
+/// # }
+/// ```
+pub struct AsyncMutex<T> {
+    /// Unique identifier for this mutex
+    id: LockId,
+    /// Thread that created this mutex
+    creator_thread_id: ThreadId,
+    /// Owner and waiter bookkeeping, protected by a short-lived blocking lock
+    inner: ParkingLotMutex<Inner>,
+    /// The protected value; access is guarded by `inner.owner` rather than by
+    /// holding `inner`'s lock across the guard's lifetime
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `AsyncMutex<T>` only ever grants access to `value` to the single
+// task recorded in `inner.owner`, exactly like a regular `Mutex<T>`.
+unsafe impl<T: Send> Send for AsyncMutex<T> {}
+unsafe impl<T: Send> Sync for AsyncMutex<T> {}
+
+impl<T> AsyncMutex<T> {
+    /// Create a new AsyncMutex with an automatically assigned ID
+    ///
+    /// # Arguments
+    /// * `value` - The initial value to store in the mutex
+    pub fn new(value: T) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+        let creator_thread_id = get_current_thread_id();
+
+        detector::mutex::create_mutex(id, Some(creator_thread_id));
+
+        AsyncMutex {
+            id,
+            creator_thread_id,
+            inner: ParkingLotMutex::new(Inner {
+                owner: None,
+                waiters: VecDeque::new(),
+            }),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Get the ID of this mutex
+    pub fn id(&self) -> LockId {
+        self.id
+    }
+
+    /// Get the ID of the thread that created this mutex
+    pub fn creator_thread_id(&self) -> ThreadId {
+        self.creator_thread_id
+    }
+
+    /// Acquire the lock, returning a future that resolves once this task owns it
+    ///
+    /// The returned future registers the current task as a waiter (via its
+    /// [`Waker`]) each time it is polled while the lock is held elsewhere,
+    /// and reports the attempt to the deadlock detector under this task's own
+    /// [`TaskId`] so a cycle spanning several `async` tasks can be detected
+    /// even if they're all being driven by the same executor thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # async fn run() {
+    /// use deloxide::AsyncMutex;
+    ///
+    /// let mutex = AsyncMutex::new(0);
+    /// {
+    ///     let mut guard = mutex.lock().await;
+    ///     *guard += 1;
+    /// } // lock is automatically released when guard goes out of scope
+    /// # }
+    /// ```
+    pub fn lock(&self) -> AsyncMutexLockFuture<'_, T> {
+        AsyncMutexLockFuture {
+            mutex: self,
+            task_id: None,
+        }
+    }
+
+    /// Try to acquire the lock without waiting
+    ///
+    /// Returns `Some(guard)` if the lock was free, `None` if it is currently
+    /// held by another task. Unlike [`AsyncMutex::lock`], this never
+    /// registers a waiter: a failed attempt never blocks, so there is nothing
+    /// to wake later.
+    pub fn try_lock(&self) -> Option<AsyncMutexGuard<'_, T>> {
+        let task_id = next_task_id();
+        let mut inner = self.inner.lock();
+
+        if inner.owner.is_some() {
+            let current_owner = inner.owner;
+            drop(inner);
+
+            if let Some(info) = detector::mutex::try_attempt(task_id, self.id, current_owner) {
+                detector::deadlock_handling::process_deadlock(info);
+            }
+            return None;
+        }
+
+        inner.owner = Some(task_id);
+        drop(inner);
+
+        detector::mutex::complete_acquire(task_id, self.id, stacktrace::capture());
+
+        Some(AsyncMutexGuard {
+            mutex: self,
+            task_id,
+        })
+    }
+
+    /// Returns a mutable reference to the underlying data
+    ///
+    /// Since this call borrows the AsyncMutex mutably, no actual locking
+    /// needs to take place - the mutable borrow statically guarantees no
+    /// task holds a guard.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T> Drop for AsyncMutex<T> {
+    fn drop(&mut self) {
+        detector::mutex::destroy_mutex(self.id);
+    }
+}
+
+/// Future returned by [`AsyncMutex::lock`]
+pub struct AsyncMutexLockFuture<'a, T> {
+    mutex: &'a AsyncMutex<T>,
+    /// Assigned on first poll and then kept for the future's whole lifetime,
+    /// so the detector sees the same owner/waiter identity across every
+    /// `.await` suspension, no matter which OS thread resumes the task.
+    task_id: Option<TaskId>,
+}
+
+impl<'a, T> Future for AsyncMutexLockFuture<'a, T> {
+    type Output = AsyncMutexGuard<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let task_id = *this.task_id.get_or_insert_with(next_task_id);
+
+        let mut inner = this.mutex.inner.lock();
+        if inner.owner.is_none() {
+            inner.owner = Some(task_id);
+            inner.waiters.retain(|w| w.task_id != task_id);
+            drop(inner);
+
+            detector::mutex::complete_acquire(task_id, this.mutex.id, stacktrace::capture());
+
+            return Poll::Ready(AsyncMutexGuard {
+                mutex: this.mutex,
+                task_id,
+            });
+        }
+
+        let current_owner = inner.owner;
+        match inner.waiters.iter_mut().find(|w| w.task_id == task_id) {
+            Some(waiter) => waiter.waker.clone_from(cx.waker()),
+            None => inner.waiters.push_back(Waiter {
+                task_id,
+                waker: cx.waker().clone(),
+            }),
+        }
+        drop(inner);
+
+        let deadlock_info =
+            detector::mutex::acquire_slow(task_id, this.mutex.id, current_owner, stacktrace::capture());
+        if let Some(info) = deadlock_info {
+            detector::deadlock_handling::process_deadlock(info);
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for AsyncMutexLockFuture<'_, T> {
+    fn drop(&mut self) {
+        // If this future never registered a waiter (never polled) or already
+        // resolved to `Poll::Ready` (ownership now belongs to the returned
+        // guard), there is nothing to retract.
+        let Some(task_id) = self.task_id else {
+            return;
+        };
+
+        let mut inner = self.mutex.inner.lock();
+        if inner.owner == Some(task_id) {
+            return;
+        }
+        inner.waiters.retain(|w| w.task_id != task_id);
+        drop(inner);
+
+        // A cancelled future (e.g. dropped by `select!` or a timeout) must
+        // not leave a stale wait-for edge behind, exactly like the blocking
+        // `Mutex`'s timed acquisitions retract theirs via `cancel_acquire`.
+        detector::mutex::cancel_acquire(task_id, self.mutex.id);
+    }
+}
+
+/// Guard for an AsyncMutex, reports lock release when dropped
+///
+/// Unlike [`MutexGuard`](crate::core::locks::mutex::MutexGuard), dropping this
+/// guard wakes the next waiter (if any) instead of letting the OS scheduler
+/// hand the lock to whichever blocked thread wakes up first; this is the
+/// unfair, futures-util-style handoff described in the module docs.
+pub struct AsyncMutexGuard<'a, T> {
+    mutex: &'a AsyncMutex<T>,
+    task_id: TaskId,
+}
+
+impl<'a, T> Deref for AsyncMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: holding this guard implies `self.mutex.inner.owner ==
+        // Some(self.task_id)`, so no other task has access to `value`.
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<'a, T> DerefMut for AsyncMutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: see `Deref::deref`.
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<'a, T> Drop for AsyncMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        let next_waiter = {
+            let mut inner = self.mutex.inner.lock();
+            inner.owner = None;
+            inner.waiters.pop_front()
+        };
+
+        detector::mutex::release_mutex(self.task_id, self.mutex.id);
+
+        if let Some(waiter) = next_waiter {
+            waiter.waker.wake();
+        }
+    }
+}
@@ -0,0 +1,460 @@
+//! Futures-aware read-write lock for `async` code
+//!
+//! [`locks::rwlock::RwLock`](crate::core::locks::rwlock::RwLock) keys every
+//! reader and writer on [`get_current_thread_id`], the same assumption
+//! [`AsyncMutex`](crate::core::locks::async_mutex::AsyncMutex)'s module docs
+//! call out as wrong for `async fn` code: a task can be suspended mid-`.await`
+//! and resumed on a different OS thread, so the blocking `RwLock` would lose
+//! track of which task actually holds a read or write guard the moment the
+//! executor moves work around.
+//!
+//! `AsyncRwLock` mirrors `AsyncMutex`'s approach: every lock attempt is
+//! assigned its own [`TaskId`] up front and that id, not the polling thread's
+//! [`ThreadId`], is what's fed to `detector::rwlock`'s hooks for the whole
+//! lifetime of the guard. Unlike the blocking `RwLock`, which hands fairness
+//! off to the underlying primitive, `AsyncRwLock` tracks readers and the
+//! writer itself so it can enforce the same writer-preference the detector
+//! already assumes: once a writer is queued, new readers wait behind it
+//! rather than being admitted ahead of it, matching
+//! [`Detector::attempt_read`](crate::core::Detector::attempt_read)'s
+//! writer-preference wait-edges.
+
+use crate::core::detector;
+use crate::core::detector::deadlock_handling;
+use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::types::{LockId, TaskId, ThreadId, get_current_thread_id};
+use fxhash::FxHashSet;
+use parking_lot::Mutex as ParkingLotMutex;
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Waker};
+
+// Mirrors `async_mutex::NEXT_TASK_ID`: a fresh id per lock-acquisition
+// future, since a task has no fixed OS thread to key a thread-local on.
+static NEXT_TASK_ID: AtomicUsize = AtomicUsize::new(1);
+
+fn next_task_id() -> TaskId {
+    NEXT_TASK_ID.fetch_add(1, Ordering::SeqCst)
+}
+
+struct Waiter {
+    task_id: TaskId,
+    waker: Waker,
+}
+
+struct Inner {
+    /// Task currently holding the write lock, if any
+    writer: Option<TaskId>,
+    /// Tasks currently holding a read lock
+    readers: FxHashSet<TaskId>,
+    /// Writers waiting their turn, in the order they first registered
+    write_waiters: VecDeque<Waiter>,
+    /// Readers waiting their turn (behind a queued writer), in the order
+    /// they first registered
+    read_waiters: VecDeque<Waiter>,
+}
+
+impl Inner {
+    /// A reader may be admitted only when there's no writer holding the lock
+    /// and no writer already queued ahead of it - see the module docs on
+    /// writer preference
+    fn read_available(&self) -> bool {
+        self.writer.is_none() && self.write_waiters.is_empty()
+    }
+
+    fn write_available(&self) -> bool {
+        self.writer.is_none() && self.readers.is_empty()
+    }
+
+    /// Drain whichever waiters are now eligible to retry, without waking
+    /// them - the caller must drop the `inner` lock first, then wake each
+    /// returned waker, so a waker that re-polls synchronously can't
+    /// re-enter this non-reentrant lock.
+    fn drain_wakeable(&mut self) -> Vec<Waker> {
+        if self.write_available()
+            && let Some(waiter) = self.write_waiters.pop_front()
+        {
+            return vec![waiter.waker];
+        }
+        if self.read_available() {
+            return self.read_waiters.drain(..).map(|w| w.waker).collect();
+        }
+        Vec::new()
+    }
+}
+
+/// A futures-aware read-write lock that tracks lock operations for deadlock detection
+///
+/// `AsyncRwLock` provides the same deadlock detection as
+/// [`RwLock`](crate::core::locks::rwlock::RwLock), but its
+/// [`read`](AsyncRwLock::read) and [`write`](AsyncRwLock::write) methods
+/// return a [`Future`] instead of blocking, so they can be `.await`ed from
+/// `async fn` code without tying up an OS thread while contended.
+///
+/// # Example
+///
+/// ```rust
+/// # async fn run() {
+/// use deloxide::AsyncRwLock;
+/// use std::sync::Arc;
+///
+/// let lock = Arc::new(AsyncRwLock::new(42));
+/// let guard = lock.read().await;
+/// assert_eq!(*guard, 42);
+/// # }
+/// ```
+pub struct AsyncRwLock<T> {
+    /// Unique identifier for this RwLock
+    id: LockId,
+    /// Thread that created this RwLock
+    creator_thread_id: ThreadId,
+    /// Reader/writer and waiter bookkeeping, protected by a short-lived blocking lock
+    inner: ParkingLotMutex<Inner>,
+    /// The protected value; access is guarded by `inner.writer`/`inner.readers`
+    /// rather than by holding `inner`'s lock across a guard's lifetime
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `AsyncRwLock<T>` only ever grants mutable access to `value` to the
+// single task recorded in `inner.writer`, and shared access to the tasks
+// recorded in `inner.readers`, exactly like a regular `RwLock<T>`.
+unsafe impl<T: Send> Send for AsyncRwLock<T> {}
+unsafe impl<T: Send + Sync> Sync for AsyncRwLock<T> {}
+
+impl<T> AsyncRwLock<T> {
+    /// Create a new AsyncRwLock with an automatically assigned ID
+    ///
+    /// # Arguments
+    /// * `value` - The initial value to store in the lock
+    pub fn new(value: T) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+        let creator_thread_id = get_current_thread_id();
+
+        detector::rwlock::create_rwlock(id, Some(creator_thread_id));
+
+        AsyncRwLock {
+            id,
+            creator_thread_id,
+            inner: ParkingLotMutex::new(Inner {
+                writer: None,
+                readers: FxHashSet::default(),
+                write_waiters: VecDeque::new(),
+                read_waiters: VecDeque::new(),
+            }),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Get the ID of this RwLock
+    pub fn id(&self) -> LockId {
+        self.id
+    }
+
+    /// Get the ID of the thread that created this RwLock
+    pub fn creator_thread_id(&self) -> ThreadId {
+        self.creator_thread_id
+    }
+
+    /// Acquire a read lock, returning a future that resolves once this task
+    /// holds it
+    ///
+    /// The returned future registers the current task as a waiter (via its
+    /// [`Waker`]) each time it is polled while the lock is unavailable, and
+    /// reports the attempt to the deadlock detector under this task's own
+    /// [`TaskId`] so a cycle spanning several `async` tasks can be detected
+    /// even if they're all being driven by the same executor thread.
+    pub fn read(&self) -> AsyncRwLockReadFuture<'_, T> {
+        AsyncRwLockReadFuture {
+            lock: self,
+            task_id: None,
+        }
+    }
+
+    /// Try to acquire a read lock without waiting
+    ///
+    /// Returns `Some(guard)` if no writer currently holds or is queued for
+    /// the lock, `None` otherwise. Unlike [`AsyncRwLock::read`], this never
+    /// registers a waiter: a failed attempt never blocks, so there is
+    /// nothing to wake later.
+    pub fn try_read(&self) -> Option<AsyncRwLockReadGuard<'_, T>> {
+        let task_id = next_task_id();
+        let mut inner = self.inner.lock();
+
+        let acquired = detector::rwlock::attempt_read(task_id, self.id, true, || {
+            inner.read_available().then(|| inner.readers.insert(task_id))
+        });
+
+        match acquired {
+            Some(_) => {
+                drop(inner);
+                Some(AsyncRwLockReadGuard {
+                    lock: self,
+                    task_id,
+                })
+            }
+            None => None,
+        }
+    }
+
+    /// Acquire the write lock, returning a future that resolves once this
+    /// task holds it
+    ///
+    /// Like [`AsyncRwLock::read`], but the returned future registers the
+    /// current task as a waiter for exclusive access; see
+    /// [`Detector::acquire_write_slow`](crate::core::Detector::acquire_write_slow)
+    /// for how a queued writer is reported to the detector.
+    pub fn write(&self) -> AsyncRwLockWriteFuture<'_, T> {
+        AsyncRwLockWriteFuture {
+            lock: self,
+            task_id: None,
+        }
+    }
+
+    /// Try to acquire the write lock without waiting
+    ///
+    /// Returns `Some(guard)` if the lock had no reader or writer, `None`
+    /// otherwise.
+    pub fn try_write(&self) -> Option<AsyncRwLockWriteGuard<'_, T>> {
+        let task_id = next_task_id();
+        let mut inner = self.inner.lock();
+
+        if !inner.write_available() {
+            return None;
+        }
+        inner.writer = Some(task_id);
+        drop(inner);
+
+        detector::rwlock::complete_write(task_id, self.id);
+
+        Some(AsyncRwLockWriteGuard {
+            lock: self,
+            task_id,
+        })
+    }
+
+    /// Returns a mutable reference to the underlying data
+    ///
+    /// Since this call borrows the AsyncRwLock mutably, no actual locking
+    /// needs to take place - the mutable borrow statically guarantees no
+    /// task holds a guard.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T> Drop for AsyncRwLock<T> {
+    fn drop(&mut self) {
+        detector::rwlock::destroy_rwlock(self.id);
+    }
+}
+
+/// Future returned by [`AsyncRwLock::read`]
+pub struct AsyncRwLockReadFuture<'a, T> {
+    lock: &'a AsyncRwLock<T>,
+    /// Assigned on first poll and then kept for the future's whole lifetime,
+    /// so the detector sees the same waiter identity across every `.await`
+    /// suspension, no matter which OS thread resumes the task.
+    task_id: Option<TaskId>,
+}
+
+impl<'a, T> Future for AsyncRwLockReadFuture<'a, T> {
+    type Output = AsyncRwLockReadGuard<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let task_id = *this.task_id.get_or_insert_with(next_task_id);
+
+        let mut inner = this.lock.inner.lock();
+        let acquired = detector::rwlock::attempt_read(task_id, this.lock.id, false, || {
+            inner.read_available().then(|| inner.readers.insert(task_id))
+        });
+
+        if acquired.is_some() {
+            inner.read_waiters.retain(|w| w.task_id != task_id);
+            drop(inner);
+            return Poll::Ready(AsyncRwLockReadGuard {
+                lock: this.lock,
+                task_id,
+            });
+        }
+
+        match inner.read_waiters.iter_mut().find(|w| w.task_id == task_id) {
+            Some(waiter) => waiter.waker.clone_from(cx.waker()),
+            None => inner.read_waiters.push_back(Waiter {
+                task_id,
+                waker: cx.waker().clone(),
+            }),
+        }
+        drop(inner);
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for AsyncRwLockReadFuture<'_, T> {
+    fn drop(&mut self) {
+        // If this future never registered a waiter (never polled) or already
+        // resolved to `Poll::Ready` (ownership now belongs to the returned
+        // guard), there is nothing to retract.
+        let Some(task_id) = self.task_id else {
+            return;
+        };
+
+        let mut inner = self.lock.inner.lock();
+        if inner.readers.contains(&task_id) {
+            return;
+        }
+        inner.read_waiters.retain(|w| w.task_id != task_id);
+        drop(inner);
+
+        // A cancelled future (e.g. dropped by `select!` or a timeout) must
+        // not leave a stale wait-for edge behind, exactly like the blocking
+        // `RwLock`'s timed acquisitions retract theirs via `cancel_acquire`.
+        detector::rwlock::cancel_acquire(task_id, self.lock.id);
+    }
+}
+
+/// Guard for a read lock held via [`AsyncRwLock`]
+pub struct AsyncRwLockReadGuard<'a, T> {
+    lock: &'a AsyncRwLock<T>,
+    task_id: TaskId,
+}
+
+impl<'a, T> Deref for AsyncRwLockReadGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: holding this guard implies `self.task_id` is in
+        // `self.lock.inner.readers`, and no task can be `inner.writer` while
+        // any reader is registered, so no exclusive access exists concurrently.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for AsyncRwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut inner = self.lock.inner.lock();
+        inner.readers.remove(&self.task_id);
+        let wakers = inner.drain_wakeable();
+        drop(inner);
+
+        detector::rwlock::release_read(self.task_id, self.lock.id);
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by [`AsyncRwLock::write`]
+pub struct AsyncRwLockWriteFuture<'a, T> {
+    lock: &'a AsyncRwLock<T>,
+    task_id: Option<TaskId>,
+}
+
+impl<'a, T> Future for AsyncRwLockWriteFuture<'a, T> {
+    type Output = AsyncRwLockWriteGuard<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let task_id = *this.task_id.get_or_insert_with(next_task_id);
+
+        let mut inner = this.lock.inner.lock();
+        if inner.write_available() {
+            inner.writer = Some(task_id);
+            inner.write_waiters.retain(|w| w.task_id != task_id);
+            drop(inner);
+
+            detector::rwlock::complete_write(task_id, this.lock.id);
+
+            return Poll::Ready(AsyncRwLockWriteGuard {
+                lock: this.lock,
+                task_id,
+            });
+        }
+
+        let current_writer = inner.writer;
+        match inner.write_waiters.iter_mut().find(|w| w.task_id == task_id) {
+            Some(waiter) => waiter.waker.clone_from(cx.waker()),
+            None => inner.write_waiters.push_back(Waiter {
+                task_id,
+                waker: cx.waker().clone(),
+            }),
+        }
+        drop(inner);
+
+        let deadlock_info =
+            detector::rwlock::acquire_write_slow(task_id, this.lock.id, current_writer);
+        if let Some(info) = deadlock_info {
+            deadlock_handling::process_deadlock(info);
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for AsyncRwLockWriteFuture<'_, T> {
+    fn drop(&mut self) {
+        let Some(task_id) = self.task_id else {
+            return;
+        };
+
+        let mut inner = self.lock.inner.lock();
+        if inner.writer == Some(task_id) {
+            return;
+        }
+        inner.write_waiters.retain(|w| w.task_id != task_id);
+        // Giving up a queued write attempt may free up readers that were
+        // waiting behind writer preference; nudge them awake.
+        let wakers = inner.drain_wakeable();
+        drop(inner);
+
+        detector::rwlock::cancel_acquire(task_id, self.lock.id);
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// Guard for a write lock held via [`AsyncRwLock`]
+pub struct AsyncRwLockWriteGuard<'a, T> {
+    lock: &'a AsyncRwLock<T>,
+    task_id: TaskId,
+}
+
+impl<'a, T> Deref for AsyncRwLockWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: see `DerefMut::deref_mut`.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<'a, T> DerefMut for AsyncRwLockWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: holding this guard implies `self.lock.inner.writer ==
+        // Some(self.task_id)`, so no other task has access to `value`.
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for AsyncRwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut inner = self.lock.inner.lock();
+        inner.writer = None;
+        let wakers = inner.drain_wakeable();
+        drop(inner);
+
+        detector::rwlock::release_write(self.task_id, self.lock.id);
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
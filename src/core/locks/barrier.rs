@@ -0,0 +1,170 @@
+use crate::core::detector;
+use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::types::{LockId, get_current_thread_id};
+use parking_lot::{Condvar as ParkingLotCondvar, Mutex as ParkingLotMutex};
+use std::sync::atomic::Ordering;
+
+/// A rendezvous point for multiple threads that tracks waits for deadlock detection
+///
+/// `Barrier` mirrors [`std::sync::Barrier`]: a fixed number of parties must each call
+/// [`wait`](Barrier::wait) before any of them is allowed to proceed. Every wait is
+/// reported to the detector so that a barrier that can never fill - because one of
+/// the expected parties is itself stuck in a deadlock elsewhere - is surfaced as a
+/// deadlock instead of hanging silently forever.
+///
+/// # Example
+///
+/// ```rust
+/// use deloxide::{Barrier, thread};
+/// use std::sync::Arc;
+///
+/// let barrier = Arc::new(Barrier::new(3));
+/// let mut handles = Vec::new();
+///
+/// for _ in 0..3 {
+///     let barrier = Arc::clone(&barrier);
+///     handles.push(thread::spawn(move || {
+///         // All three threads rendezvous here before any of them continues
+///         barrier.wait();
+///     }));
+/// }
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+pub struct Barrier {
+    /// Unique identifier for this barrier
+    id: LockId,
+    /// Number of threads that must call `wait()` to release one generation
+    parties: usize,
+    /// Count of arrivals and generation for the current round
+    state: ParkingLotMutex<BarrierState>,
+    /// Wakes waiting threads once a generation fills
+    condvar: ParkingLotCondvar,
+}
+
+/// Mutable state protected by `Barrier::state`
+struct BarrierState {
+    /// Number of threads that have arrived for the current generation
+    count: usize,
+    /// Incremented every time the barrier fills, so waiters can tell a spurious
+    /// wakeup from a real release
+    generation: usize,
+}
+
+/// Returned by [`Barrier::wait`], indicating whether the calling thread was the
+/// last to arrive for this generation
+///
+/// # Example
+///
+/// ```rust
+/// use deloxide::Barrier;
+///
+/// let barrier = Barrier::new(1);
+/// let result = barrier.wait();
+/// assert!(result.is_leader());
+/// ```
+#[derive(Debug)]
+pub struct BarrierWaitResult(bool);
+
+impl BarrierWaitResult {
+    /// Whether this thread was the last one to arrive at the barrier
+    ///
+    /// Exactly one of the threads released by a given barrier generation will
+    /// observe `true` here; the rest observe `false`.
+    pub fn is_leader(&self) -> bool {
+        self.0
+    }
+}
+
+impl Barrier {
+    /// Create a new Barrier that releases once `n` threads have called `wait()`
+    ///
+    /// # Arguments
+    /// * `n` - The number of parties required to release a `wait()` call
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::Barrier;
+    ///
+    /// let barrier = Barrier::new(4);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+
+        detector::barrier::create_barrier(id, n);
+
+        Barrier {
+            id,
+            parties: n,
+            state: ParkingLotMutex::new(BarrierState {
+                count: 0,
+                generation: 0,
+            }),
+            condvar: ParkingLotCondvar::new(),
+        }
+    }
+
+    /// Get the ID of this barrier
+    ///
+    /// # Returns
+    /// The unique identifier assigned to this barrier
+    pub fn id(&self) -> LockId {
+        self.id
+    }
+
+    /// Block until all parties have called `wait()` on this barrier
+    ///
+    /// When the last of the `n` parties arrives, all waiting threads are released
+    /// and the barrier is reset for reuse. Exactly one of the released threads
+    /// (the one whose arrival filled the barrier) gets a result for which
+    /// [`BarrierWaitResult::is_leader`] returns `true`.
+    ///
+    /// # Returns
+    /// A [`BarrierWaitResult`] indicating whether this thread was the last to arrive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::Barrier;
+    ///
+    /// let barrier = Barrier::new(1);
+    /// let result = barrier.wait();
+    /// assert!(result.is_leader());
+    /// ```
+    pub fn wait(&self) -> BarrierWaitResult {
+        let thread_id = get_current_thread_id();
+
+        if let Some(info) = detector::barrier::wait_begin(thread_id, self.id) {
+            detector::deadlock_handling::process_deadlock(info);
+        }
+
+        let mut state = self.state.lock();
+        let local_generation = state.generation;
+        state.count += 1;
+
+        let is_leader = if state.count == self.parties {
+            state.count = 0;
+            state.generation = state.generation.wrapping_add(1);
+            self.condvar.notify_all();
+            true
+        } else {
+            self.condvar
+                .wait_while(&mut state, |s| s.generation == local_generation);
+            false
+        };
+
+        drop(state);
+        detector::barrier::wait_end(thread_id, self.id);
+
+        BarrierWaitResult(is_leader)
+    }
+}
+
+impl Drop for Barrier {
+    fn drop(&mut self) {
+        detector::barrier::destroy_barrier(self.id);
+    }
+}
@@ -1,16 +1,27 @@
 use crate::core::detector;
+use crate::core::locks::poison::{LockResult, PoisonError};
 use crate::core::locks::{NEXT_LOCK_ID, mutex::MutexGuard};
-use crate::core::types::{CondvarId, get_current_thread_id};
+use crate::core::stacktrace;
+use crate::core::types::{CondvarId, LockId, get_current_thread_id};
 use parking_lot::Condvar as ParkingLotCondvar;
 use std::ops::DerefMut;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 /// A wrapper around a condition variable that tracks operations for deadlock detection
 ///
 /// The Condvar provides the same interface as a standard condition variable but adds
 /// deadlock detection by tracking wait and notify operations. It's a drop-in replacement
-/// for std::sync::Condvar that enables deadlock detection.
+/// for std::sync::Condvar that enables deadlock detection, built the same way every
+/// other lock in this crate is: wrapping `parking_lot`'s primitive (here,
+/// `parking_lot::Condvar`) rather than reimplementing the parking/waking logic,
+/// and layering detector reporting around it. `wait`/`wait_timeout` report the
+/// calling thread's mutex release and condvar park to the detector, and the
+/// matching reacquisition on wakeup, mirroring the attempt/acquire two-phase
+/// pattern `RwLock::write` uses - so a cycle like "thread A parked on this
+/// condvar waiting to be woken by thread B, which is itself blocked trying to
+/// acquire a mutex A holds" is visible to the same wait-for graph that catches
+/// ordinary lock cycles.
 ///
 /// # Example
 ///
@@ -25,23 +36,44 @@ use std::time::Duration;
 /// // Spawn a thread that waits for the condition
 /// thread::spawn(move || {
 ///     let (lock, cvar) = &*pair2;
-///     let mut started = lock.lock();
+///     let mut started = lock.lock().unwrap();
 ///     while !*started {
-///         cvar.wait(&mut started);
+///         cvar.wait(&mut started).unwrap();
 ///     }
 /// });
 ///
 /// // Signal the condition in the main thread
 /// let (lock, cvar) = &*pair;
-/// let mut started = lock.lock();
+/// let mut started = lock.lock().unwrap();
 /// *started = true;
 /// cvar.notify_one();
 /// ```
+/// `wait`/`wait_timeout`/`wait_while` report the mutex as released
+/// ([`detector::condvar::begin_wait`] calls
+/// [`Detector::release_mutex`](crate::core::Detector::release_mutex)
+/// internally) in the same step as the wait-queue bookkeeping, before
+/// parking on [`ParkingLotCondvar::wait`], and report it reacquired via
+/// [`detector::mutex::complete_acquire`] immediately after waking. A parked
+/// waiter genuinely isn't holding the mutex - parking_lot unlocks it
+/// internally for the duration of the wait - so without this the detector's
+/// wait-for graph would keep a phantom edge alive on a lock the thread no
+/// longer holds, producing both false-positive deadlock reports against the
+/// sleeping thread and missed deadlocks that actually do involve it.
 pub struct Condvar {
     /// Unique identifier for this condition variable
     id: CondvarId,
     /// The wrapped parking_lot condition variable
     inner: ParkingLotCondvar,
+    /// ID of the mutex this condvar has been waited on with so far, using
+    /// AtomicUsize. 0 if it hasn't been used with any mutex yet.
+    ///
+    /// Waiting on the same condvar with two different mutexes is undefined
+    /// behavior for the underlying primitive (a notification can race with a
+    /// waiter that's blocked on the "wrong" mutex and be lost), so this is
+    /// checked on every wait the same way rust-lightning's `debug_sync`
+    /// Condvar does, rather than left to manifest as a hard-to-reproduce
+    /// missed wakeup.
+    bound_mutex: AtomicUsize,
 }
 
 impl Condvar {
@@ -61,11 +93,12 @@ impl Condvar {
         let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
 
         // Register the condvar with the detector
-        detector::condvar::on_condvar_create(id);
+        detector::condvar::create_condvar(id);
 
         Condvar {
             id,
             inner: ParkingLotCondvar::new(),
+            bound_mutex: AtomicUsize::new(0),
         }
     }
 
@@ -77,6 +110,29 @@ impl Condvar {
         self.id
     }
 
+    /// Bind this condvar to `mutex_id` on first use, or confirm that it's
+    /// still being used with the same mutex it was bound to before.
+    ///
+    /// # Panics
+    /// Panics if this condvar has previously been waited on with a
+    /// different mutex. Mixing mutexes on a single condvar is a logic bug:
+    /// a notification sent while one mutex is held can be missed by a
+    /// waiter blocked on the other, since the two waits are never ordered
+    /// against each other.
+    fn check_bound_mutex(&self, mutex_id: LockId) {
+        match self
+            .bound_mutex
+            .compare_exchange(0, mutex_id, Ordering::SeqCst, Ordering::SeqCst)
+        {
+            Ok(_) => {}
+            Err(bound) if bound == mutex_id => {}
+            Err(bound) => panic!(
+                "Condvar {} was waited on with mutex {bound} before, but is now being waited on with mutex {mutex_id}; a condvar must always be used with the same mutex",
+                self.id
+            ),
+        }
+    }
+
     /// Wait on this condition variable, releasing the associated mutex and blocking
     /// until another thread notifies this condition variable
     ///
@@ -88,6 +144,18 @@ impl Condvar {
     /// # Arguments
     /// * `guard` - A mutable reference to a MutexGuard that will be atomically unlocked
     ///
+    /// # Returns
+    /// `Ok(())` once the mutex is reacquired, or `Err(PoisonError::new(()))` if
+    /// the mutex was found poisoned on reacquisition - which can happen even
+    /// though this thread never saw the panic itself, since the notifier (or
+    /// any other thread) may have panicked while holding the mutex between
+    /// this thread's wakeup and its own reacquire. The guard is always
+    /// usable either way; the caller opts into the possibly-tainted data by
+    /// unwrapping or matching on the error, the same as [`crate::Mutex::lock`].
+    ///
+    /// # Panics
+    /// Panics if this condvar was previously waited on with a different mutex.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -98,27 +166,32 @@ impl Condvar {
     /// let (lock, cvar) = &*pair;
     ///
     /// // In a real application, you would use this in a loop:
-    /// // let mut guard = lock.lock();
+    /// // let mut guard = lock.lock().unwrap();
     /// // while !*guard {
-    /// //     cvar.wait(&mut guard);
+    /// //     cvar.wait(&mut guard).unwrap();
     /// // }
     /// ```
-    pub fn wait<'a, T>(&self, guard: &mut MutexGuard<'a, T>) {
+    pub fn wait<'a, T>(&self, guard: &mut MutexGuard<'a, T>) -> LockResult<()> {
         let thread_id = get_current_thread_id();
         let mutex_id = guard.lock_id();
+        self.check_bound_mutex(mutex_id);
 
-        // Report wait begin - this logs the condvar wait and simulates mutex release
-        detector::condvar::on_wait_begin(thread_id, self.id, mutex_id);
-
-        // Explicitly report mutex release since parking_lot will unlock it internally
-        detector::mutex::on_mutex_release(thread_id, mutex_id);
+        // Report wait begin and mutex release together, atomically, since
+        // parking_lot will unlock the mutex internally as part of the wait
+        detector::condvar::begin_wait(thread_id, self.id, mutex_id, false);
 
         // Perform the actual wait operation
         self.inner.wait(guard.inner_guard());
 
         // Report wait end and mutex reacquisition
-        detector::condvar::on_wait_end(thread_id, self.id, mutex_id);
-        detector::mutex::on_mutex_acquired(thread_id, mutex_id);
+        detector::condvar::end_wait(thread_id, self.id, mutex_id, false);
+        detector::mutex::complete_acquire(thread_id, mutex_id, stacktrace::capture());
+
+        if guard.is_poisoned() {
+            Err(PoisonError::new(()))
+        } else {
+            Ok(())
+        }
     }
 
     /// Wait on this condition variable with a timeout
@@ -133,7 +206,14 @@ impl Condvar {
     /// * `timeout` - The maximum duration to wait
     ///
     /// # Returns
-    /// `true` if the timeout elapsed, `false` if the condition variable was notified
+    /// `Ok(true)` if the timeout elapsed, `Ok(false)` if the condition
+    /// variable was notified, or `Err(PoisonError::new(timed_out))` if the
+    /// mutex was found poisoned on reacquisition - see [`Condvar::wait`] for
+    /// why this can happen even if this thread never panicked itself. The
+    /// timeout outcome is still recoverable via [`crate::PoisonError::into_inner`].
+    ///
+    /// # Panics
+    /// Panics if this condvar was previously waited on with a different mutex.
     ///
     /// # Example
     ///
@@ -145,31 +225,39 @@ impl Condvar {
     /// let pair = Arc::new((Mutex::new(false), Condvar::new()));
     /// let (lock, cvar) = &*pair;
     ///
-    /// let mut guard = lock.lock();
-    /// let timed_out = cvar.wait_timeout(&mut guard, Duration::from_millis(100));
+    /// let mut guard = lock.lock().unwrap();
+    /// let timed_out = cvar.wait_timeout(&mut guard, Duration::from_millis(100)).unwrap();
     /// if timed_out {
     ///     println!("Timed out waiting for condition");
     /// }
     /// ```
-    pub fn wait_timeout<'a, T>(&self, guard: &mut MutexGuard<'a, T>, timeout: Duration) -> bool {
+    pub fn wait_timeout<'a, T>(
+        &self,
+        guard: &mut MutexGuard<'a, T>,
+        timeout: Duration,
+    ) -> LockResult<bool> {
         let thread_id = get_current_thread_id();
         let mutex_id = guard.lock_id();
+        self.check_bound_mutex(mutex_id);
 
-        // Report wait begin - this logs the condvar wait and simulates mutex release
-        detector::condvar::on_wait_begin(thread_id, self.id, mutex_id);
-
-        // Explicitly report mutex release since parking_lot will unlock it internally
-        detector::mutex::on_mutex_release(thread_id, mutex_id);
+        // Report wait begin and mutex release together, atomically, since
+        // parking_lot will unlock the mutex internally as part of the wait
+        detector::condvar::begin_wait(thread_id, self.id, mutex_id, true);
 
         // Perform the actual wait operation with timeout
         let wait_result = self.inner.wait_for(guard.inner_guard(), timeout);
         let timed_out = wait_result.timed_out();
 
-        // Report wait end and mutex reacquisition
-        detector::condvar::on_wait_end(thread_id, self.id, mutex_id);
-        detector::mutex::on_mutex_acquired(thread_id, mutex_id);
+        // Report wait end (distinguishing a timeout from a real notification) and
+        // mutex reacquisition
+        detector::condvar::end_wait(thread_id, self.id, mutex_id, timed_out);
+        detector::mutex::complete_acquire(thread_id, mutex_id, stacktrace::capture());
 
-        timed_out
+        if guard.is_poisoned() {
+            Err(PoisonError::new(timed_out))
+        } else {
+            Ok(timed_out)
+        }
     }
 
     /// Blocks the current thread until the provided condition becomes false
@@ -190,17 +278,28 @@ impl Condvar {
     /// let pair = Arc::new((Mutex::new(true), Condvar::new()));
     /// let (lock, cvar) = &*pair;
     ///
-    /// let mut guard = lock.lock();
+    /// let mut guard = lock.lock().unwrap();
     /// // Wait while the value is true
-    /// cvar.wait_while(&mut guard, |pending| *pending);
+    /// cvar.wait_while(&mut guard, |pending| *pending).unwrap();
     /// ```
-    pub fn wait_while<'a, T, F>(&self, guard: &mut MutexGuard<'a, T>, mut condition: F)
+    ///
+    /// # Returns
+    /// `Ok(())` once `condition` returns `false`, or `Err(PoisonError::new(()))`
+    /// as soon as a reacquire observes the mutex poisoned - the loop
+    /// short-circuits there instead of calling `condition` again, since the
+    /// data it would inspect may already be inconsistent.
+    pub fn wait_while<'a, T, F>(
+        &self,
+        guard: &mut MutexGuard<'a, T>,
+        mut condition: F,
+    ) -> LockResult<()>
     where
         F: FnMut(&mut T) -> bool,
     {
         while condition(guard.deref_mut()) {
-            self.wait(guard);
+            self.wait(guard)?;
         }
+        Ok(())
     }
 
     /// Waits on this condition variable with a timeout while a condition is true
@@ -226,19 +325,24 @@ impl Condvar {
     /// let pair = Arc::new((Mutex::new(true), Condvar::new()));
     /// let (lock, cvar) = &*pair;
     ///
-    /// let mut guard = lock.lock();
+    /// let mut guard = lock.lock().unwrap();
     /// let timed_out = cvar.wait_timeout_while(
     ///     &mut guard,
     ///     Duration::from_millis(100),
     ///     |pending| *pending
-    /// );
+    /// ).unwrap();
     /// ```
+    ///
+    /// # Returns
+    /// `Ok(true)` if the timeout elapsed, `Ok(false)` once `condition` became
+    /// `false`, or `Err(PoisonError::new(timed_out))` as soon as a reacquire
+    /// observes the mutex poisoned - see [`Condvar::wait_while`].
     pub fn wait_timeout_while<'a, T, F>(
         &self,
         guard: &mut MutexGuard<'a, T>,
         timeout: Duration,
         mut condition: F,
-    ) -> bool
+    ) -> LockResult<bool>
     where
         F: FnMut(&mut T) -> bool,
     {
@@ -246,14 +350,14 @@ impl Condvar {
         while condition(guard.deref_mut()) {
             let elapsed = start.elapsed();
             if elapsed >= timeout {
-                return true; // Timed out
+                return Ok(true); // Timed out
             }
             let remaining = timeout - elapsed;
-            if self.wait_timeout(guard, remaining) {
-                return true; // Timed out in wait_timeout
+            if self.wait_timeout(guard, remaining)? {
+                return Ok(true); // Timed out in wait_timeout
             }
         }
-        false // Condition became false
+        Ok(false) // Condition became false
     }
 
     /// Wake up one blocked thread on this condition variable
@@ -272,7 +376,7 @@ impl Condvar {
     ///
     /// // ... some other thread is waiting on cvar ...
     ///
-    /// let mut guard = lock.lock();
+    /// let mut guard = lock.lock().unwrap();
     /// *guard = true;
     /// drop(guard); // Release the lock before notifying
     /// cvar.notify_one();
@@ -281,7 +385,7 @@ impl Condvar {
         let thread_id = get_current_thread_id();
 
         // Report the notify operation to the detector first (for synthetic mutex attempts)
-        detector::condvar::on_notify_one(self.id, thread_id);
+        detector::condvar::notify_one(self.id, thread_id);
 
         // Perform the actual notification
         self.inner.notify_one();
@@ -303,7 +407,7 @@ impl Condvar {
     ///
     /// // ... multiple threads are waiting on cvar ...
     ///
-    /// let mut guard = lock.lock();
+    /// let mut guard = lock.lock().unwrap();
     /// *guard = true;
     /// drop(guard); // Release the lock before notifying
     /// cvar.notify_all();
@@ -312,7 +416,7 @@ impl Condvar {
         let thread_id = get_current_thread_id();
 
         // Report the notify operation to the detector first (for synthetic mutex attempts)
-        detector::condvar::on_notify_all(self.id, thread_id);
+        detector::condvar::notify_all(self.id, thread_id);
 
         // Perform the actual notification
         self.inner.notify_all();
@@ -328,6 +432,6 @@ impl Default for Condvar {
 impl Drop for Condvar {
     fn drop(&mut self) {
         // Register the condvar destruction with the detector
-        detector::condvar::on_condvar_destroy(self.id);
+        detector::condvar::destroy_condvar(self.id);
     }
 }
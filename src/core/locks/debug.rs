@@ -0,0 +1,43 @@
+//! Zero-overhead debug/release lock aliases
+//!
+//! Mirrors the `tracing-mutex` crate's `DebugMutex` (and `epaint`'s analogous
+//! aliases): [`DebugMutex`]/[`DebugRwLock`] resolve to the tracked
+//! [`crate::Mutex`]/[`crate::RwLock`] wrappers when `debug_assertions` are
+//! on, and to plain [`std::sync::Mutex`]/[`std::sync::RwLock`] when they're
+//! off. A release build therefore never runs `detector::on_lock_*`, never
+//! touches `NEXT_LOCK_ID`, and pays nothing for the instrumentation - while
+//! `cargo build`/`cargo test` (which default to debug assertions on) still
+//! get full deadlock tracking.
+//!
+//! The two arms only share the subset of the API both underlying types
+//! actually have in common (`new`, `lock`/`read`/`write`,
+//! `try_lock`/`try_read`/`try_write`); code written against these aliases
+//! should stick to that subset so it builds the same way in both modes.
+//! Anything tracked-specific (`id()`, `creator_thread_id()`,
+//! `lock_owned`/`map`, ...) isn't available through the alias - reach for
+//! [`crate::Mutex`]/[`crate::RwLock`] directly if you need it.
+
+#[cfg(debug_assertions)]
+pub type DebugMutex<T> = crate::core::locks::mutex::Mutex<T>;
+#[cfg(not(debug_assertions))]
+pub type DebugMutex<T> = std::sync::Mutex<T>;
+
+#[cfg(debug_assertions)]
+pub type DebugMutexGuard<'a, T> = crate::core::locks::mutex::MutexGuard<'a, T>;
+#[cfg(not(debug_assertions))]
+pub type DebugMutexGuard<'a, T> = std::sync::MutexGuard<'a, T>;
+
+#[cfg(debug_assertions)]
+pub type DebugRwLock<T> = crate::core::locks::rwlock::RwLock<T>;
+#[cfg(not(debug_assertions))]
+pub type DebugRwLock<T> = std::sync::RwLock<T>;
+
+#[cfg(debug_assertions)]
+pub type DebugRwLockReadGuard<'a, T> = crate::core::locks::rwlock::RwLockReadGuard<'a, T>;
+#[cfg(not(debug_assertions))]
+pub type DebugRwLockReadGuard<'a, T> = std::sync::RwLockReadGuard<'a, T>;
+
+#[cfg(debug_assertions)]
+pub type DebugRwLockWriteGuard<'a, T> = crate::core::locks::rwlock::RwLockWriteGuard<'a, T>;
+#[cfg(not(debug_assertions))]
+pub type DebugRwLockWriteGuard<'a, T> = std::sync::RwLockWriteGuard<'a, T>;
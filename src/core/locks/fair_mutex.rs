@@ -0,0 +1,503 @@
+//! A tracked mutex with fair (FIFO-leaning) unlock semantics
+//!
+//! Ordinary lock handoff favors the thread that just released the lock if it
+//! immediately tries to re-acquire it ("barging"), which can mask lock orderings
+//! that only appear under fairer scheduling. `FairMutex` records how long each
+//! waiter has been blocked and, once the longest-waiting thread has blocked past
+//! a configurable threshold, hands the lock directly to it on unlock instead of
+//! releasing to open competition.
+
+use crate::core::detector;
+use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::locks::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
+use crate::core::stacktrace;
+use crate::core::types::{LockId, ThreadId, get_current_thread_id};
+#[cfg(feature = "logging-and-visualization")]
+use crate::core::{Events, logger};
+use fxhash::FxHashMap;
+use parking_lot::{Mutex as ParkingLotMutex, MutexGuard as ParkingLotMutexGuard};
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Default fairness threshold: a waiter blocked longer than this is handed the
+/// lock directly on unlock instead of having to compete for it again.
+pub const DEFAULT_FAIRNESS_THRESHOLD: Duration = Duration::from_micros(500);
+
+/// Aggregated wait-time statistics for a `FairMutex`, covering every
+/// acquisition that went through the slow (contended) path
+///
+/// See [`FairMutex::wait_stats`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WaitStats {
+    /// The single longest time any thread has spent blocked in
+    /// `MutexAttempt` before acquiring this lock
+    pub max: Duration,
+    /// The sum of every thread's time spent blocked in `MutexAttempt`
+    /// before acquiring this lock
+    pub total: Duration,
+    /// How many contended acquisitions have contributed to `total`
+    pub count: u64,
+}
+
+/// Tracks how long each waiter has been blocked on a `FairMutex`, and
+/// accumulates wait-time statistics across every contended acquisition
+struct WaitTracker {
+    waiters: ParkingLotMutex<FxHashMap<ThreadId, Instant>>,
+    threshold: Duration,
+    max_wait_us: AtomicU64,
+    total_wait_us: AtomicU64,
+    count: AtomicU64,
+}
+
+impl WaitTracker {
+    fn new(threshold: Duration) -> Self {
+        WaitTracker {
+            waiters: ParkingLotMutex::new(FxHashMap::default()),
+            threshold,
+            max_wait_us: AtomicU64::new(0),
+            total_wait_us: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn begin_wait(&self, thread_id: ThreadId) {
+        self.waiters.lock().insert(thread_id, Instant::now());
+    }
+
+    /// Stop tracking `thread_id` as a waiter, fold its wait time into the
+    /// running statistics, and return how long it waited
+    fn end_wait(&self, thread_id: ThreadId) -> Duration {
+        let started = self
+            .waiters
+            .lock()
+            .remove(&thread_id)
+            .unwrap_or_else(Instant::now);
+        let waited = started.elapsed();
+
+        let waited_us = waited.as_micros().min(u64::MAX as u128) as u64;
+        self.max_wait_us.fetch_max(waited_us, Ordering::Relaxed);
+        self.total_wait_us.fetch_add(waited_us, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+
+        waited
+    }
+
+    /// Whether the lock should be handed off fairly to the longest-waiting thread
+    fn should_hand_off(&self) -> bool {
+        #[cfg(feature = "stress-test")]
+        if detector::mutex::fair_unlock_forced() {
+            return true;
+        }
+
+        self.waiters
+            .lock()
+            .values()
+            .any(|started| started.elapsed() >= self.threshold)
+    }
+
+    fn stats(&self) -> WaitStats {
+        WaitStats {
+            max: Duration::from_micros(self.max_wait_us.load(Ordering::Relaxed)),
+            total: Duration::from_micros(self.total_wait_us.load(Ordering::Relaxed)),
+            count: self.count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A wrapper around a mutex that tracks lock operations for deadlock detection
+/// and hands off the lock fairly to long-waiting threads on unlock
+///
+/// Behaves exactly like [`crate::Mutex`] except for its unlock policy: if the
+/// longest-waiting thread has blocked past the fairness threshold (or
+/// [`crate::StressConfig::fair_unlock`] forces it), the lock is handed directly
+/// to that thread instead of being released to open competition.
+pub struct FairMutex<T> {
+    /// Unique identifier for this mutex
+    id: LockId,
+    /// The wrapped mutex
+    inner: ParkingLotMutex<T>,
+    /// Thread that created this mutex
+    creator_thread_id: ThreadId,
+    /// Stores the ThreadId of the current owner (0 if unlocked)
+    owner: AtomicUsize,
+    /// Set when a guard was dropped during a panic, poisoning the data
+    poisoned: AtomicBool,
+    /// Tracks how long each waiter has been blocked
+    wait_tracker: WaitTracker,
+}
+
+/// Guard for a FairMutex, reports lock release and fairness decision when dropped
+pub struct FairMutexGuard<'a, T> {
+    /// Thread that owns this guard
+    thread_id: ThreadId,
+    /// Lock that this guard is for
+    lock_id: LockId,
+    /// The inner MutexGuard, manually dropped so we can choose a fair unlock
+    guard: ManuallyDrop<ParkingLotMutexGuard<'a, T>>,
+    /// Reference to the owner atomic to clear it on drop
+    owner_atomic: &'a AtomicUsize,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
+    /// Whether this lock acquisition was tracked by the global detector
+    tracked_globally: bool,
+    /// Reference to the wait tracker used to decide fair handoff on drop
+    wait_tracker: &'a WaitTracker,
+}
+
+impl<T> FairMutex<T> {
+    /// Create a new FairMutex with an automatically assigned ID and the default
+    /// fairness threshold ([`DEFAULT_FAIRNESS_THRESHOLD`])
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::FairMutex;
+    ///
+    /// let mutex = FairMutex::new(42);
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self::with_fairness_threshold(value, DEFAULT_FAIRNESS_THRESHOLD)
+    }
+
+    /// Create a new FairMutex with a custom fairness threshold
+    ///
+    /// # Arguments
+    /// * `value` - The initial value to store in the mutex
+    /// * `threshold` - How long a waiter must be blocked before it is handed the
+    ///   lock directly on unlock
+    pub fn with_fairness_threshold(value: T, threshold: Duration) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+        let creator_thread_id = get_current_thread_id();
+
+        detector::mutex::create_mutex(id, Some(creator_thread_id));
+
+        FairMutex {
+            id,
+            inner: ParkingLotMutex::new(value),
+            creator_thread_id,
+            owner: AtomicUsize::new(0),
+            poisoned: AtomicBool::new(false),
+            wait_tracker: WaitTracker::new(threshold),
+        }
+    }
+
+    /// Get the ID of this mutex
+    pub fn id(&self) -> LockId {
+        self.id
+    }
+
+    /// Get the ID of the thread that created this mutex
+    pub fn creator_thread_id(&self) -> ThreadId {
+        self.creator_thread_id
+    }
+
+    /// Acquire the lock, blocking if necessary
+    ///
+    /// Uses the same optimistic fast path and two-phase detection as
+    /// [`crate::Mutex::lock`]; the only difference is in how the lock is released.
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn lock(&self) -> LockResult<FairMutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id;
+
+        // Optimistic Fast Path (Disabled during stress testing to ensure full detector coverage)
+        #[cfg(not(feature = "stress-test"))]
+        if let Some(guard) = self.inner.try_lock() {
+            self.owner.store(tid_usize, Ordering::Release);
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAttempt);
+                }
+            }
+
+            #[cfg(feature = "lock-order-graph")]
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAcquired);
+                }
+            }
+
+            let guard = self.wrap_guard(thread_id, guard, cfg!(feature = "lock-order-graph"));
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(PoisonError::new(guard))
+            } else {
+                Ok(guard)
+            };
+        }
+
+        // Slow Path (Contention)
+        let mut current_owner_val = self.owner.load(Ordering::Acquire);
+
+        if current_owner_val == 0 && self.inner.is_locked() {
+            let mut spin_count = 0;
+            while current_owner_val == 0 {
+                if spin_count < 100 {
+                    std::hint::spin_loop();
+                } else {
+                    std::thread::yield_now();
+                }
+
+                current_owner_val = self.owner.load(Ordering::Relaxed);
+                spin_count += 1;
+
+                if spin_count % 16 == 0 && !self.inner.is_locked() {
+                    break;
+                }
+            }
+            std::sync::atomic::fence(Ordering::Acquire);
+        }
+
+        let current_owner = if current_owner_val == 0 {
+            None
+        } else {
+            Some(current_owner_val as ThreadId)
+        };
+
+        let deadlock_info =
+            detector::mutex::acquire_slow(thread_id, self.id, current_owner, stacktrace::capture());
+
+        if let Some(info) = deadlock_info {
+            let is_stale = if let Some(expected_owner) = current_owner {
+                let actual_owner = self.owner.load(Ordering::Relaxed);
+                !detector::deadlock_handling::verify_deadlock_edges(
+                    &info,
+                    thread_id,
+                    self.id,
+                    expected_owner,
+                    actual_owner,
+                )
+            } else {
+                false
+            };
+
+            if !is_stale {
+                detector::deadlock_handling::process_deadlock(info);
+            }
+        }
+
+        // Record that we're about to block, so a fair unlocker can find us
+        self.wait_tracker.begin_wait(thread_id);
+        let guard = self.inner.lock();
+        #[cfg_attr(not(feature = "logging-and-visualization"), allow(unused_variables))]
+        let waited = self.wait_tracker.end_wait(thread_id);
+
+        detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+        self.owner.store(tid_usize, Ordering::Release);
+
+        // Surface starvation that isn't a deadlock: the thread did get the lock,
+        // but only after waiting past the fairness threshold.
+        #[cfg(feature = "logging-and-visualization")]
+        if waited >= self.wait_tracker.threshold && logger::LOGGING_ENABLED.load(Ordering::Relaxed)
+        {
+            logger::log_lock_starvation(thread_id, self.id, waited.as_micros() as u64);
+        }
+
+        let guard = self.wrap_guard(thread_id, guard, true);
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Try to acquire the lock without blocking
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding this
+    /// mutex, or [`TryLockError::WouldBlock`] if the lock is currently held.
+    pub fn try_lock(&self) -> TryLockResult<FairMutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id;
+
+        if let Some(guard) = self.inner.try_lock() {
+            self.owner.store(tid_usize, Ordering::Release);
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAttempt);
+                }
+            }
+
+            #[cfg(feature = "lock-order-graph")]
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAcquired);
+                }
+            }
+
+            let guard = self.wrap_guard(thread_id, guard, cfg!(feature = "lock-order-graph"));
+
+            if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            }
+        } else {
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    fn wrap_guard<'a>(
+        &'a self,
+        thread_id: ThreadId,
+        guard: ParkingLotMutexGuard<'a, T>,
+        tracked_globally: bool,
+    ) -> FairMutexGuard<'a, T> {
+        FairMutexGuard {
+            thread_id,
+            lock_id: self.id,
+            guard: ManuallyDrop::new(guard),
+            owner_atomic: &self.owner,
+            poisoned: &self.poisoned,
+            tracked_globally,
+            wait_tracker: &self.wait_tracker,
+        }
+    }
+
+    /// Wait-time statistics for every contended acquisition of this lock so far
+    ///
+    /// Surfaces starvation that isn't a deadlock: a thread that waits
+    /// unusually long still eventually gets the lock, so the detector has
+    /// nothing to report, but [`WaitStats::max`] climbing over time is a
+    /// sign of contention worth investigating. See also the
+    /// [`Events::MutexStarvation`] log event, emitted whenever a single
+    /// acquisition waits past the fairness threshold.
+    pub fn wait_stats(&self) -> WaitStats {
+        self.wait_tracker.stats()
+    }
+
+    /// Returns `true` if the mutex is poisoned.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Clears the poisoned state of this mutex.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+    }
+
+    /// Consumes this mutex, returning the underlying data
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn into_inner(self) -> LockResult<T>
+    where
+        T: Sized,
+    {
+        let poisoned = self.poisoned.load(Ordering::Acquire);
+
+        detector::mutex::destroy_mutex(self.id);
+
+        let mutex = ManuallyDrop::new(self);
+        let value = unsafe { std::ptr::read(&mutex.inner) }.into_inner();
+
+        if poisoned {
+            Err(PoisonError::new(value))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(self.inner.get_mut()))
+        } else {
+            Ok(self.inner.get_mut())
+        }
+    }
+}
+
+impl<T> Drop for FairMutex<T> {
+    fn drop(&mut self) {
+        detector::mutex::destroy_mutex(self.id);
+    }
+}
+
+impl<T> Deref for FairMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<T> DerefMut for FairMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.deref_mut()
+    }
+}
+
+impl<T> Drop for FairMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // 0. Poison the mutex if we're unwinding from a panic while holding the guard
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::MutexPoisoned);
+            }
+        }
+
+        // 1. Clear local ownership first
+        self.owner_atomic.store(0, Ordering::Release);
+
+        // 2. Report lock release (detector and/or logger)
+        if self.tracked_globally {
+            detector::mutex::release_mutex(self.thread_id, self.lock_id);
+        } else {
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::MutexReleased);
+            }
+        }
+
+        // 3. Unlock, handing off directly to the longest-waiting thread if it has
+        // blocked past the fairness threshold.
+        //
+        // Safety: `self.guard` is read exactly once here, and `self` is being
+        // dropped so it will never be observed again afterwards.
+        let guard = unsafe { ManuallyDrop::take(&mut self.guard) };
+        if self.wait_tracker.should_hand_off() {
+            ParkingLotMutexGuard::unlock_fair(guard);
+        } else {
+            drop(guard);
+        }
+    }
+}
+
+// Trait implementations for better compatibility with std
+
+impl<T: Default> Default for FairMutex<T> {
+    /// Creates a `FairMutex<T>`, with the Default value for T
+    fn default() -> FairMutex<T> {
+        FairMutex::new(Default::default())
+    }
+}
+
+impl<T> From<T> for FairMutex<T> {
+    /// Creates a new mutex in an unlocked state ready for use
+    /// This is equivalent to FairMutex::new
+    fn from(t: T) -> Self {
+        FairMutex::new(t)
+    }
+}
@@ -1,5 +1,16 @@
+#[cfg(feature = "async")]
+pub mod async_mutex;
+#[cfg(feature = "async")]
+pub mod async_rwlock;
+pub mod barrier;
 pub mod condvar;
+pub mod debug;
+pub mod fair_mutex;
 pub mod mutex;
+pub mod once;
+pub mod poison;
+pub mod priority_mutex;
+pub mod reentrant_mutex;
 pub mod rwlock;
 
 use std::sync::atomic::AtomicUsize;
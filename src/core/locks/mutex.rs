@@ -1,12 +1,19 @@
 use crate::core::detector;
 use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::locks::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
+use crate::core::stacktrace;
 
-use crate::core::types::{LockId, ThreadId, get_current_thread_id};
+use crate::core::types::{LockHeldState, LockId, ThreadId, get_current_thread_id};
 #[cfg(feature = "logging-and-visualization")]
 use crate::core::{Events, logger};
-use parking_lot::{Mutex as ParkingLotMutex, MutexGuard as ParkingLotMutexGuard};
+use parking_lot::{
+    MappedMutexGuard as ParkingLotMappedMutexGuard, Mutex as ParkingLotMutex,
+    MutexGuard as ParkingLotMutexGuard,
+};
 use std::ops::{Deref, DerefMut};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 /// A wrapper around a mutex that tracks lock operations for deadlock detection
 ///
@@ -29,12 +36,12 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 ///
 /// // Use it just like a regular mutex
 /// thread::spawn(move || {
-///     let mut data = mutex.lock();
+///     let mut data = mutex.lock().unwrap();
 ///     *data += 1;
 /// });
 ///
 /// // In another thread
-/// let mut data = mutex_clone.lock();
+/// let mut data = mutex_clone.lock().unwrap();
 /// *data += 10;
 /// ```
 pub struct Mutex<T> {
@@ -47,6 +54,8 @@ pub struct Mutex<T> {
     /// Stores the ThreadId of the current owner (0 if unlocked).
     /// This allows us to skip the global detector on the fast path.
     owner: AtomicUsize,
+    /// Set when a guard was dropped during a panic, poisoning the data
+    poisoned: AtomicBool,
 }
 
 /// Guard for a Mutex, reports lock release when dropped
@@ -63,6 +72,51 @@ pub struct MutexGuard<'a, T> {
     guard: ParkingLotMutexGuard<'a, T>,
     /// Reference to the owner atomic to clear it on drop
     owner_atomic: &'a AtomicUsize,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
+    /// Whether this lock acquisition was tracked by the global detector
+    tracked_globally: bool,
+}
+
+/// An owned guard for a Mutex, holding an `Arc` clone instead of borrowing
+///
+/// Unlike [`MutexGuard`], this guard has no lifetime tied to the `Mutex`, so it
+/// can be moved into a spawned thread or stored in a struct without borrow
+/// gymnastics. Obtained via [`Mutex::lock_owned`] or [`Mutex::try_lock_owned`].
+pub struct OwnedMutexGuard<T> {
+    /// The inner parking_lot guard, lifetime-extended to `'static`
+    ///
+    /// # Safety
+    /// This actually borrows from `mutex.inner` below. Since `mutex` is an
+    /// `Arc` clone kept alive alongside it and dropped after it (struct
+    /// fields drop in declaration order), the borrow stays valid for the
+    /// guard's entire lifetime despite the `'static` annotation.
+    guard: ParkingLotMutexGuard<'static, T>,
+    /// Keeps the mutex (and the data it protects) alive for as long as this
+    /// guard exists
+    mutex: Arc<Mutex<T>>,
+    /// Thread that owns this guard
+    thread_id: ThreadId,
+    /// Whether this lock acquisition was tracked by the global detector
+    tracked_globally: bool,
+}
+
+/// A guard for a sub-field of a `Mutex`'s data, produced by [`MutexGuard::map`]
+///
+/// Keeps the same drop-time reporting as [`MutexGuard`] (clearing ownership and
+/// releasing through the detector/logger), but derefs to the projected `U`
+/// instead of the original `T`.
+pub struct MappedMutexGuard<'a, U: ?Sized> {
+    /// Thread that owns this guard
+    thread_id: ThreadId,
+    /// Lock that this guard is for
+    lock_id: LockId,
+    /// The inner parking_lot mapped guard, keeping the original guard alive
+    guard: ParkingLotMappedMutexGuard<'a, U>,
+    /// Reference to the owner atomic to clear it on drop
+    owner_atomic: &'a AtomicUsize,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
     /// Whether this lock acquisition was tracked by the global detector
     tracked_globally: bool,
 }
@@ -95,6 +149,7 @@ impl<T> Mutex<T> {
             inner: ParkingLotMutex::new(value),
             creator_thread_id,
             owner: AtomicUsize::new(0),
+            poisoned: AtomicBool::new(false),
         }
     }
 
@@ -114,6 +169,23 @@ impl<T> Mutex<T> {
         self.creator_thread_id
     }
 
+    /// Query whether this mutex is currently held, and by whom
+    ///
+    /// Lets a test assert lock discipline (e.g. "this mutex must not still
+    /// be held here") without reaching into detector internals.
+    ///
+    /// # Returns
+    /// * `LockHeldState::HeldByCurrentThread` if the calling thread holds this mutex
+    /// * `LockHeldState::HeldByOtherThread(id)` if another thread holds it
+    /// * `LockHeldState::NotHeld` if it's currently unlocked
+    pub fn held_state(&self) -> LockHeldState {
+        match self.owner.load(Ordering::Acquire) {
+            0 => LockHeldState::NotHeld,
+            owner if owner == get_current_thread_id() => LockHeldState::HeldByCurrentThread,
+            owner => LockHeldState::HeldByOtherThread(owner),
+        }
+    }
+
     /// Acquire the lock, blocking if necessary
     ///
     /// Uses atomic deadlock detection to prevent race conditions.
@@ -128,11 +200,15 @@ impl<T> Mutex<T> {
     ///
     /// let mutex = Mutex::new(42);
     /// {
-    ///     let guard = mutex.lock();
+    ///     let guard = mutex.lock().unwrap();
     ///     assert_eq!(*guard, 42);
     /// } // lock is automatically released when guard goes out of scope
     /// ```
-    pub fn lock(&self) -> MutexGuard<'_, T> {
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    /// The guard is still returned inside the error so the caller can recover it.
+    pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
         let thread_id = get_current_thread_id();
         let tid_usize = thread_id;
 
@@ -149,7 +225,7 @@ impl<T> Mutex<T> {
             }
 
             #[cfg(feature = "lock-order-graph")]
-            detector::mutex::complete_acquire(thread_id, self.id);
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
 
             #[cfg(feature = "logging-and-visualization")]
             {
@@ -158,52 +234,27 @@ impl<T> Mutex<T> {
                 }
             }
 
-            return MutexGuard {
+            let guard = MutexGuard {
                 thread_id,
                 lock_id: self.id,
                 guard,
                 owner_atomic: &self.owner,
+                poisoned: &self.poisoned,
                 tracked_globally: cfg!(feature = "lock-order-graph"),
             };
-        }
-
-        // Slow Path (Contention)
-        // Read the current owner to report the dependency.
-        let mut current_owner_val = self.owner.load(Ordering::Acquire);
-
-        // Adaptive Backoff:
-        // If the lock is physically held but we don't see an owner yet, it means
-        // the owner is in the tiny gap between acquiring the lock and setting the owner ID.
-        if current_owner_val == 0 && self.inner.is_locked() {
-            let mut spin_count = 0;
-            while current_owner_val == 0 {
-                if spin_count < 100 {
-                    std::hint::spin_loop();
-                } else {
-                    std::thread::yield_now();
-                }
-
-                // Use Relaxed loading during the spin loop for performance
-                current_owner_val = self.owner.load(Ordering::Relaxed);
-                spin_count += 1;
 
-                // Optimization: Only check lock state occasionally to reduce cache traffic
-                // If the lock is released, current_owner_val might remain 0, so we must check.
-                if spin_count % 16 == 0 && !self.inner.is_locked() {
-                    break;
-                }
-            }
-            // Final Acquire fence to ensure we see the data associated with the owner store
-            std::sync::atomic::fence(Ordering::Acquire);
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(PoisonError::new(guard))
+            } else {
+                Ok(guard)
+            };
         }
 
-        let current_owner = if current_owner_val == 0 {
-            None
-        } else {
-            Some(current_owner_val as ThreadId)
-        };
+        // Slow Path (Contention)
+        let current_owner = self.spin_for_owner_hint();
 
-        let deadlock_info = detector::mutex::acquire_slow(thread_id, self.id, current_owner);
+        let deadlock_info =
+            detector::mutex::acquire_slow(thread_id, self.id, current_owner, stacktrace::capture());
 
         if let Some(info) = deadlock_info {
             // Verify the edge is still valid (it might be stale if the owner released the lock).
@@ -229,15 +280,62 @@ impl<T> Mutex<T> {
         let guard = self.inner.lock();
 
         // Update state
-        detector::mutex::complete_acquire(thread_id, self.id);
+        detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
         self.owner.store(tid_usize, Ordering::Release);
 
-        MutexGuard {
+        let guard = MutexGuard {
             thread_id,
             lock_id: self.id,
             guard,
             owner_atomic: &self.owner,
+            poisoned: &self.poisoned,
             tracked_globally: true,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Determine the current owner of the lock when the fast path fails
+    ///
+    /// Reads the atomic owner hint, spinning briefly with adaptive backoff to
+    /// cover the tiny gap between a fast-path thread acquiring the lock and
+    /// recording itself as owner. Used by the blocking slow paths (`lock`,
+    /// `lock_for`, `lock_until`); `try_lock` takes a single snapshot instead
+    /// since it must not add latency to a non-blocking call.
+    fn spin_for_owner_hint(&self) -> Option<ThreadId> {
+        let mut current_owner_val = self.owner.load(Ordering::Acquire);
+
+        if current_owner_val == 0 && self.inner.is_locked() {
+            let mut spin_count = 0;
+            while current_owner_val == 0 {
+                if spin_count < 100 {
+                    std::hint::spin_loop();
+                } else {
+                    std::thread::yield_now();
+                }
+
+                // Use Relaxed loading during the spin loop for performance
+                current_owner_val = self.owner.load(Ordering::Relaxed);
+                spin_count += 1;
+
+                // Optimization: Only check lock state occasionally to reduce cache traffic
+                // If the lock is released, current_owner_val might remain 0, so we must check.
+                if spin_count % 16 == 0 && !self.inner.is_locked() {
+                    break;
+                }
+            }
+            // Final Acquire fence to ensure we see the data associated with the owner store
+            std::sync::atomic::fence(Ordering::Acquire);
+        }
+
+        if current_owner_val == 0 {
+            None
+        } else {
+            Some(current_owner_val as ThreadId)
         }
     }
 
@@ -253,15 +351,16 @@ impl<T> Mutex<T> {
     /// let mutex = Mutex::new(42);
     ///
     /// // Non-blocking attempt to acquire the lock
-    /// if let Some(guard) = mutex.try_lock() {
-    ///     // Lock was acquired
-    ///     assert_eq!(*guard, 42);
-    /// } else {
-    ///     // Lock was already held by another thread
-    ///     println!("Lock already held by another thread");
+    /// match mutex.try_lock() {
+    ///     Ok(guard) => assert_eq!(*guard, 42),
+    ///     Err(_) => println!("Lock already held by another thread"),
     /// }
     /// ```
-    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding this
+    /// mutex, or [`TryLockError::WouldBlock`] if the lock is currently held.
+    pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
         let thread_id = get_current_thread_id();
         let tid_usize = thread_id;
 
@@ -276,7 +375,7 @@ impl<T> Mutex<T> {
             }
 
             #[cfg(feature = "lock-order-graph")]
-            detector::mutex::complete_acquire(thread_id, self.id);
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
 
             #[cfg(feature = "logging-and-visualization")]
             {
@@ -285,18 +384,292 @@ impl<T> Mutex<T> {
                 }
             }
 
-            Some(MutexGuard {
+            let guard = MutexGuard {
                 thread_id,
                 lock_id: self.id,
                 guard,
                 owner_atomic: &self.owner,
+                poisoned: &self.poisoned,
                 tracked_globally: cfg!(feature = "lock-order-graph"),
-            })
+            };
+
+            if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            }
         } else {
-            None
+            // Give the detector a transient look at this attempt: a cycle built
+            // entirely out of spinning try_lock calls should still be caught,
+            // even though this particular attempt never blocks.
+            let current_owner_val = self.owner.load(Ordering::Acquire);
+            let current_owner = if current_owner_val == 0 {
+                None
+            } else {
+                Some(current_owner_val as ThreadId)
+            };
+
+            let deadlock_info = detector::mutex::try_attempt(thread_id, self.id, current_owner);
+
+            if let Some(info) = deadlock_info {
+                let is_stale = if let Some(expected_owner) = current_owner {
+                    let actual_owner = self.owner.load(Ordering::Relaxed);
+                    !detector::deadlock_handling::verify_deadlock_edges(
+                        &info,
+                        thread_id,
+                        self.id,
+                        expected_owner,
+                        actual_owner,
+                    )
+                } else {
+                    false
+                };
+
+                if !is_stale {
+                    detector::deadlock_handling::process_deadlock(info);
+                }
+            }
+
+            Err(TryLockError::WouldBlock)
         }
     }
 
+    /// Acquire the lock, blocking for at most `timeout`
+    ///
+    /// Behaves like [`Mutex::lock`], registering the same persistent wait-for
+    /// edge while waiting, but gives up and returns
+    /// [`TryLockError::WouldBlock`] if `timeout` elapses first, retracting
+    /// the edge and logging an `AcquireTimedOut` event. If
+    /// [`crate::Deloxide::with_deadlock_recovery`] is configured and this
+    /// thread is chosen as the victim to break a detected cycle, gives up
+    /// early with [`TryLockError::Abandoned`] instead.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding
+    /// this mutex, [`TryLockError::WouldBlock`] if the timeout elapses first, or
+    /// [`TryLockError::Abandoned`] if this thread was sacrificed to break a
+    /// detected deadlock cycle.
+    pub fn lock_for(&self, timeout: Duration) -> TryLockResult<MutexGuard<'_, T>> {
+        self.lock_until(Instant::now() + timeout)
+    }
+
+    /// Acquire the lock, blocking until at most `deadline`
+    ///
+    /// See [`Mutex::lock_for`] for the timed-out and abandoned-victim behavior.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding
+    /// this mutex, [`TryLockError::WouldBlock`] if `deadline` passes first, or
+    /// [`TryLockError::Abandoned`] if this thread was sacrificed to break a
+    /// detected deadlock cycle.
+    pub fn lock_until(&self, deadline: Instant) -> TryLockResult<MutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id;
+
+        // Optimistic Fast Path (Disabled during stress testing to ensure full detector coverage)
+        #[cfg(not(feature = "stress-test"))]
+        if let Some(guard) = self.inner.try_lock() {
+            self.owner.store(tid_usize, Ordering::Release);
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAttempt);
+                }
+            }
+
+            #[cfg(feature = "lock-order-graph")]
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAcquired);
+                }
+            }
+
+            let guard = MutexGuard {
+                thread_id,
+                lock_id: self.id,
+                guard,
+                owner_atomic: &self.owner,
+                poisoned: &self.poisoned,
+                tracked_globally: cfg!(feature = "lock-order-graph"),
+            };
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            };
+        }
+
+        // Slow Path (Contention)
+        let current_owner = self.spin_for_owner_hint();
+
+        let deadlock_info =
+            detector::mutex::acquire_slow(thread_id, self.id, current_owner, stacktrace::capture());
+
+        if let Some(info) = deadlock_info {
+            let is_stale = if let Some(expected_owner) = current_owner {
+                let actual_owner = self.owner.load(Ordering::Relaxed);
+                !detector::deadlock_handling::verify_deadlock_edges(
+                    &info,
+                    thread_id,
+                    self.id,
+                    expected_owner,
+                    actual_owner,
+                )
+            } else {
+                false
+            };
+
+            if !is_stale {
+                detector::deadlock_handling::process_deadlock(info);
+            }
+        }
+
+        // Wait until the deadline, giving up if it passes first. When
+        // deadlock recovery is configured, poll in short slices instead of
+        // making one long blocking call, so a thread chosen as the victim to
+        // break a detected cycle (see `Detector::should_abandon`) notices and
+        // bails out instead of waiting out the rest of the deadline. With no
+        // recovery callback registered this is skipped entirely, preserving
+        // the exact original single-call behavior.
+        let guard = if detector::recovery_configured() {
+            const POLL_SLICE: Duration = Duration::from_millis(10);
+            loop {
+                let slice_deadline = deadline.min(Instant::now() + POLL_SLICE);
+                if let Some(guard) = self.inner.try_lock_until(slice_deadline) {
+                    break Some(guard);
+                }
+                if detector::should_abandon(thread_id) {
+                    detector::mutex::cancel_acquire(thread_id, self.id);
+                    return Err(TryLockError::Abandoned);
+                }
+                if Instant::now() >= deadline {
+                    break None;
+                }
+            }
+        } else {
+            self.inner.try_lock_until(deadline)
+        };
+
+        let Some(guard) = guard else {
+            detector::mutex::cancel_acquire(thread_id, self.id);
+            return Err(TryLockError::WouldBlock);
+        };
+
+        // Update state
+        detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+        self.owner.store(tid_usize, Ordering::Release);
+
+        let guard = MutexGuard {
+            thread_id,
+            lock_id: self.id,
+            guard,
+            owner_atomic: &self.owner,
+            poisoned: &self.poisoned,
+            tracked_globally: true,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(TryLockError::Poisoned(PoisonError::new(guard)))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Acquire the lock, blocking if necessary, returning an owned guard
+    ///
+    /// Like [`Mutex::lock`], but the returned [`OwnedMutexGuard`] holds an
+    /// `Arc` clone of this mutex instead of borrowing it, so it can be moved
+    /// into a spawned thread or stored in a struct without a lifetime tied to
+    /// `self`.
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    /// The guard is still returned inside the error so the caller can recover it.
+    pub fn lock_owned(self: &Arc<Self>) -> LockResult<OwnedMutexGuard<T>> {
+        match self.lock() {
+            Ok(guard) => Ok(self.into_owned_guard(guard)),
+            Err(err) => {
+                let poisoned = err.into_inner();
+                Err(PoisonError::new(self.into_owned_guard(poisoned)))
+            }
+        }
+    }
+
+    /// Try to acquire the lock without blocking, returning an owned guard
+    ///
+    /// Like [`Mutex::try_lock`], but the returned [`OwnedMutexGuard`] holds an
+    /// `Arc` clone of this mutex instead of borrowing it.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding this
+    /// mutex, or [`TryLockError::WouldBlock`] if the lock is currently held.
+    pub fn try_lock_owned(self: &Arc<Self>) -> TryLockResult<OwnedMutexGuard<T>> {
+        match self.try_lock() {
+            Ok(guard) => Ok(self.into_owned_guard(guard)),
+            Err(TryLockError::Poisoned(err)) => {
+                let poisoned = err.into_inner();
+                Err(TryLockError::Poisoned(PoisonError::new(self.into_owned_guard(poisoned))))
+            }
+            Err(TryLockError::WouldBlock) => Err(TryLockError::WouldBlock),
+            // `try_lock` never blocks, so deadlock recovery never gets a
+            // chance to pick this thread as a victim.
+            Err(TryLockError::Abandoned) => unreachable!("try_lock never waits"),
+        }
+    }
+
+    /// Convert a borrowed guard into an owned one, keeping `self` alive via an `Arc` clone
+    ///
+    /// Takes the guard by value and moves its inner parking_lot guard out
+    /// without running [`MutexGuard`]'s `Drop` (which would release the lock
+    /// and report it released to the detector before we could re-wrap it).
+    ///
+    /// # Safety
+    /// The borrowed guard's lifetime is tied to `&self`, and `self` is kept
+    /// alive for at least as long as the returned `OwnedMutexGuard` by the
+    /// `Arc` clone stored alongside it, so extending the guard's lifetime to
+    /// `'static` is sound as long as the two fields keep that relative drop
+    /// order (the guard is declared, and therefore dropped, before the `Arc`).
+    fn into_owned_guard(self: &Arc<Self>, guard: MutexGuard<'_, T>) -> OwnedMutexGuard<T> {
+        let mut guard = std::mem::ManuallyDrop::new(guard);
+        let tracked_globally = guard.tracked_globally;
+        // Safety: `guard.guard` is read exactly once here and `guard` itself
+        // is never used again (it's wrapped in `ManuallyDrop`), so this does
+        // not double-move or leave a dangling reference behind.
+        let inner = unsafe { std::ptr::read(&mut guard.guard) };
+        let inner: ParkingLotMutexGuard<'static, T> = unsafe { std::mem::transmute(inner) };
+
+        OwnedMutexGuard {
+            guard: inner,
+            mutex: Arc::clone(self),
+            thread_id: get_current_thread_id(),
+            tracked_globally,
+        }
+    }
+
+    /// Returns `true` if the mutex is poisoned.
+    ///
+    /// A mutex becomes poisoned when a thread panics while holding its guard.
+    /// Once poisoned, every future lock acquisition returns a [`PoisonError`]
+    /// until [`Mutex::clear_poison`] is called.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Clears the poisoned state of this mutex.
+    ///
+    /// If the mutex is poisoned, this will clear the poisoning so future
+    /// acquisitions succeed without error. This is useful when the data
+    /// protected by the mutex is known to still be in a valid state.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+        detector::clear_poisoned(self.id);
+    }
+
     /// Consumes this mutex, returning the underlying data
     ///
     /// # Example
@@ -305,13 +678,18 @@ impl<T> Mutex<T> {
     /// use deloxide::Mutex;
     ///
     /// let mutex = Mutex::new(42);
-    /// let value = mutex.into_inner();
+    /// let value = mutex.into_inner().unwrap();
     /// assert_eq!(value, 42);
     /// ```
-    pub fn into_inner(self) -> T
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn into_inner(self) -> LockResult<T>
     where
         T: Sized,
     {
+        let poisoned = self.poisoned.load(Ordering::Acquire);
+
         // We need to prevent Drop from running since we're manually extracting the value
         // First, manually drop the detector tracking
         detector::mutex::destroy_mutex(self.id);
@@ -320,7 +698,13 @@ impl<T> Mutex<T> {
         let mutex = std::mem::ManuallyDrop::new(self);
 
         // Safety: We're taking ownership and preventing double-drop
-        unsafe { std::ptr::read(&mutex.inner) }.into_inner()
+        let value = unsafe { std::ptr::read(&mutex.inner) }.into_inner();
+
+        if poisoned {
+            Err(PoisonError::new(value))
+        } else {
+            Ok(value)
+        }
     }
 
     /// Returns a mutable reference to the underlying data
@@ -334,11 +718,18 @@ impl<T> Mutex<T> {
     /// use deloxide::Mutex;
     ///
     /// let mut mutex = Mutex::new(0);
-    /// *mutex.get_mut() = 10;
-    /// assert_eq!(*mutex.lock(), 10);
+    /// *mutex.get_mut().unwrap() = 10;
+    /// assert_eq!(*mutex.lock().unwrap(), 10);
     /// ```
-    pub fn get_mut(&mut self) -> &mut T {
-        self.inner.get_mut()
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(self.inner.get_mut()))
+        } else {
+            Ok(self.inner.get_mut())
+        }
     }
 }
 
@@ -379,6 +770,15 @@ impl<'a, T> MutexGuard<'a, T> {
         self.lock_id
     }
 
+    /// Whether the mutex this guard protects is currently poisoned
+    ///
+    /// Used internally by Condvar to check for poisoning on reacquisition
+    /// after a wait, since a notifier may have panicked while holding the
+    /// mutex between this thread's wakeup and its own reacquire.
+    pub(crate) fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
     /// Clear local ownership tracking (used internally by Condvar)
     pub(crate) fn clear_ownership(&self) {
         self.owner_atomic.store(0, Ordering::Release);
@@ -388,10 +788,160 @@ impl<'a, T> MutexGuard<'a, T> {
     pub(crate) fn restore_ownership(&self) {
         self.owner_atomic.store(self.thread_id, Ordering::Release);
     }
+
+    /// Project this guard onto a sub-field of `T`, returning a [`MappedMutexGuard`]
+    ///
+    /// The lock stays held for as long as the returned guard lives, and is
+    /// released (with the same detector/logger reporting as `MutexGuard`)
+    /// when it is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::{Mutex, MutexGuard};
+    ///
+    /// let mutex = Mutex::new((1, 2));
+    /// let guard = mutex.lock().unwrap();
+    /// let mut mapped = MutexGuard::map(guard, |pair| &mut pair.0);
+    /// *mapped = 10;
+    /// ```
+    pub fn map<U: ?Sized, F>(orig: Self, f: F) -> MappedMutexGuard<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let (thread_id, lock_id, owner_atomic, poisoned, tracked_globally, inner) =
+            orig.into_parts();
+        MappedMutexGuard {
+            thread_id,
+            lock_id,
+            guard: ParkingLotMutexGuard::map(inner, f),
+            owner_atomic,
+            poisoned,
+            tracked_globally,
+        }
+    }
+
+    /// Attempt to project this guard onto a sub-field of `T`
+    ///
+    /// Returns the original, unchanged guard in `Err` if `f` returns `None`.
+    pub fn try_map<U: ?Sized, F>(orig: Self, f: F) -> Result<MappedMutexGuard<'a, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        let (thread_id, lock_id, owner_atomic, poisoned, tracked_globally, inner) =
+            orig.into_parts();
+        match ParkingLotMutexGuard::try_map(inner, f) {
+            Ok(guard) => Ok(MappedMutexGuard {
+                thread_id,
+                lock_id,
+                guard,
+                owner_atomic,
+                poisoned,
+                tracked_globally,
+            }),
+            Err(inner) => Err(MutexGuard {
+                thread_id,
+                lock_id,
+                guard: inner,
+                owner_atomic,
+                poisoned,
+                tracked_globally,
+            }),
+        }
+    }
+
+    /// Move this guard's fields out without running `Drop`
+    ///
+    /// Used by [`MutexGuard::map`]/[`MutexGuard::try_map`], which hand the
+    /// inner parking_lot guard off to a [`MappedMutexGuard`] (or a
+    /// reconstructed `MutexGuard` on a failed `try_map`) instead of releasing
+    /// the lock.
+    fn into_parts(
+        self,
+    ) -> (
+        ThreadId,
+        LockId,
+        &'a AtomicUsize,
+        &'a AtomicBool,
+        bool,
+        ParkingLotMutexGuard<'a, T>,
+    ) {
+        let mut this = std::mem::ManuallyDrop::new(self);
+        let inner = unsafe { std::ptr::read(&mut this.guard) };
+        (
+            this.thread_id,
+            this.lock_id,
+            this.owner_atomic,
+            this.poisoned,
+            this.tracked_globally,
+            inner,
+        )
+    }
+}
+
+impl<U: ?Sized> Deref for MappedMutexGuard<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<U: ?Sized> DerefMut for MappedMutexGuard<'_, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.deref_mut()
+    }
+}
+
+impl<U: ?Sized> Drop for MappedMutexGuard<'_, U> {
+    fn drop(&mut self) {
+        // Same release path as `MutexGuard::drop`.
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.lock_id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.lock_id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::MutexPoisoned);
+            }
+        }
+
+        self.owner_atomic.store(0, Ordering::Release);
+
+        if self.tracked_globally {
+            detector::mutex::release_mutex(self.thread_id, self.lock_id);
+        } else {
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::MutexReleased);
+            }
+        }
+    }
 }
 
 impl<T> Drop for MutexGuard<'_, T> {
     fn drop(&mut self) {
+        // 0. Poison the mutex if we're unwinding from a panic while holding the guard
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.lock_id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.lock_id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::MutexPoisoned);
+            }
+        }
+
         // 1. Clear local ownership first
         self.owner_atomic.store(0, Ordering::Release);
 
@@ -407,6 +957,52 @@ impl<T> Drop for MutexGuard<'_, T> {
     }
 }
 
+impl<T> Deref for OwnedMutexGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<T> DerefMut for OwnedMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.deref_mut()
+    }
+}
+
+impl<T> Drop for OwnedMutexGuard<T> {
+    fn drop(&mut self) {
+        // Same release path as `MutexGuard::drop`, just reading the owner
+        // atomic/poison flag/lock id off the owned `Arc` instead of borrowing them.
+        if std::thread::panicking() {
+            self.mutex.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.mutex.id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.mutex.id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.mutex.id, Events::MutexPoisoned);
+            }
+        }
+
+        self.mutex.owner.store(0, Ordering::Release);
+
+        if self.tracked_globally {
+            detector::mutex::release_mutex(self.thread_id, self.mutex.id);
+        } else {
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.mutex.id, Events::MutexReleased);
+            }
+        }
+    }
+}
+
 // Trait implementations for better compatibility with std
 
 impl<T: Default> Default for Mutex<T> {
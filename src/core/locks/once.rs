@@ -0,0 +1,271 @@
+//! One-time initialization primitives with deadlock detection
+//!
+//! `std::sync::Once`/`OnceLock`/`LazyLock` all deadlock if their initializer
+//! re-enters the same cell, whether directly (the initializing thread calls
+//! `get_or_init` on itself again) or indirectly (a lock cycle with another
+//! cell). These wrappers build on [`crate::Mutex`], which already reports
+//! both cases through the normal detector machinery: the Mutex's own
+//! self-deadlock check catches direct re-entrancy, and a cross-thread cycle
+//! folds into the existing wait-for graph the same way any other mutex cycle
+//! would, so it shows up with a normal `thread_cycle`/`thread_waiting_for_locks`
+//! [`crate::DeadlockInfo`].
+//!
+//! Unlike `std`, initialization state is tracked behind a real, trackable
+//! lock rather than a bespoke wait queue, so `get_or_init`/`force` participate
+//! in deadlock detection like any other blocking call in this crate. The
+//! tradeoff is that a panicking initializer only poisons (and can be retried,
+//! like [`crate::Mutex`]) rather than permanently "poisoning" the cell the
+//! way `std::sync::Once` does. Another consequence of wrapping
+//! [`crate::Mutex`], whose constructor registers the lock with the detector
+//! and so isn't a `const fn`, is that these types can't be used in a `static`
+//! the way their `std` counterparts can - wrap them in `Arc` instead, like
+//! every other tracked lock in this crate.
+
+use crate::core::locks::mutex::Mutex;
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A cell that can be written to only once, with deadlock detection.
+///
+/// A drop-in replacement for `std::sync::OnceLock` that reports a deadlock
+/// through the detector's callback (the call itself still blocks) if the
+/// thread currently running the initializer
+/// calls `get_or_init` on the same cell again, or if initializing this cell
+/// forms a lock cycle with another thread.
+///
+/// # Example
+///
+/// ```rust
+/// use deloxide::OnceLock;
+/// use std::sync::Arc;
+///
+/// let cell = Arc::new(OnceLock::new());
+/// let value = cell.get_or_init(|| "hello".to_string());
+/// assert_eq!(value, "hello");
+/// assert_eq!(cell.get(), Some(&"hello".to_string()));
+/// ```
+pub struct OnceLock<T> {
+    /// Set to `true` (with `Release` ordering) the moment `value` is fully
+    /// written, so [`OnceLock::get`] can check initialization without taking
+    /// `init_lock` at all once the cell is filled.
+    initialized: AtomicBool,
+    /// Guards the uninitialized -> initializing -> initialized transition.
+    /// Held for the entire duration of the user's initializer closure, so a
+    /// thread that blocks acquiring it is either waiting for initialization
+    /// to finish (folding into the normal wait-for graph) or, if it's the
+    /// same thread re-entering, is caught by `Mutex`'s own self-deadlock
+    /// detection.
+    init_lock: Mutex<()>,
+    /// The value, written at most once while holding `init_lock`.
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// Safety: `OnceLock<T>` only exposes `&T` once `value` is fully initialized
+// and never again mutated, and writes to `value` happen-before any read
+// through the `initialized` Release/Acquire pair - the same reasoning
+// `std::sync::OnceLock` relies on.
+unsafe impl<T: Send> Send for OnceLock<T> {}
+unsafe impl<T: Send + Sync> Sync for OnceLock<T> {}
+
+impl<T> Default for OnceLock<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceLock<T> {
+    fn drop(&mut self) {
+        if *self.initialized.get_mut() {
+            unsafe {
+                (*self.value.get()).assume_init_drop();
+            }
+        }
+    }
+}
+
+impl<T> OnceLock<T> {
+    /// Creates a new, uninitialized cell.
+    pub fn new() -> Self {
+        OnceLock {
+            initialized: AtomicBool::new(false),
+            init_lock: Mutex::new(()),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns a reference to the value if it has already been initialized.
+    ///
+    /// Never blocks and never participates in deadlock detection, since it
+    /// doesn't wait on anything.
+    pub fn get(&self) -> Option<&T> {
+        if self.initialized.load(Ordering::Acquire) {
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value, initializing it with `f` if this is the first call
+    /// to return successfully.
+    ///
+    /// If another thread is already running an initializer for this cell,
+    /// this blocks until it finishes, reporting the wait to the deadlock
+    /// detector through the internal [`crate::Mutex`] for its duration. If
+    /// the thread currently running the initializer calls this again on the
+    /// same cell (directly, or transitively through a lock cycle), the
+    /// detector reports an immediate self-cycle deadlock via the registered
+    /// callback, but the call still blocks: since this method can't itself
+    /// return before the initializer does, the caller only observes the
+    /// deadlock through the callback, not a returned error.
+    pub fn get_or_init<F>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let guard = match self.init_lock.lock() {
+            Ok(guard) => guard,
+            // A previous initializer panicked. Like `crate::Mutex`, the
+            // cell is recoverable rather than permanently poisoned: retry
+            // initialization rather than panicking on every future call.
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if !self.initialized.load(Ordering::Acquire) {
+            let value = f();
+            unsafe {
+                (*self.value.get()).write(value);
+            }
+            self.initialized.store(true, Ordering::Release);
+        }
+
+        drop(guard);
+        self.get().expect("value was just initialized")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnceLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceLock").field(value).finish(),
+            None => f.write_str("OnceLock(Uninit)"),
+        }
+    }
+}
+
+/// A synchronization primitive for running a one-time initializer, with
+/// deadlock detection.
+///
+/// A drop-in replacement for `std::sync::Once`, built on [`OnceLock`] the
+/// same way `std::sync::Once` is conceptually an `OnceLock<()>`.
+///
+/// # Example
+///
+/// ```rust
+/// use deloxide::Once;
+/// use std::sync::Arc;
+///
+/// let init = Arc::new(Once::new());
+/// init.call_once(|| {
+///     // one-time initialization work
+/// });
+/// assert!(init.is_completed());
+/// ```
+pub struct Once {
+    inner: OnceLock<()>,
+}
+
+impl Default for Once {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Once {
+    /// Creates a new `Once`.
+    pub fn new() -> Self {
+        Once {
+            inner: OnceLock::new(),
+        }
+    }
+
+    /// Runs `f` if this is the first call to `call_once` for this `Once`;
+    /// otherwise blocks until the in-progress or already-completed call
+    /// returns, with the same deadlock detection as
+    /// [`OnceLock::get_or_init`].
+    pub fn call_once<F: FnOnce()>(&self, f: F) {
+        let mut f = Some(f);
+        self.inner.get_or_init(|| {
+            (f.take().expect("called exactly once"))();
+        });
+    }
+
+    /// Returns `true` if `call_once` has completed successfully.
+    pub fn is_completed(&self) -> bool {
+        self.inner.get().is_some()
+    }
+}
+
+/// A value that is initialized on first access, with deadlock detection.
+///
+/// A drop-in replacement for `std::sync::LazyLock` built on [`OnceLock`];
+/// see [`OnceLock::get_or_init`] for how re-entrant initialization is
+/// detected.
+///
+/// # Example
+///
+/// ```rust
+/// use deloxide::LazyLock;
+/// use std::sync::Arc;
+///
+/// let greeting = Arc::new(LazyLock::new(|| "hello".to_string()));
+/// assert_eq!(&**greeting, "hello");
+/// ```
+pub struct LazyLock<T, F = fn() -> T> {
+    cell: OnceLock<T>,
+    init: Mutex<Option<F>>,
+}
+
+impl<T, F: FnOnce() -> T> LazyLock<T, F> {
+    /// Creates a new lazy value, deferring `f` until the first access.
+    pub fn new(f: F) -> Self {
+        LazyLock {
+            cell: OnceLock::new(),
+            init: Mutex::new(Some(f)),
+        }
+    }
+
+    /// Forces evaluation of `this` and returns a reference to the result.
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(|| {
+            let f = this
+                .init
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .take()
+                .expect("initializer only taken once, while init_lock is held");
+            f()
+        })
+    }
+}
+
+impl<T, F: FnOnce() -> T> std::ops::Deref for LazyLock<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        LazyLock::force(self)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for LazyLock<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell.get() {
+            Some(value) => f.debug_tuple("LazyLock").field(value).finish(),
+            None => f.write_str("LazyLock(Uninit)"),
+        }
+    }
+}
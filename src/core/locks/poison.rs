@@ -0,0 +1,103 @@
+//! Lock poisoning support
+//!
+//! Mirrors the poisoning semantics of `std::sync`: if a thread panics while
+//! holding a guard, the lock it came from is marked poisoned so that the next
+//! acquirer is warned that the protected data may be in an inconsistent state.
+//! As in `std`, the guard is still handed back on the `Err` path so a caller
+//! that knows the invariant wasn't actually broken can recover it.
+
+use std::error::Error;
+use std::fmt;
+
+/// The result of a lock acquisition that can detect poisoning.
+pub type LockResult<Guard> = Result<Guard, PoisonError<Guard>>;
+
+/// The result of a non-blocking lock acquisition that can detect poisoning.
+pub type TryLockResult<Guard> = Result<Guard, TryLockError<Guard>>;
+
+/// Error returned when a lock is acquired while poisoned.
+///
+/// The guard is always available via [`PoisonError::into_inner`], since the
+/// lock is held on return regardless of whether it was poisoned.
+pub struct PoisonError<Guard> {
+    guard: Guard,
+}
+
+impl<Guard> PoisonError<Guard> {
+    pub(crate) fn new(guard: Guard) -> Self {
+        PoisonError { guard }
+    }
+
+    /// Consumes this error, returning the underlying guard so the caller can
+    /// deliberately recover from the poisoning.
+    pub fn into_inner(self) -> Guard {
+        self.guard
+    }
+
+    /// Returns a reference to the underlying guard.
+    pub fn get_ref(&self) -> &Guard {
+        &self.guard
+    }
+
+    /// Returns a mutable reference to the underlying guard.
+    pub fn get_mut(&mut self) -> &mut Guard {
+        &mut self.guard
+    }
+}
+
+impl<Guard> fmt::Debug for PoisonError<Guard> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoisonError").field("..", &"..").finish()
+    }
+}
+
+impl<Guard> fmt::Display for PoisonError<Guard> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "poisoned lock: another task failed inside")
+    }
+}
+
+impl<Guard> Error for PoisonError<Guard> {}
+
+/// Error returned by the `try_*` lock methods.
+pub enum TryLockError<Guard> {
+    /// The lock is poisoned; the guard is still returned for recovery.
+    Poisoned(PoisonError<Guard>),
+    /// The lock could not be acquired without blocking.
+    WouldBlock,
+    /// This thread was chosen as the victim to break a detected deadlock
+    /// cycle (see [`crate::Deloxide::with_deadlock_recovery`]) and gave up
+    /// waiting instead of blocking forever.
+    Abandoned,
+}
+
+impl<Guard> fmt::Debug for TryLockError<Guard> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryLockError::Poisoned(p) => fmt::Debug::fmt(p, f),
+            TryLockError::WouldBlock => write!(f, "WouldBlock"),
+            TryLockError::Abandoned => write!(f, "Abandoned"),
+        }
+    }
+}
+
+impl<Guard> fmt::Display for TryLockError<Guard> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryLockError::Poisoned(p) => fmt::Display::fmt(p, f),
+            TryLockError::WouldBlock => write!(f, "try_lock failed because the operation would block"),
+            TryLockError::Abandoned => write!(
+                f,
+                "try_lock gave up because this thread was chosen to break a deadlock cycle"
+            ),
+        }
+    }
+}
+
+impl<Guard> Error for TryLockError<Guard> {}
+
+impl<Guard> From<PoisonError<Guard>> for TryLockError<Guard> {
+    fn from(err: PoisonError<Guard>) -> Self {
+        TryLockError::Poisoned(err)
+    }
+}
@@ -0,0 +1,399 @@
+//! A tracked mutex that hands the lock to waiters in priority order
+//!
+//! Ordinary lock handoff - including [`crate::FairMutex`]'s - has no notion of
+//! a waiting thread's [`Priority`]: whoever the OS or `parking_lot`'s internal
+//! queue wakes next gets the lock. That's exactly how a classic unbounded
+//! priority inversion (see [`crate::DeadlockSource::PriorityInversion`])
+//! becomes a real liveness problem instead of a brief hiccup: a low-priority
+//! owner that's itself preempted behind a medium-priority thread can sit on
+//! the lock indefinitely while a high-priority waiter starves behind it.
+//!
+//! `PriorityMutex` mitigates this by tracking its own wait queue, ordered by
+//! [`get_current_priority`], and handing the lock directly to the
+//! highest-priority waiter on unlock (FIFO among equal priorities) instead of
+//! to whichever thread happens to be woken first.
+
+use crate::core::detector;
+use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::locks::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
+use crate::core::stacktrace;
+use crate::core::types::{LockId, Priority, ThreadId, get_current_priority, get_current_thread_id};
+#[cfg(feature = "logging-and-visualization")]
+use crate::core::{Events, logger};
+use parking_lot::Mutex as ParkingLotMutex;
+use std::cell::UnsafeCell;
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::thread::Thread;
+
+/// A thread parked on a `PriorityMutex`, ordered by priority and (among equal
+/// priorities) by arrival order
+struct Waiter {
+    priority: Priority,
+    seq: u64,
+    thread: Thread,
+    granted: Arc<AtomicBool>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.seq == other.seq
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        // `BinaryHeap` is a max-heap: higher priority sorts first, and among
+        // equal priorities the earlier arrival (smaller `seq`) sorts first.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+/// Locking state shared between `lock()` and `unlock()`: the waiter queue is
+/// consulted and updated under the same lock as the "is it held" flag so a
+/// waiter can never be left registered after the lock has already been
+/// handed off to nobody (a classic lost-wakeup race in hand-rolled locks).
+struct State {
+    locked: bool,
+    waiters: BinaryHeap<Waiter>,
+}
+
+/// A wrapper around a mutex that tracks lock operations for deadlock
+/// detection and grants the lock to waiters in priority order on unlock
+///
+/// Behaves like [`crate::Mutex`] except for its unlock policy: the
+/// highest-priority waiter (see [`crate::thread::spawn_with_priority`]) is
+/// handed the lock directly, instead of releasing to open competition.
+pub struct PriorityMutex<T> {
+    /// Unique identifier for this mutex
+    id: LockId,
+    /// The wrapped value, exclusively accessible to whichever thread holds
+    /// the token tracked by `state.locked`
+    data: UnsafeCell<T>,
+    /// Thread that created this mutex
+    creator_thread_id: ThreadId,
+    /// Stores the ThreadId of the current owner (0 if unlocked)
+    owner: AtomicUsize,
+    /// Set when a guard was dropped during a panic, poisoning the data
+    poisoned: AtomicBool,
+    /// The lock token and priority-ordered waiter queue
+    state: ParkingLotMutex<State>,
+    /// Monotonic counter used to break priority ties in arrival order
+    next_seq: AtomicU64,
+}
+
+// Safety: `data` is only ever accessed by the thread currently holding the
+// lock token tracked by `state.locked`, exactly like `parking_lot::Mutex`.
+unsafe impl<T: Send> Send for PriorityMutex<T> {}
+unsafe impl<T: Send> Sync for PriorityMutex<T> {}
+
+/// Guard for a `PriorityMutex`, reports lock release and grants the lock to
+/// the next priority-ordered waiter when dropped
+pub struct PriorityMutexGuard<'a, T> {
+    mutex: &'a PriorityMutex<T>,
+    thread_id: ThreadId,
+}
+
+impl<T> PriorityMutex<T> {
+    /// Create a new `PriorityMutex` with an automatically assigned ID
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::PriorityMutex;
+    ///
+    /// let mutex = PriorityMutex::new(42);
+    /// ```
+    pub fn new(value: T) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+        let creator_thread_id = get_current_thread_id();
+
+        detector::mutex::create_mutex(id, Some(creator_thread_id));
+
+        PriorityMutex {
+            id,
+            data: UnsafeCell::new(value),
+            creator_thread_id,
+            owner: AtomicUsize::new(0),
+            poisoned: AtomicBool::new(false),
+            state: ParkingLotMutex::new(State {
+                locked: false,
+                waiters: BinaryHeap::new(),
+            }),
+            next_seq: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the ID of this mutex
+    pub fn id(&self) -> LockId {
+        self.id
+    }
+
+    /// Get the ID of the thread that created this mutex
+    pub fn creator_thread_id(&self) -> ThreadId {
+        self.creator_thread_id
+    }
+
+    /// Acquire the lock, blocking if necessary
+    ///
+    /// Unlike [`crate::Mutex::lock`], a blocked caller is never handed the
+    /// lock out of turn: if other threads are already waiting when it's
+    /// finally released, the highest-priority one among them goes first (see
+    /// [`get_current_priority`]).
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn lock(&self) -> LockResult<PriorityMutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+
+        // Fast path: grab the token directly if nobody holds it.
+        {
+            let mut state = self.state.lock();
+            if !state.locked {
+                state.locked = true;
+                drop(state);
+                self.owner.store(thread_id, Ordering::Release);
+                detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+                return self.finish_lock(thread_id);
+            }
+        }
+
+        // Slow path: report the wait-for edge (this is also where a
+        // priority-inversion hazard gets caught), then register as a
+        // priority-ordered waiter and park until granted the lock.
+        let current_owner_val = self.owner.load(Ordering::Acquire);
+        let current_owner = (current_owner_val != 0).then_some(current_owner_val);
+
+        let deadlock_info =
+            detector::mutex::acquire_slow(thread_id, self.id, current_owner, stacktrace::capture());
+        if let Some(info) = deadlock_info {
+            detector::deadlock_handling::process_deadlock(info);
+        }
+
+        let granted = Arc::new(AtomicBool::new(false));
+        let acquired_immediately = {
+            let mut state = self.state.lock();
+            if !state.locked {
+                // Freed between our fast-path check and now.
+                state.locked = true;
+                true
+            } else {
+                let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+                state.waiters.push(Waiter {
+                    priority: get_current_priority(),
+                    seq,
+                    thread: std::thread::current(),
+                    granted: Arc::clone(&granted),
+                });
+                false
+            }
+        };
+
+        if !acquired_immediately {
+            while !granted.load(Ordering::Acquire) {
+                std::thread::park();
+            }
+        }
+
+        self.owner.store(thread_id, Ordering::Release);
+        detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+        self.finish_lock(thread_id)
+    }
+
+    /// Try to acquire the lock without blocking
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding this
+    /// mutex, or [`TryLockError::WouldBlock`] if the lock is currently held.
+    pub fn try_lock(&self) -> TryLockResult<PriorityMutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+
+        let acquired = {
+            let mut state = self.state.lock();
+            if state.locked {
+                false
+            } else {
+                state.locked = true;
+                true
+            }
+        };
+
+        if !acquired {
+            return Err(TryLockError::WouldBlock);
+        }
+
+        self.owner.store(thread_id, Ordering::Release);
+
+        #[cfg(feature = "logging-and-visualization")]
+        {
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(thread_id, self.id, Events::MutexAttempt);
+            }
+        }
+
+        detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+
+        #[cfg(feature = "logging-and-visualization")]
+        {
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(thread_id, self.id, Events::MutexAcquired);
+            }
+        }
+
+        match self.finish_lock(thread_id) {
+            Ok(guard) => Ok(guard),
+            Err(poisoned) => Err(TryLockError::Poisoned(poisoned)),
+        }
+    }
+
+    fn finish_lock(&self, thread_id: ThreadId) -> LockResult<PriorityMutexGuard<'_, T>> {
+        let guard = PriorityMutexGuard {
+            mutex: self,
+            thread_id,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Release the lock token, handing it directly to the highest-priority
+    /// waiter if any are queued
+    fn unlock(&self) {
+        let mut state = self.state.lock();
+        if let Some(next) = state.waiters.pop() {
+            // Ownership transfers directly: `state.locked` stays `true` the
+            // entire time, so no other thread can barge in between.
+            next.granted.store(true, Ordering::Release);
+            next.thread.unpark();
+        } else {
+            state.locked = false;
+        }
+    }
+
+    /// Returns `true` if the mutex is poisoned.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Clears the poisoned state of this mutex.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+    }
+
+    /// Consumes this mutex, returning the underlying data
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn into_inner(self) -> LockResult<T>
+    where
+        T: Sized,
+    {
+        let poisoned = self.poisoned.load(Ordering::Acquire);
+
+        detector::mutex::destroy_mutex(self.id);
+
+        let value = self.data.into_inner();
+
+        if poisoned {
+            Err(PoisonError::new(value))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(self.data.get_mut()))
+        } else {
+            Ok(self.data.get_mut())
+        }
+    }
+}
+
+impl<T> Drop for PriorityMutex<T> {
+    fn drop(&mut self) {
+        detector::mutex::destroy_mutex(self.id);
+    }
+}
+
+impl<T> Deref for PriorityMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: we hold the lock token, so we have exclusive access.
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T> DerefMut for PriorityMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: we hold the lock token, so we have exclusive access.
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T> Drop for PriorityMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // 0. Poison the mutex if we're unwinding from a panic while holding the guard
+        if std::thread::panicking() {
+            self.mutex.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.mutex.id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.mutex.id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.mutex.id, Events::MutexPoisoned);
+            }
+        }
+
+        // 1. Clear local ownership first
+        self.mutex.owner.store(0, Ordering::Release);
+
+        // 2. Report lock release to the detector
+        detector::mutex::release_mutex(self.thread_id, self.mutex.id);
+
+        // 3. Hand the token to the next priority-ordered waiter, if any.
+        self.mutex.unlock();
+    }
+}
+
+// Trait implementations for better compatibility with std
+
+impl<T: Default> Default for PriorityMutex<T> {
+    /// Creates a `PriorityMutex<T>`, with the Default value for T
+    fn default() -> PriorityMutex<T> {
+        PriorityMutex::new(Default::default())
+    }
+}
+
+impl<T> From<T> for PriorityMutex<T> {
+    /// Creates a new mutex in an unlocked state ready for use
+    /// This is equivalent to PriorityMutex::new
+    fn from(t: T) -> Self {
+        PriorityMutex::new(t)
+    }
+}
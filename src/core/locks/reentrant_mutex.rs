@@ -0,0 +1,479 @@
+//! A tracked reentrant (recursive) mutex for deadlock detection
+//!
+//! Unlike [`crate::Mutex`], this lock allows the thread that already owns it to
+//! re-acquire it without blocking or being reported as waiting on itself. Because
+//! nested guards can coexist, the protected data is only exposed as `&T`.
+//!
+//! This is the tracked counterpart to parking_lot's `ReentrantMutex`
+//! (`remutex.rs`): only the outermost `lock()` and the `Drop` of the last
+//! guard still held (the one that brings the recursion count back to zero)
+//! are reported to the detector (see [`ReentrantMutex::lock`] and
+//! [`ReentrantMutexGuard`]'s `Drop` impl), so a thread recursing into a lock
+//! it already holds is never mistaken for a self-wait the way re-locking a
+//! plain [`crate::Mutex`] would be. [`crate::RwLock::read`] handles the
+//! analogous but genuinely-unsafe case for reader-writer locks: parking_lot's
+//! task-fair `RwLock` warns that a thread recursively taking a read lock can
+//! actually deadlock behind a writer queued in between the two reads, so
+//! unlike this type it reports that recursion as a real
+//! [`crate::core::types::DeadlockSource::SelfDeadlock`] rather than silently
+//! allowing it - see the recursive-read check in
+//! [`crate::core::detector::Detector::attempt_read`].
+
+use crate::core::detector;
+use crate::core::locks::NEXT_LOCK_ID;
+use crate::core::locks::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
+use crate::core::stacktrace;
+use crate::core::types::{LockId, ThreadId, get_current_thread_id};
+#[cfg(feature = "logging-and-visualization")]
+use crate::core::{Events, logger};
+use parking_lot::{
+    ReentrantMutex as ParkingLotReentrantMutex, ReentrantMutexGuard as ParkingLotReentrantMutexGuard,
+};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// A wrapper around a reentrant mutex that tracks lock operations for deadlock detection
+///
+/// The owning thread may call [`ReentrantMutex::lock`] any number of times without
+/// blocking; each acquisition must be matched by dropping the returned guard. Only
+/// the first acquisition and the last release are reported to the deadlock detector.
+pub struct ReentrantMutex<T> {
+    /// Unique identifier for this mutex
+    id: LockId,
+    /// The wrapped reentrant mutex
+    inner: ParkingLotReentrantMutex<T>,
+    /// Thread that created this mutex
+    creator_thread_id: ThreadId,
+    /// Stores the ThreadId of the current owner (0 if unlocked)
+    owner: AtomicUsize,
+    /// Number of guards currently held by the owning thread
+    ///
+    /// Only the acquisition that takes this from 0 to 1 goes through
+    /// [`detector::mutex::complete_acquire`]/[`detector::mutex::acquire_slow`]
+    /// (see [`ReentrantMutex::lock`]'s reentrant fast path), and only
+    /// the [`ReentrantMutexGuard`] drop that brings it back to 0 calls
+    /// [`detector::mutex::release_mutex`] - nested lock/drop pairs in between
+    /// are invisible to the detector, which is what keeps a thread's own
+    /// recursive re-acquisition from ever showing up as a wait-for edge onto
+    /// itself.
+    count: AtomicUsize,
+    /// Whether the current ownership was registered with the global detector
+    tracked_globally: AtomicBool,
+    /// Set when a guard was dropped during a panic, poisoning the data
+    poisoned: AtomicBool,
+}
+
+/// Guard for a ReentrantMutex, reports lock release when dropped
+///
+/// Derefs to `&T` only, since multiple guards held by the same thread may be
+/// live at once.
+pub struct ReentrantMutexGuard<'a, T> {
+    /// Thread that owns this guard
+    thread_id: ThreadId,
+    /// Lock that this guard is for
+    lock_id: LockId,
+    /// The inner parking_lot guard
+    guard: ParkingLotReentrantMutexGuard<'a, T>,
+    /// Reference to the owner atomic to clear it on drop
+    owner_atomic: &'a AtomicUsize,
+    /// Reference to the recursion count to decrement on drop
+    count: &'a AtomicUsize,
+    /// Reference to the flag recording whether this acquisition was tracked globally
+    tracked_globally: &'a AtomicBool,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
+}
+
+impl<T> ReentrantMutex<T> {
+    /// Create a new ReentrantMutex with an automatically assigned ID
+    ///
+    /// # Arguments
+    /// * `value` - The initial value to store in the mutex
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::ReentrantMutex;
+    ///
+    /// let mutex = ReentrantMutex::new(42);
+    /// ```
+    pub fn new(value: T) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+        let creator_thread_id = get_current_thread_id();
+
+        detector::mutex::create_mutex(id, Some(creator_thread_id));
+
+        ReentrantMutex {
+            id,
+            inner: ParkingLotReentrantMutex::new(value),
+            creator_thread_id,
+            owner: AtomicUsize::new(0),
+            count: AtomicUsize::new(0),
+            tracked_globally: AtomicBool::new(false),
+            poisoned: AtomicBool::new(false),
+        }
+    }
+
+    /// Get the ID of this mutex
+    pub fn id(&self) -> LockId {
+        self.id
+    }
+
+    /// Get the ID of the thread that created this mutex
+    pub fn creator_thread_id(&self) -> ThreadId {
+        self.creator_thread_id
+    }
+
+    /// Get the ID of the thread currently owning this mutex, or `None` if it's unlocked
+    ///
+    /// This is the thread id the reentrant fast path in [`ReentrantMutex::lock`]
+    /// compares against to decide whether a call is a genuine re-entry.
+    pub fn owner_thread_id(&self) -> Option<ThreadId> {
+        match self.owner.load(Ordering::Acquire) {
+            0 => None,
+            owner => Some(owner),
+        }
+    }
+
+    /// Get how many guards the owning thread currently holds on this mutex
+    ///
+    /// `0` if the mutex is unlocked, `1` after a first acquisition, and one
+    /// higher for each nested re-entrant [`ReentrantMutex::lock`] call the
+    /// owning thread hasn't yet matched with a guard drop.
+    pub fn recursion_count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Acquire the lock, blocking if necessary
+    ///
+    /// If the calling thread already owns this mutex, this returns immediately
+    /// with a new guard and bumps the recursion count, without registering a new
+    /// wait-for edge or emitting a duplicate [`Events::MutexAcquired`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use deloxide::ReentrantMutex;
+    ///
+    /// let mutex = ReentrantMutex::new(42);
+    /// let guard1 = mutex.lock().unwrap();
+    /// let guard2 = mutex.lock().unwrap(); // Re-entrant: does not block
+    /// assert_eq!(*guard1, 42);
+    /// assert_eq!(*guard2, 42);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if another thread panicked while holding this mutex.
+    pub fn lock(&self) -> LockResult<ReentrantMutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id;
+
+        // Reentrant fast path: the owning thread can always re-acquire without
+        // blocking or being reported as waiting on itself.
+        if self.owner.load(Ordering::Acquire) == tid_usize {
+            let guard = self.inner.lock();
+            self.count.fetch_add(1, Ordering::Relaxed);
+
+            let guard = self.wrap_guard(thread_id, guard);
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(PoisonError::new(guard))
+            } else {
+                Ok(guard)
+            };
+        }
+
+        // Optimistic Fast Path (Disabled during stress testing to ensure full detector coverage)
+        #[cfg(not(feature = "stress-test"))]
+        if let Some(guard) = self.inner.try_lock() {
+            self.begin_ownership(thread_id, cfg!(feature = "lock-order-graph"));
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAttempt);
+                }
+            }
+
+            #[cfg(feature = "lock-order-graph")]
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAcquired);
+                }
+            }
+
+            let guard = self.wrap_guard(thread_id, guard);
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(PoisonError::new(guard))
+            } else {
+                Ok(guard)
+            };
+        }
+
+        // Slow Path (Contention)
+        let mut current_owner_val = self.owner.load(Ordering::Acquire);
+
+        if current_owner_val == 0 && self.inner.is_locked() {
+            let mut spin_count = 0;
+            while current_owner_val == 0 {
+                if spin_count < 100 {
+                    std::hint::spin_loop();
+                } else {
+                    std::thread::yield_now();
+                }
+
+                current_owner_val = self.owner.load(Ordering::Relaxed);
+                spin_count += 1;
+
+                if spin_count % 16 == 0 && !self.inner.is_locked() {
+                    break;
+                }
+            }
+            std::sync::atomic::fence(Ordering::Acquire);
+        }
+
+        let current_owner = if current_owner_val == 0 {
+            None
+        } else {
+            Some(current_owner_val as ThreadId)
+        };
+
+        let deadlock_info =
+            detector::mutex::acquire_slow(thread_id, self.id, current_owner, stacktrace::capture());
+
+        if let Some(info) = deadlock_info {
+            let is_stale = if let Some(expected_owner) = current_owner {
+                let actual_owner = self.owner.load(Ordering::Relaxed);
+                !detector::deadlock_handling::verify_deadlock_edges(
+                    &info,
+                    thread_id,
+                    self.id,
+                    expected_owner,
+                    actual_owner,
+                )
+            } else {
+                false
+            };
+
+            if !is_stale {
+                detector::deadlock_handling::process_deadlock(info);
+            }
+        }
+
+        let guard = self.inner.lock();
+
+        detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+        self.begin_ownership(thread_id, true);
+
+        let guard = self.wrap_guard(thread_id, guard);
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Try to acquire the lock without blocking
+    ///
+    /// If the calling thread already owns this mutex, this always succeeds.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if another thread panicked while holding this
+    /// mutex, or [`TryLockError::WouldBlock`] if it is held by another thread.
+    pub fn try_lock(&self) -> TryLockResult<ReentrantMutexGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id;
+
+        if self.owner.load(Ordering::Acquire) == tid_usize {
+            // The owning thread can always re-acquire without blocking.
+            let guard = self.inner.lock();
+            self.count.fetch_add(1, Ordering::Relaxed);
+
+            let guard = self.wrap_guard(thread_id, guard);
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            };
+        }
+
+        if let Some(guard) = self.inner.try_lock() {
+            self.begin_ownership(thread_id, cfg!(feature = "lock-order-graph"));
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAttempt);
+                }
+            }
+
+            #[cfg(feature = "lock-order-graph")]
+            detector::mutex::complete_acquire(thread_id, self.id, stacktrace::capture());
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::MutexAcquired);
+                }
+            }
+
+            let guard = self.wrap_guard(thread_id, guard);
+
+            if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            }
+        } else {
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    /// Record that `thread_id` now owns this mutex for the first time (count = 1)
+    fn begin_ownership(&self, thread_id: ThreadId, tracked_globally: bool) {
+        self.owner.store(thread_id, Ordering::Release);
+        self.count.store(1, Ordering::Relaxed);
+        self.tracked_globally
+            .store(tracked_globally, Ordering::Relaxed);
+    }
+
+    /// Wrap an inner guard with the bookkeeping references shared by all guard sites
+    fn wrap_guard<'a>(
+        &'a self,
+        thread_id: ThreadId,
+        guard: ParkingLotReentrantMutexGuard<'a, T>,
+    ) -> ReentrantMutexGuard<'a, T> {
+        ReentrantMutexGuard {
+            thread_id,
+            lock_id: self.id,
+            guard,
+            owner_atomic: &self.owner,
+            count: &self.count,
+            tracked_globally: &self.tracked_globally,
+            poisoned: &self.poisoned,
+        }
+    }
+
+    /// Returns `true` if the mutex is poisoned.
+    ///
+    /// A mutex becomes poisoned when a thread panics while holding one of its guards.
+    /// Once poisoned, every future lock acquisition returns a [`PoisonError`]
+    /// until [`ReentrantMutex::clear_poison`] is called.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Clears the poisoned state of this mutex.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+        detector::clear_poisoned(self.id);
+    }
+
+    /// Consumes this mutex, returning the underlying data
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this mutex.
+    pub fn into_inner(self) -> LockResult<T>
+    where
+        T: Sized,
+    {
+        let poisoned = self.poisoned.load(Ordering::Acquire);
+
+        detector::mutex::destroy_mutex(self.id);
+
+        let mutex = std::mem::ManuallyDrop::new(self);
+        let value = unsafe { std::ptr::read(&mutex.inner) }.into_inner();
+
+        if poisoned {
+            Err(PoisonError::new(value))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data
+    ///
+    /// Since this call borrows the mutex mutably, no actual locking needs to
+    /// take place – the mutable borrow statically guarantees no guards exist.
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this mutex.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(self.inner.get_mut()))
+        } else {
+            Ok(self.inner.get_mut())
+        }
+    }
+}
+
+impl<T> Drop for ReentrantMutex<T> {
+    fn drop(&mut self) {
+        detector::mutex::destroy_mutex(self.id);
+    }
+}
+
+impl<T> Deref for ReentrantMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<T> Drop for ReentrantMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // 0. Poison the mutex if we're unwinding from a panic while holding the guard
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.lock_id);
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::MutexPoisoned);
+            }
+        }
+
+        // Only the guard that brings the recursion count back to zero reports
+        // the actual release; nested guards are a no-op here.
+        if self.count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.owner_atomic.store(0, Ordering::Release);
+
+            if self.tracked_globally.load(Ordering::Relaxed) {
+                detector::mutex::release_mutex(self.thread_id, self.lock_id);
+            } else {
+                #[cfg(feature = "logging-and-visualization")]
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(
+                        self.thread_id,
+                        self.lock_id,
+                        Events::MutexReleased,
+                    );
+                }
+            }
+        }
+    }
+}
+
+// Trait implementations for better compatibility with std
+
+impl<T: Default> Default for ReentrantMutex<T> {
+    /// Creates a `ReentrantMutex<T>`, with the Default value for T
+    fn default() -> ReentrantMutex<T> {
+        ReentrantMutex::new(Default::default())
+    }
+}
+
+impl<T> From<T> for ReentrantMutex<T> {
+    /// Creates a new mutex in an unlocked state ready for use
+    /// This is equivalent to ReentrantMutex::new
+    fn from(t: T) -> Self {
+        ReentrantMutex::new(t)
+    }
+}
@@ -14,33 +14,49 @@
 //! let lock_clone = Arc::clone(&lock);
 //!
 //! thread::spawn(move || {
-//!     let data = lock_clone.read();
+//!     let data = lock_clone.read().unwrap();
 //!     println!("Read: {}", *data);
 //! });
 //!
-//! let mut data = lock.write();
+//! let mut data = lock.write().unwrap();
 //! *data += 1;
 //! ```
 
 use crate::core::detector;
 use crate::core::locks::NEXT_LOCK_ID;
-
+use crate::core::locks::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
 
 use crate::core::types::{LockId, ThreadId, get_current_thread_id};
 #[cfg(feature = "logging-and-visualization")]
 use crate::core::{Events, logger};
 use parking_lot::{
     RwLock as ParkingLotRwLock, RwLockReadGuard as ParkingLotReadGuard,
+    RwLockUpgradableReadGuard as ParkingLotUpgradableReadGuard,
     RwLockWriteGuard as ParkingLotWriteGuard,
 };
 use std::ops::{Deref, DerefMut};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 /// A wrapper around a reader-writer lock that tracks operations for deadlock detection
 ///
 /// The RwLock provides the same API as a standard reader-writer lock
 /// but also notifies the detector on lock/unlock operations.
 ///
+/// `read()`/`try_read()` and `write()`/`try_write()` don't just call into a
+/// single generic "attempt this lock" path on the detector - reads call
+/// [`Detector::attempt_read`](crate::core::Detector::attempt_read), while
+/// `write()` calls
+/// [`Detector::acquire_write_slow`](crate::core::Detector::acquire_write_slow)
+/// and a failed `try_write()` calls
+/// [`Detector::try_write_attempt`](crate::core::Detector::try_write_attempt),
+/// which model shared vs. exclusive ownership distinctly when
+/// building wait-for edges: a writer attempt conflicts with every current
+/// reader *and* the current writer (if any), while a reader attempt only
+/// conflicts with a writer, never with other concurrent readers. This is
+/// what lets `RwLock`-heavy code get the same cycle detection that
+/// `Mutex`/`TrackedMutex` give for exclusive-only locks, without reporting
+/// phantom deadlocks between two threads that are both just reading.
 pub struct RwLock<T> {
     /// Unique identifier for this lock
     id: LockId,
@@ -50,6 +66,10 @@ pub struct RwLock<T> {
     creator_thread_id: ThreadId,
     /// Tracks the thread ID of a WRITER using AtomicUsize. 0 if no writer.
     writer_owner: AtomicUsize,
+    /// Tracks the thread ID of the UPGRADABLE reader using AtomicUsize. 0 if none.
+    upgradable_owner: AtomicUsize,
+    /// Set when a guard was dropped during a panic, poisoning the data
+    poisoned: AtomicBool,
 }
 
 /// Guard for a shared (read) lock, reports release when dropped
@@ -57,6 +77,8 @@ pub struct RwLockReadGuard<'a, T> {
     thread_id: ThreadId,
     lock_id: LockId,
     guard: ParkingLotReadGuard<'a, T>,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
 }
 
 /// Guard for an exclusive (write) lock, reports release when dropped
@@ -66,10 +88,32 @@ pub struct RwLockWriteGuard<'a, T> {
     guard: ParkingLotWriteGuard<'a, T>,
     /// Reference to the owner atomic to clear it on drop
     owner_atomic: &'a AtomicUsize,
+    /// Reference to the upgradable owner atomic, handed off to the
+    /// `RwLockUpgradableReadGuard` on [`RwLockWriteGuard::downgrade`]
+    upgradable_owner_atomic: &'a AtomicUsize,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
     /// Whether this lock acquisition was tracked by the global detector
     tracked_globally: bool,
 }
 
+/// Guard for an upgradable read lock, reports release when dropped
+///
+/// At most one upgradable read guard may be held on a given lock at a time, but it
+/// coexists with ordinary [`RwLockReadGuard`]s. Call [`RwLockUpgradableReadGuard::upgrade`]
+/// to atomically convert it into a [`RwLockWriteGuard`] once all current readers drain.
+pub struct RwLockUpgradableReadGuard<'a, T> {
+    thread_id: ThreadId,
+    lock_id: LockId,
+    guard: ParkingLotUpgradableReadGuard<'a, T>,
+    /// Reference to the owner atomic to clear it on drop
+    owner_atomic: &'a AtomicUsize,
+    /// Reference to the writer owner atomic, handed off to the `RwLockWriteGuard` on upgrade
+    writer_owner_atomic: &'a AtomicUsize,
+    /// Reference to the poison flag, set on drop if the current thread is panicking
+    poisoned: &'a AtomicBool,
+}
+
 impl<T> RwLock<T> {
     /// Create a new tracked RwLock with a unique ID
     ///
@@ -94,6 +138,8 @@ impl<T> RwLock<T> {
             inner: ParkingLotRwLock::new(value),
             creator_thread_id,
             writer_owner: AtomicUsize::new(0),
+            upgradable_owner: AtomicUsize::new(0),
+            poisoned: AtomicBool::new(false),
         }
     }
 
@@ -114,11 +160,14 @@ impl<T> RwLock<T> {
     ///
     /// # Returns
     /// A guard which releases the lock when dropped
-    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this lock.
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
         let thread_id = get_current_thread_id();
 
         // Phase 1: Atomic detection and try-acquire
-        let guard = crate::core::detector::rwlock::attempt_read(thread_id, self.id, || {
+        let guard = crate::core::detector::rwlock::attempt_read(thread_id, self.id, false, || {
             self.inner.try_read()
         });
 
@@ -132,10 +181,102 @@ impl<T> RwLock<T> {
             }
         };
 
-        RwLockReadGuard {
+        let guard = RwLockReadGuard {
+            thread_id,
+            lock_id: self.id,
+            guard,
+            poisoned: &self.poisoned,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Acquire an upgradable read lock, tracking the attempt and acquisition
+    ///
+    /// At most one upgradable read lock may be held at a time, but it coexists with
+    /// ordinary readers. Call [`RwLockUpgradableReadGuard::upgrade`] on the returned
+    /// guard to atomically convert it into a write lock once current readers drain.
+    ///
+    /// # Returns
+    /// A guard which releases the upgradable read lock when dropped, unless upgraded
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this lock.
+    pub fn upgradable_read(&self) -> LockResult<RwLockUpgradableReadGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id;
+
+        // Phase 1: Atomic detection and try-acquire
+        let guard = detector::rwlock::attempt_upgradable_read(thread_id, self.id, || {
+            self.inner.try_upgradable_read()
+        });
+
+        // Phase 2: If try-acquire failed, use blocking upgradable read
+        let guard = match guard {
+            Some(g) => g,
+            None => {
+                let g = self.inner.upgradable_read();
+                detector::rwlock::complete_upgradable_read(thread_id, self.id);
+                g
+            }
+        };
+
+        self.upgradable_owner.store(tid_usize, Ordering::Release);
+
+        let guard = RwLockUpgradableReadGuard {
             thread_id,
             lock_id: self.id,
             guard,
+            owner_atomic: &self.upgradable_owner,
+            writer_owner_atomic: &self.writer_owner,
+            poisoned: &self.poisoned,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Try to acquire an upgradable read lock without blocking
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// or [`TryLockError::WouldBlock`] if the lock is currently held by a writer or
+    /// another upgradable reader.
+    pub fn try_upgradable_read(&self) -> TryLockResult<RwLockUpgradableReadGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+
+        let guard =
+            detector::rwlock::attempt_upgradable_read(thread_id, self.id, || {
+                self.inner.try_upgradable_read()
+            });
+
+        match guard {
+            Some(g) => {
+                self.upgradable_owner.store(thread_id, Ordering::Release);
+
+                let guard = RwLockUpgradableReadGuard {
+                    thread_id,
+                    lock_id: self.id,
+                    guard: g,
+                    owner_atomic: &self.upgradable_owner,
+                    writer_owner_atomic: &self.writer_owner,
+                    poisoned: &self.poisoned,
+                };
+
+                if self.poisoned.load(Ordering::Acquire) {
+                    Err(TryLockError::Poisoned(PoisonError::new(guard)))
+                } else {
+                    Ok(guard)
+                }
+            }
+            None => Err(TryLockError::WouldBlock),
         }
     }
 
@@ -146,7 +287,10 @@ impl<T> RwLock<T> {
     ///
     /// # Returns
     /// A guard which releases the lock when dropped
-    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this lock.
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
         let thread_id = get_current_thread_id();
         let tid_usize = thread_id as usize;
 
@@ -172,13 +316,21 @@ impl<T> RwLock<T> {
                 }
             }
 
-            return RwLockWriteGuard {
+            let guard = RwLockWriteGuard {
                 thread_id,
                 lock_id: self.id,
                 guard,
                 owner_atomic: &self.writer_owner,
+                upgradable_owner_atomic: &self.upgradable_owner,
+                poisoned: &self.poisoned,
                 tracked_globally: cfg!(feature = "lock-order-graph"),
             };
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(PoisonError::new(guard))
+            } else {
+                Ok(guard)
+            };
         }
 
         // Slow Path
@@ -218,12 +370,20 @@ impl<T> RwLock<T> {
         detector::rwlock::complete_write(thread_id, self.id);
         self.writer_owner.store(tid_usize, Ordering::Release);
 
-        RwLockWriteGuard {
+        let guard = RwLockWriteGuard {
             thread_id,
             lock_id: self.id,
             guard,
             owner_atomic: &self.writer_owner,
+            upgradable_owner_atomic: &self.upgradable_owner,
+            poisoned: &self.poisoned,
             tracked_globally: true,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
         }
     }
 
@@ -231,24 +391,138 @@ impl<T> RwLock<T> {
     ///
     /// Uses atomic detection to ensure deadlock detection and acquisition
     /// happen together.
-    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// or [`TryLockError::WouldBlock`] if the lock is currently held exclusively.
+    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+
+        // Use atomic detection and try-acquire. This attempt never blocks, so
+        // any wait-for edge set up to check for a cycle is transient.
+        let guard =
+            detector::rwlock::attempt_read(thread_id, self.id, true, || self.inner.try_read());
+
+        match guard {
+            Some(g) => {
+                let guard = RwLockReadGuard {
+                    thread_id,
+                    lock_id: self.id,
+                    guard: g,
+                    poisoned: &self.poisoned,
+                };
+
+                if self.poisoned.load(Ordering::Acquire) {
+                    Err(TryLockError::Poisoned(PoisonError::new(guard)))
+                } else {
+                    Ok(guard)
+                }
+            }
+            None => Err(TryLockError::WouldBlock),
+        }
+    }
+
+    /// Acquire a shared (read) lock, blocking for at most `timeout`
+    ///
+    /// Behaves like [`RwLock::read`] but gives up and returns
+    /// [`TryLockError::WouldBlock`] if `timeout` elapses before the lock
+    /// becomes available.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// [`TryLockError::WouldBlock`] if the timeout elapses first, or
+    /// [`TryLockError::Abandoned`] if this thread was sacrificed to break a
+    /// detected deadlock cycle.
+    pub fn read_for(&self, timeout: Duration) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        self.read_until(Instant::now() + timeout)
+    }
+
+    /// Acquire a shared (read) lock, blocking until at most `deadline`
+    ///
+    /// See [`RwLock::read_for`] for the timed-out behavior. When
+    /// [`crate::Deloxide::with_deadlock_recovery`] is configured and this
+    /// thread is chosen as the victim to break a detected cycle, returns
+    /// early with [`TryLockError::Abandoned`] instead.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// [`TryLockError::WouldBlock`] if `deadline` passes first, or
+    /// [`TryLockError::Abandoned`] if this thread was sacrificed to break a
+    /// detected deadlock cycle.
+    pub fn read_until(&self, deadline: Instant) -> TryLockResult<RwLockReadGuard<'_, T>> {
         let thread_id = get_current_thread_id();
 
-        // Use atomic detection and try-acquire
-        let guard = detector::rwlock::attempt_read(thread_id, self.id, || self.inner.try_read());
+        // Phase 1: Atomic detection and try-acquire. A persistent edge is
+        // used here (like the blocking read() path) since we may still end
+        // up waiting; it is retracted by cancel_acquire if the deadline passes.
+        let guard = detector::rwlock::attempt_read(thread_id, self.id, false, || {
+            self.inner.try_read()
+        });
+
+        let guard = match guard {
+            Some(g) => g,
+            None => {
+                // Poll in short slices instead of one long blocking call when
+                // deadlock recovery is configured, so a thread chosen as the
+                // victim to break a detected cycle (see
+                // `Detector::should_abandon`) notices and bails out instead
+                // of waiting out the rest of the deadline; see
+                // `Mutex::lock_until` for the same pattern.
+                let inner_guard = if detector::recovery_configured() {
+                    const POLL_SLICE: Duration = Duration::from_millis(10);
+                    loop {
+                        let slice_deadline = deadline.min(Instant::now() + POLL_SLICE);
+                        if let Some(g) = self.inner.try_read_until(slice_deadline) {
+                            break Some(g);
+                        }
+                        if detector::should_abandon(thread_id) {
+                            detector::rwlock::cancel_acquire(thread_id, self.id);
+                            return Err(TryLockError::Abandoned);
+                        }
+                        if Instant::now() >= deadline {
+                            break None;
+                        }
+                    }
+                } else {
+                    self.inner.try_read_until(deadline)
+                };
 
-        guard.map(|g| RwLockReadGuard {
+                match inner_guard {
+                    Some(g) => {
+                        detector::rwlock::complete_read(thread_id, self.id);
+                        g
+                    }
+                    None => {
+                        detector::rwlock::cancel_acquire(thread_id, self.id);
+                        return Err(TryLockError::WouldBlock);
+                    }
+                }
+            }
+        };
+
+        let guard = RwLockReadGuard {
             thread_id,
             lock_id: self.id,
-            guard: g,
-        })
+            guard,
+            poisoned: &self.poisoned,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(TryLockError::Poisoned(PoisonError::new(guard)))
+        } else {
+            Ok(guard)
+        }
     }
 
     /// Try to acquire an exclusive (write) lock, tracking the attempt
     ///
     /// Uses atomic detection to ensure deadlock detection and acquisition
     /// happen together.
-    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// or [`TryLockError::WouldBlock`] if the lock is currently held.
+    pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         let thread_id = get_current_thread_id();
 
         if let Some(guard) = self.inner.try_write() {
@@ -272,18 +546,225 @@ impl<T> RwLock<T> {
                 }
             }
 
-            Some(RwLockWriteGuard {
+            let guard = RwLockWriteGuard {
                 thread_id,
                 lock_id: self.id,
                 guard,
                 owner_atomic: &self.writer_owner,
+                upgradable_owner_atomic: &self.upgradable_owner,
+                poisoned: &self.poisoned,
                 tracked_globally: cfg!(feature = "lock-order-graph"),
-            })
+            };
+
+            if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            }
         } else {
+            // Give the detector a transient look at this attempt: a cycle built
+            // entirely out of spinning try_write calls should still be caught,
+            // even though this particular attempt never blocks.
+            let current_writer_val = self.writer_owner.load(Ordering::Acquire);
+            let current_writer = if current_writer_val == 0 {
+                None
+            } else {
+                Some(current_writer_val as ThreadId)
+            };
+
+            let deadlock_info =
+                detector::rwlock::try_write_attempt(thread_id, self.id, current_writer);
+
+            if let Some(info) = deadlock_info {
+                let is_stale = if let Some(expected_writer) = current_writer {
+                    let actual_writer = self.writer_owner.load(Ordering::Relaxed);
+                    !detector::deadlock_handling::verify_deadlock_edges(
+                        &info,
+                        thread_id,
+                        self.id,
+                        expected_writer,
+                        actual_writer,
+                    )
+                } else {
+                    false
+                };
+
+                if !is_stale {
+                    detector::deadlock_handling::process_deadlock(info);
+                }
+            }
+
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    /// Acquire an exclusive (write) lock, blocking for at most `timeout`
+    ///
+    /// Behaves like [`RwLock::write`] but gives up and returns
+    /// [`TryLockError::WouldBlock`] if `timeout` elapses before the lock
+    /// becomes available.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// [`TryLockError::WouldBlock`] if the timeout elapses first, or
+    /// [`TryLockError::Abandoned`] if this thread was sacrificed to break a
+    /// detected deadlock cycle.
+    pub fn write_for(&self, timeout: Duration) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        self.write_until(Instant::now() + timeout)
+    }
+
+    /// Acquire an exclusive (write) lock, blocking until at most `deadline`
+    ///
+    /// See [`RwLock::write_for`] for the timed-out behavior. When
+    /// [`crate::Deloxide::with_deadlock_recovery`] is configured and this
+    /// thread is chosen as the victim to break a detected cycle, returns
+    /// early with [`TryLockError::Abandoned`] instead.
+    ///
+    /// # Errors
+    /// Returns [`TryLockError::Poisoned`] if a thread panicked while holding this lock,
+    /// [`TryLockError::WouldBlock`] if `deadline` passes first, or
+    /// [`TryLockError::Abandoned`] if this thread was sacrificed to break a
+    /// detected deadlock cycle.
+    pub fn write_until(&self, deadline: Instant) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        let thread_id = get_current_thread_id();
+        let tid_usize = thread_id as usize;
+
+        // Optimistic Fast Path (Writer) - Disabled during stress testing
+        #[cfg(not(feature = "stress-test"))]
+        if let Some(guard) = self.inner.try_write() {
+            self.writer_owner.store(tid_usize, Ordering::Release);
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::RwWriteAttempt);
+                }
+            }
+
+            #[cfg(feature = "lock-order-graph")]
+            detector::rwlock::complete_write(thread_id, self.id);
+
+            #[cfg(feature = "logging-and-visualization")]
+            {
+                if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                    logger::log_interaction_event(thread_id, self.id, Events::RwWriteAcquired);
+                }
+            }
+
+            let guard = RwLockWriteGuard {
+                thread_id,
+                lock_id: self.id,
+                guard,
+                owner_atomic: &self.writer_owner,
+                upgradable_owner_atomic: &self.upgradable_owner,
+                poisoned: &self.poisoned,
+                tracked_globally: cfg!(feature = "lock-order-graph"),
+            };
+
+            return if self.poisoned.load(Ordering::Acquire) {
+                Err(TryLockError::Poisoned(PoisonError::new(guard)))
+            } else {
+                Ok(guard)
+            };
+        }
+
+        // Slow Path
+        let current_writer_val = self.writer_owner.load(Ordering::Acquire);
+        let current_writer = if current_writer_val == 0 {
             None
+        } else {
+            Some(current_writer_val as ThreadId)
+        };
+
+        let deadlock_info = detector::rwlock::acquire_write_slow(thread_id, self.id, current_writer);
+
+        if let Some(info) = deadlock_info {
+            let is_stale = if let Some(expected_writer) = current_writer {
+                let actual_writer = self.writer_owner.load(Ordering::Relaxed);
+                !detector::deadlock_handling::verify_deadlock_edges(
+                    &info,
+                    thread_id,
+                    self.id,
+                    expected_writer,
+                    actual_writer,
+                )
+            } else {
+                false
+            };
+
+            if !is_stale {
+                detector::deadlock_handling::process_deadlock(info);
+            }
+        }
+
+        // Poll in short slices instead of one long blocking call when
+        // deadlock recovery is configured, so a thread chosen as the victim
+        // to break a detected cycle (see `Detector::should_abandon`)
+        // notices and bails out instead of waiting out the rest of the
+        // deadline; see `Mutex::lock_until` for the same pattern.
+        let guard = if detector::recovery_configured() {
+            const POLL_SLICE: Duration = Duration::from_millis(10);
+            loop {
+                let slice_deadline = deadline.min(Instant::now() + POLL_SLICE);
+                if let Some(guard) = self.inner.try_write_until(slice_deadline) {
+                    break Some(guard);
+                }
+                if detector::should_abandon(thread_id) {
+                    detector::rwlock::cancel_acquire(thread_id, self.id);
+                    return Err(TryLockError::Abandoned);
+                }
+                if Instant::now() >= deadline {
+                    break None;
+                }
+            }
+        } else {
+            self.inner.try_write_until(deadline)
+        };
+
+        let Some(guard) = guard else {
+            detector::rwlock::cancel_acquire(thread_id, self.id);
+            return Err(TryLockError::WouldBlock);
+        };
+
+        detector::rwlock::complete_write(thread_id, self.id);
+        self.writer_owner.store(tid_usize, Ordering::Release);
+
+        let guard = RwLockWriteGuard {
+            thread_id,
+            lock_id: self.id,
+            guard,
+            owner_atomic: &self.writer_owner,
+            upgradable_owner_atomic: &self.upgradable_owner,
+            poisoned: &self.poisoned,
+            tracked_globally: true,
+        };
+
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(TryLockError::Poisoned(PoisonError::new(guard)))
+        } else {
+            Ok(guard)
         }
     }
 
+    /// Returns `true` if the lock is poisoned.
+    ///
+    /// An RwLock becomes poisoned when a thread panics while holding one of its guards.
+    /// Once poisoned, every future lock acquisition returns a [`PoisonError`] until
+    /// [`RwLock::clear_poison`] is called.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Clears the poisoned state of this lock.
+    ///
+    /// If the lock is poisoned, this will clear the poisoning so future acquisitions
+    /// succeed without error. This is useful when the data protected by the lock is
+    /// known to still be in a valid state.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+        detector::clear_poisoned(self.id);
+    }
+
     /// Consumes this RwLock, returning the underlying data
     ///
     /// # Example
@@ -292,13 +773,18 @@ impl<T> RwLock<T> {
     /// use deloxide::RwLock;
     ///
     /// let lock = RwLock::new(String::from("hello"));
-    /// let s = lock.into_inner();
+    /// let s = lock.into_inner().unwrap();
     /// assert_eq!(s, "hello");
     /// ```
-    pub fn into_inner(self) -> T
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this lock.
+    pub fn into_inner(self) -> LockResult<T>
     where
         T: Sized,
     {
+        let poisoned = self.poisoned.load(Ordering::Acquire);
+
         // We need to prevent Drop from running since we're manually extracting the value
         // First, manually drop the detector tracking
         detector::rwlock::destroy_rwlock(self.id);
@@ -307,7 +793,13 @@ impl<T> RwLock<T> {
         let rwlock = std::mem::ManuallyDrop::new(self);
 
         // Safety: We're taking ownership and preventing double-drop
-        unsafe { std::ptr::read(&rwlock.inner) }.into_inner()
+        let value = unsafe { std::ptr::read(&rwlock.inner) }.into_inner();
+
+        if poisoned {
+            Err(PoisonError::new(value))
+        } else {
+            Ok(value)
+        }
     }
 
     /// Returns a mutable reference to the underlying data
@@ -321,11 +813,18 @@ impl<T> RwLock<T> {
     /// use deloxide::RwLock;
     ///
     /// let mut lock = RwLock::new(0);
-    /// *lock.get_mut() = 10;
-    /// assert_eq!(*lock.read(), 10);
+    /// *lock.get_mut().unwrap() = 10;
+    /// assert_eq!(*lock.read().unwrap(), 10);
     /// ```
-    pub fn get_mut(&mut self) -> &mut T {
-        self.inner.get_mut()
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this lock.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        if self.poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(self.inner.get_mut()))
+        } else {
+            Ok(self.inner.get_mut())
+        }
     }
 }
 
@@ -345,6 +844,21 @@ impl<'a, T> Deref for RwLockReadGuard<'a, T> {
 }
 impl<'a, T> Drop for RwLockReadGuard<'a, T> {
     fn drop(&mut self) {
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.lock_id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.lock_id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::RwPoisoned);
+            }
+        }
+
         detector::rwlock::release_read(self.thread_id, self.lock_id);
     }
 }
@@ -362,6 +876,22 @@ impl<'a, T> DerefMut for RwLockWriteGuard<'a, T> {
 }
 impl<'a, T> Drop for RwLockWriteGuard<'a, T> {
     fn drop(&mut self) {
+        // 0. Poison the lock if we're unwinding from a panic while holding the guard
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.lock_id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.lock_id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::RwPoisoned);
+            }
+        }
+
         // 1. Clear local ownership
         self.owner_atomic.store(0, Ordering::Release);
 
@@ -383,6 +913,117 @@ impl<'a, T> Drop for RwLockWriteGuard<'a, T> {
     }
 }
 
+impl<'a, T> RwLockWriteGuard<'a, T> {
+    /// Atomically downgrades this write guard into an upgradable read guard
+    ///
+    /// Unlike dropping the write guard and calling [`RwLock::upgradable_read`], this
+    /// never releases exclusive access in between, so no other thread can acquire the
+    /// write lock in the gap. The returned guard can later call
+    /// [`RwLockUpgradableReadGuard::upgrade`] to go back to a write lock.
+    pub fn downgrade(self) -> RwLockUpgradableReadGuard<'a, T> {
+        let thread_id = self.thread_id;
+        let lock_id = self.lock_id;
+        let poisoned = self.poisoned;
+        let writer_owner_atomic = self.owner_atomic;
+        let upgradable_owner_atomic = self.upgradable_owner_atomic;
+
+        // Prevent this guard's Drop from reporting a release; ownership is handed off.
+        let this = std::mem::ManuallyDrop::new(self);
+        let inner_guard = unsafe { std::ptr::read(&this.guard) };
+
+        let upgradable_guard = ParkingLotWriteGuard::downgrade_to_upgradable(inner_guard);
+        detector::rwlock::downgrade_to_upgradable(thread_id, lock_id);
+        writer_owner_atomic.store(0, Ordering::Release);
+        upgradable_owner_atomic.store(thread_id, Ordering::Release);
+
+        RwLockUpgradableReadGuard {
+            thread_id,
+            lock_id,
+            guard: upgradable_guard,
+            owner_atomic: upgradable_owner_atomic,
+            writer_owner_atomic,
+            poisoned,
+        }
+    }
+}
+
+impl<'a, T> Deref for RwLockUpgradableReadGuard<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<'a, T> RwLockUpgradableReadGuard<'a, T> {
+    /// Atomically upgrades this upgradable read guard into an exclusive write guard
+    ///
+    /// Blocks until all current readers release their read locks. Other threads may
+    /// not acquire any kind of lock (read, write, or upgradable) while the upgrade is
+    /// pending. If another thread is itself upgrading and the two upgrades wait on
+    /// each other's readers, this is reported as a deadlock.
+    ///
+    /// # Errors
+    /// Returns a [`PoisonError`] if a thread panicked while holding this lock.
+    pub fn upgrade(self) -> LockResult<RwLockWriteGuard<'a, T>> {
+        let thread_id = self.thread_id;
+        let lock_id = self.lock_id;
+        let poisoned = self.poisoned;
+        let writer_owner_atomic = self.writer_owner_atomic;
+        let upgradable_owner_atomic = self.owner_atomic;
+
+        let deadlock_info = detector::rwlock::acquire_upgrade_slow(thread_id, lock_id);
+        if let Some(info) = deadlock_info {
+            detector::deadlock_handling::process_deadlock(info);
+        }
+
+        // Prevent this guard's Drop from reporting a release; ownership is handed off.
+        let this = std::mem::ManuallyDrop::new(self);
+        let inner_guard = unsafe { std::ptr::read(&this.guard) };
+
+        let write_guard = inner_guard.upgrade();
+        detector::rwlock::complete_upgrade(thread_id, lock_id);
+        writer_owner_atomic.store(thread_id, Ordering::Release);
+
+        let guard = RwLockWriteGuard {
+            thread_id,
+            lock_id,
+            guard: write_guard,
+            owner_atomic: writer_owner_atomic,
+            upgradable_owner_atomic,
+            poisoned,
+            tracked_globally: true,
+        };
+
+        if poisoned.load(Ordering::Acquire) {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+}
+
+impl<'a, T> Drop for RwLockUpgradableReadGuard<'a, T> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::Release);
+            detector::mark_poisoned(self.lock_id);
+            detector::mutex::report_abandoned_lock(
+                self.thread_id,
+                self.lock_id,
+                crate::core::panic_info::take_last_panic_message(),
+            );
+
+            #[cfg(feature = "logging-and-visualization")]
+            if logger::LOGGING_ENABLED.load(Ordering::Relaxed) {
+                logger::log_interaction_event(self.thread_id, self.lock_id, Events::RwPoisoned);
+            }
+        }
+
+        self.owner_atomic.store(0, Ordering::Release);
+        detector::rwlock::release_upgradable_read(self.thread_id, self.lock_id);
+    }
+}
+
 // Trait implementations for better compatibility with std
 
 impl<T: Default> Default for RwLock<T> {
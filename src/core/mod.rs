@@ -20,6 +20,16 @@ pub(crate) mod detector;
 #[allow(unused_imports)]
 pub(crate) use detector::*;
 
+// Cross-process distributed deadlock detection
+#[cfg(feature = "distributed")]
+pub(crate) mod distributed;
+
+// Lock acquisition stack trace capture
+pub(crate) mod stacktrace;
+
+// Panic message capture, for reporting abandoned locks
+pub(crate) mod panic_info;
+
 pub mod thread;
 
 pub(crate) mod locks;
@@ -28,9 +38,15 @@ pub mod stress;
 #[allow(unused_imports)]
 pub use stress::{StressConfig, StressMode};
 
+// Deterministic, systematically-varied RwLock schedule exploration
+pub mod explore;
+
 use anyhow::Result;
 #[cfg(feature = "logging-and-visualization")]
 use logger::EventLogger;
+pub use logger::FlushGuard;
+#[cfg(feature = "logging-and-visualization")]
+pub use logger::LogFormat;
 
 /// Deloxide configuration builder struct
 ///
@@ -65,6 +81,10 @@ pub struct Deloxide {
     #[cfg(feature = "logging-and-visualization")]
     log_path: Option<String>,
 
+    /// Wire format the log file is written in; see `with_log_format`
+    #[cfg(feature = "logging-and-visualization")]
+    log_format: logger::LogFormat,
+
     /// Callback function to invoke when a deadlock is detected
     callback: Box<dyn Fn(DeadlockInfo) + Send + Sync + 'static>,
 
@@ -72,6 +92,11 @@ pub struct Deloxide {
     #[cfg(feature = "lock-order-graph")]
     check_lock_order: bool,
 
+    /// How to react to a detected lock order violation; see
+    /// [`with_lock_order_violation_policy`](Self::with_lock_order_violation_policy)
+    #[cfg(feature = "lock-order-graph")]
+    lock_order_violation_policy: LockOrderViolationPolicy,
+
     /// Stress testing mode (only available with "stress-test" feature)
     #[cfg(feature = "stress-test")]
     stress_mode: StressMode,
@@ -79,6 +104,37 @@ pub struct Deloxide {
     /// Stress testing configuration (only available with "stress-test" feature)
     #[cfg(feature = "stress-test")]
     stress_config: Option<StressConfig>,
+
+    /// Address of a distributed coordinator to forward wait-for edges to
+    /// (only available with the "distributed" feature)
+    #[cfg(feature = "distributed")]
+    coordinator_addr: Option<String>,
+
+    /// Address to bind a distributed coordinator on, making this process the
+    /// coordinator (only available with the "distributed" feature)
+    #[cfg(feature = "distributed")]
+    coordinator_bind_addr: Option<String>,
+
+    /// Whether to capture a stack trace at every lock acquisition/wait, so
+    /// `DeadlockInfo::lock_sites` can be populated
+    capture_backtraces: bool,
+
+    /// Background watchdog configuration, if enabled with `with_watchdog`
+    watchdog: Option<detector::WatchdogConfig>,
+
+    /// Background parking_lot oracle configuration, if enabled with
+    /// `with_parking_lot_oracle` (only available with the
+    /// "parking-lot-oracle" feature)
+    #[cfg(feature = "parking-lot-oracle")]
+    oracle: Option<detector::OracleConfig>,
+
+    /// Victim-selection callback for deadlock recovery, if enabled with
+    /// `with_deadlock_recovery`
+    recovery: Option<Box<dyn Fn(&DeadlockInfo) -> Option<ThreadId> + Send + Sync + 'static>>,
+
+    /// Whether a new reader is modeled as blocked by a writer that's merely
+    /// queued; see [`with_rwlock_fairness`](Self::with_rwlock_fairness)
+    rwlock_fairness: RwLockFairness,
 }
 
 impl Default for Deloxide {
@@ -98,7 +154,25 @@ impl Deloxide {
         Deloxide {
             #[cfg(feature = "logging-and-visualization")]
             log_path: Some("deloxide.log".to_string()),
+            #[cfg(feature = "logging-and-visualization")]
+            log_format: logger::LogFormat::default(),
             callback: Box::new(|info: DeadlockInfo| {
+                for site in &info.lock_sites {
+                    let held = match (site.held_lock, &site.held_at) {
+                        (Some(lock), Some(at)) => format!("held lock {lock} acquired at {at}"),
+                        (Some(lock), None) => format!("held lock {lock}"),
+                        (None, _) => "holds no lock in this cycle".to_string(),
+                    };
+                    let waiting = match &site.waiting_at {
+                        Some(at) => format!(
+                            "waiting on lock {} requested at {at}",
+                            site.waiting_lock
+                        ),
+                        None => format!("waiting on lock {}", site.waiting_lock),
+                    };
+                    eprintln!("Thread {}: {held}, {waiting}", site.thread_id);
+                }
+
                 panic!(
                     "Deadlock detected: {}",
                     serde_json::to_string_pretty(&info).unwrap_or_else(|_| format!("{info:?}"))
@@ -106,10 +180,22 @@ impl Deloxide {
             }),
             #[cfg(feature = "lock-order-graph")]
             check_lock_order: true,
+            #[cfg(feature = "lock-order-graph")]
+            lock_order_violation_policy: LockOrderViolationPolicy::LogOnly,
             #[cfg(feature = "stress-test")]
             stress_mode: StressMode::None,
             #[cfg(feature = "stress-test")]
             stress_config: None,
+            #[cfg(feature = "distributed")]
+            coordinator_addr: None,
+            #[cfg(feature = "distributed")]
+            coordinator_bind_addr: None,
+            capture_backtraces: false,
+            watchdog: None,
+            #[cfg(feature = "parking-lot-oracle")]
+            oracle: None,
+            recovery: None,
+            rwlock_fairness: RwLockFairness::default(),
         }
     }
 
@@ -170,6 +256,22 @@ impl Deloxide {
         self
     }
 
+    /// Choose the wire format the log file is written in
+    ///
+    /// Defaults to [`LogFormat::Json`](logger::LogFormat::Json), one
+    /// human-readable JSON object per line. [`LogFormat::Bincode`](logger::LogFormat::Bincode)
+    /// trades that readability for a compact length-prefixed binary encoding
+    /// with no JSON allocation on the hot logging path, worth it on
+    /// high-event-rate traces.
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    #[cfg(feature = "logging-and-visualization")]
+    pub fn with_log_format(mut self, format: logger::LogFormat) -> Self {
+        self.log_format = format;
+        self
+    }
+
     /// Set a custom callback to be invoked when a deadlock is detected
     ///
     /// # Arguments
@@ -292,6 +394,51 @@ impl Deloxide {
         self
     }
 
+    /// Configure how the detector reacts to a detected lock order violation
+    ///
+    /// Defaults to [`LockOrderViolationPolicy::LogOnly`], which reports the
+    /// violation through the callback/log and lets the offending thread's
+    /// `lock()` call proceed. Setting this to
+    /// [`LockOrderViolationPolicy::Panic`] or
+    /// [`LockOrderViolationPolicy::Abort`] makes Deloxide fail fast, like the
+    /// `tracing-mutex` crate's `DebugMutex`: the offending thread panics (or
+    /// aborts the process) at the `lock()` call site the instant a cyclic
+    /// lock acquisition order is created, with the cycle and - if
+    /// `.with_backtraces()` is enabled - the conflicting acquisition sites,
+    /// instead of waiting for threads to actually deadlock.
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    ///
+    /// # Note
+    /// This method is only available when the "lock-order-graph" feature is
+    /// enabled. It has no effect unless lock order checking itself is also
+    /// enabled (the default; see [`with_lock_order_checking`](Self::with_lock_order_checking)).
+    #[cfg(feature = "lock-order-graph")]
+    pub fn with_lock_order_violation_policy(mut self, policy: LockOrderViolationPolicy) -> Self {
+        self.lock_order_violation_policy = policy;
+        self
+    }
+
+    /// Configure whether a new reader is modeled as blocked by a writer
+    /// that's merely queued (task-fair, the default) or only by one that
+    /// already holds the lock
+    ///
+    /// Defaults to [`RwLockFairness::WriterPreferring`], matching
+    /// parking_lot's `RwLock` (the implementation Deloxide's own
+    /// `crate::RwLock` wraps), which blocks new readers behind a queued
+    /// writer to avoid starving it. Set this to
+    /// [`RwLockFairness::ReaderPreferring`] to match a reader-preferring
+    /// implementation instead, since which model is used changes whether a
+    /// given interleaving is reported as a deadlock.
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn with_rwlock_fairness(mut self, fairness: RwLockFairness) -> Self {
+        self.rwlock_fairness = fairness;
+        self
+    }
+
     /// Initialize the deloxide deadlock detector with the configured settings
     ///
     /// This finalizes the configuration and starts the deadlock detector.
@@ -299,7 +446,11 @@ impl Deloxide {
     /// operations and can detect deadlocks.
     ///
     /// # Returns
-    /// A Result that is Ok if initialization succeeded, or an error if it failed
+    /// A [`FlushGuard`] that flushes the logger when dropped, or an error if
+    /// initialization failed. Most callers can discard it and rely on the
+    /// `atexit` hook this method also registers; holding onto it instead
+    /// (e.g. binding it in `main`) guarantees a final flush at an exact point
+    /// of your choosing. See also [`crate::flush_on_panic`].
     ///
     /// # Errors
     /// Returns an error if logger initialization fails
@@ -329,26 +480,67 @@ impl Deloxide {
     ///         .expect("Failed to initialize deadlock detector");
     /// }
     /// ```
-    pub fn start(self) -> Result<()> {
+    pub fn start(self) -> Result<FlushGuard> {
+        stacktrace::set_capture_enabled(self.capture_backtraces);
+        panic_info::install_hook();
+        logger::install_atexit_flush_hook();
+
         // Initialize the logger if enabled
         #[cfg(feature = "logging-and-visualization")]
         let logger = if let Some(log_path) = self.log_path {
-            Some(EventLogger::with_file(log_path)?)
+            Some(EventLogger::with_encoder(
+                log_path,
+                self.log_format.encoder(),
+            )?)
         } else {
             None
         };
 
+        // If this process is the distributed coordinator, start it listening
+        // before connecting any local participant to it.
+        #[cfg(feature = "distributed")]
+        if let Some(bind_addr) = self.coordinator_bind_addr {
+            distributed::Coordinator::start(bind_addr)?;
+        }
+
+        // Connect to a distributed coordinator, if configured. Cross-process
+        // cycles are delivered through the same callback/logging path as
+        // local deadlocks via `deadlock_handling::process_deadlock`.
+        #[cfg(feature = "distributed")]
+        let distributed_client = match self.coordinator_addr {
+            Some(addr) => Some(distributed::DistributedClient::connect(
+                addr,
+                |node_cycle| {
+                    let info = detector::deadlock_handling::extract_distributed_deadlock_info(
+                        distributed::local_process_id(),
+                        node_cycle,
+                    );
+                    detector::deadlock_handling::process_deadlock(info);
+                },
+            )?),
+            None => None,
+        };
+
         // Create configuration object
         let config = detector::DetectorConfig {
             callback: self.callback,
             #[cfg(feature = "lock-order-graph")]
             check_lock_order: self.check_lock_order,
+            #[cfg(feature = "lock-order-graph")]
+            violation_policy: self.lock_order_violation_policy,
             #[cfg(feature = "stress-test")]
             stress_mode: self.stress_mode,
             #[cfg(feature = "stress-test")]
             stress_config: self.stress_config,
             #[cfg(feature = "logging-and-visualization")]
             logger,
+            #[cfg(feature = "distributed")]
+            distributed_client,
+            rwlock_fairness: self.rwlock_fairness,
+            watchdog: self.watchdog,
+            #[cfg(feature = "parking-lot-oracle")]
+            oracle: self.oracle,
+            recovery: self.recovery,
         };
 
         // Initialize the detector
@@ -357,7 +549,7 @@ impl Deloxide {
         // Print header
         println!("{}", crate::BANNER);
 
-        Ok(())
+        Ok(logger::flush_guard())
     }
 
     /// Enable random preemption stress testing
@@ -413,4 +605,238 @@ impl Deloxide {
         self.stress_config = Some(config);
         self
     }
+
+    /// Enable PCT (Probabilistic Concurrency Testing)-style stress testing
+    ///
+    /// Rather than perturbing timing uniformly, this assigns each contending
+    /// thread a random priority and a handful of forced priority-change
+    /// points, steering interleavings toward a latent bug of the given
+    /// `depth` instead of waiting for one to show up by chance - turning a
+    /// flaky, sleep-tuned repro into a deterministic, seed-replayable one.
+    /// See [`StressConfig::pct_depth`] for what `depth` controls.
+    ///
+    /// # Arguments
+    /// * `depth` - Target bug depth; higher values cast a wider net at the
+    ///   cost of perturbing the run more
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    ///
+    /// # Note
+    /// This method is only available when the "stress-test" feature is enabled.
+    #[cfg(feature = "stress-test")]
+    pub fn with_pct_stress(mut self, depth: usize) -> Self {
+        self.stress_mode = StressMode::Pct;
+        self.stress_config = Some(
+            self.stress_config
+                .unwrap_or_default()
+                .with_pct_depth(depth),
+        );
+        self
+    }
+
+    /// Enable seeded active fuzzing: random preemption stress testing with a
+    /// fixed, replayable seed
+    ///
+    /// Shorthand for [`Deloxide::with_stress_config`] with
+    /// [`StressConfig::with_seed`] already applied, under
+    /// [`StressMode::RandomPreemption`] - every preemption decision at a lock
+    /// acquisition point is then a deterministic function of `seed` alone, so
+    /// a run that surfaces a deadlock can be reproduced bit-for-bit later
+    /// with `stress::replay`, and the seed itself is recorded on the
+    /// resulting [`DeadlockInfo::stress_seed`].
+    ///
+    /// # Arguments
+    /// * `seed` - Seed for the deterministic per-thread PRNGs driving
+    ///   preemption decisions
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    ///
+    /// # Note
+    /// This method is only available when the "stress-test" feature is enabled.
+    #[cfg(feature = "stress-test")]
+    pub fn with_fuzzing(mut self, seed: u64) -> Self {
+        self.stress_mode = StressMode::RandomPreemption;
+        self.stress_config = Some(self.stress_config.unwrap_or_default().with_seed(seed));
+        self
+    }
+
+    /// Forward this process's wait-for edges to a distributed coordinator
+    ///
+    /// Connects to a coordinator started elsewhere with [`Deloxide::as_coordinator`]
+    /// (possibly in another process). Deadlocks whose cycle spans multiple
+    /// processes are reported through the usual [`Deloxide::callback`], with
+    /// [`DeadlockInfo::distributed_cycle`] carrying the full cross-process cycle.
+    ///
+    /// # Arguments
+    /// * `addr` - Address of the coordinator, e.g. `"127.0.0.1:7777"`
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    ///
+    /// # Note
+    /// This method is only available when the "distributed" feature is enabled.
+    #[cfg(feature = "distributed")]
+    pub fn with_coordinator<A: Into<String>>(mut self, addr: A) -> Self {
+        self.coordinator_addr = Some(addr.into());
+        self
+    }
+
+    /// Run a distributed coordinator in this process
+    ///
+    /// The coordinator merges wait-for edges forwarded by every process that
+    /// connects via [`Deloxide::with_coordinator`] into a single graph keyed
+    /// by `(ProcessId, ThreadId)`, and runs the same incremental cycle
+    /// detection over it. A process can be a coordinator and a participant at
+    /// the same time by also calling [`Deloxide::with_coordinator`] pointed at
+    /// its own bind address.
+    ///
+    /// # Arguments
+    /// * `bind_addr` - Address to listen on, e.g. `"0.0.0.0:7777"`
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    ///
+    /// # Note
+    /// This method is only available when the "distributed" feature is enabled.
+    #[cfg(feature = "distributed")]
+    pub fn as_coordinator<A: Into<String>>(mut self, bind_addr: A) -> Self {
+        self.coordinator_bind_addr = Some(bind_addr.into());
+        self
+    }
+
+    /// Capture a stack trace at every lock acquisition and wait
+    ///
+    /// When enabled, a deadlock report's [`DeadlockInfo::lock_sites`] carries
+    /// the source location where each thread in the cycle acquired the lock
+    /// it holds and where it requested the lock it's waiting for. Off by
+    /// default, since capturing a backtrace on every lock operation is
+    /// expensive.
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn with_backtraces(mut self) -> Self {
+        self.capture_backtraces = true;
+        self
+    }
+
+    /// Enable a background watchdog that periodically scans for deadlocks
+    ///
+    /// The detector normally only checks for cycles reactively, at the
+    /// moment a thread attempts a tracked lock operation. That misses a
+    /// deadlock where the final blocking thread never reaches another
+    /// tracked acquisition site (e.g. blocked inside a condvar wait with a
+    /// lost notification, or waiting on a resource this library doesn't
+    /// model). The watchdog runs on its own background thread, waking every
+    /// `interval` to scan the whole wait-for graph for a cycle and check
+    /// whether any thread has been blocked continuously for at least
+    /// `interval` (see [`Deloxide::with_watchdog_threshold`] to use a
+    /// different threshold). Either condition fires the deadlock callback
+    /// with [`DeadlockSource::Watchdog`], whose [`DeadlockInfo::stalled_threads`]
+    /// carries "blocked for N ms" diagnostics per thread.
+    ///
+    /// # Arguments
+    /// * `interval` - How often the watchdog scans the global detector
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn with_watchdog(mut self, interval: std::time::Duration) -> Self {
+        self.watchdog = Some(detector::WatchdogConfig {
+            interval,
+            stall_threshold: interval,
+        });
+        self
+    }
+
+    /// Override the stall threshold used by the background watchdog
+    ///
+    /// Only meaningful after [`Deloxide::with_watchdog`]; a no-op otherwise.
+    /// By default the threshold equals the scan `interval` passed to
+    /// `with_watchdog`, so a thread is reported as soon as the watchdog has
+    /// observed it blocked on two consecutive scans.
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn with_watchdog_threshold(mut self, threshold: std::time::Duration) -> Self {
+        if let Some(watchdog) = &mut self.watchdog {
+            watchdog.stall_threshold = threshold;
+        }
+        self
+    }
+
+    /// Enable cross-validation of reported deadlocks against parking_lot's
+    /// own, independent `deadlock_detection`
+    ///
+    /// Every lock here is already backed by `parking_lot`. This spawns a
+    /// background thread that polls `parking_lot::deadlock::check_deadlock`
+    /// every `interval`; whenever deloxide's wait-for graph reports a real
+    /// cycle, the most recent poll is checked for agreement and a
+    /// disagreement is printed to stderr as a diagnostic. This gives a
+    /// second, independently-implemented detector to cross-check deloxide's
+    /// own reports against, and can help catch an instrumentation gap (e.g.
+    /// a lock path that forgot to emit an attempt/acquire event).
+    ///
+    /// Only available with the "parking-lot-oracle" feature, which must also
+    /// enable `parking_lot/deadlock_detection`; production builds that don't
+    /// opt into the feature pay nothing for this.
+    ///
+    /// # Arguments
+    /// * `interval` - How often to poll parking_lot's own detector
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    #[cfg(feature = "parking-lot-oracle")]
+    pub fn with_parking_lot_oracle(mut self, interval: std::time::Duration) -> Self {
+        self.oracle = Some(detector::OracleConfig { interval });
+        self
+    }
+
+    /// Enable deadlock recovery: when a wait-for cycle is detected, pick a
+    /// victim thread and let it bail out instead of blocking forever.
+    ///
+    /// `select_victim` receives the detected cycle and returns the
+    /// [`ThreadId`] to sacrifice, or `None` to fall back to the default
+    /// policy (the thread in the cycle currently holding the fewest locks).
+    /// A returned `ThreadId` that isn't actually part of the cycle is treated
+    /// the same as `None`.
+    ///
+    /// Only the timed acquisitions - [`crate::Mutex::lock_for`]/[`crate::Mutex::lock_until`]
+    /// and [`crate::RwLock::read_for`]/[`crate::RwLock::read_until`]/
+    /// [`crate::RwLock::write_for`]/[`crate::RwLock::write_until`] - honor the
+    /// selection - they already return a `TryLockResult`, a natural place to
+    /// add a new "gave up early" outcome. Plain [`crate::Mutex::lock`] is
+    /// unaffected and keeps blocking forever, matching its documented,
+    /// always-succeeds-eventually contract. The victim's call returns
+    /// `TryLockError::Abandoned` once it notices.
+    ///
+    /// Only applies to real wait-for cycles ([`DeadlockSource::WaitForGraph`]);
+    /// other sources (e.g. priority inversion, abandoned locks) aren't
+    /// blocking in the same way and are left alone.
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn with_deadlock_recovery<F>(mut self, select_victim: F) -> Self
+    where
+        F: Fn(&DeadlockInfo) -> Option<ThreadId> + Send + Sync + 'static,
+    {
+        self.recovery = Some(Box::new(select_victim));
+        self
+    }
+
+    /// Enable deadlock recovery using the "lowest thread id" victim-selection
+    /// policy instead of the default "fewest locks held" one.
+    ///
+    /// Shorthand for [`Deloxide::with_deadlock_recovery`] with a closure that
+    /// always picks [`detector::Detector::lowest_thread_id_victim`].
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn with_deadlock_recovery_lowest_thread_id(self) -> Self {
+        self.with_deadlock_recovery(|info| {
+            Some(detector::Detector::lowest_thread_id_victim(
+                &info.thread_cycle,
+            ))
+        })
+    }
 }
@@ -0,0 +1,47 @@
+//! Thread-local capture of the in-flight panic message
+//!
+//! `MutexGuard::drop` already learns *that* its thread is panicking via
+//! `std::thread::panicking()`, but not the panic's message: by the time
+//! `catch_unwind` in [`crate::core::thread`] observes the payload, every
+//! guard on the unwinding stack has already run its `Drop`. A panic hook
+//! runs earlier, before any unwinding starts, so this module installs one
+//! that stashes the message per-thread for a guard's `Drop` to read back
+//! when reporting an abandoned lock.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+thread_local! {
+    static LAST_PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Install a panic hook that records each thread's panic message, chaining
+/// to whatever hook was already installed so existing panic output (e.g.
+/// `RUST_BACKTRACE`) is unaffected.
+///
+/// Called once by `Deloxide::start()`; safe to call more than once, but only
+/// the first call actually installs the hook.
+pub fn install_hook() {
+    if INSTALLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned());
+        LAST_PANIC_MESSAGE.with(|cell| *cell.borrow_mut() = message);
+        previous(info);
+    }));
+}
+
+/// Take (and clear) the current thread's most recently recorded panic
+/// message, if the panic hook has run on it since the last call
+pub fn take_last_panic_message() -> Option<String> {
+    LAST_PANIC_MESSAGE.with(|cell| cell.borrow_mut().take())
+}
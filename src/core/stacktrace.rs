@@ -0,0 +1,118 @@
+//! Lock acquisition backtrace capture
+//!
+//! Mirrors the "lock context" LLVM's sanitizer deadlock detector stores with
+//! every currently held lock: when capture is enabled, a backtrace is taken
+//! at the moment a lock is acquired or a thread begins waiting for one, and
+//! interned behind a compact [`StackTraceId`] so the detector only ever has
+//! to store a cheap handle per lock/thread rather than a full backtrace.
+//!
+//! Capturing a backtrace on every lock operation is expensive, so it is off
+//! by default; enable it with `Deloxide::with_backtraces()`.
+
+use fxhash::FxHashMap;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Compact handle to an interned backtrace, cheap to store per lock/thread
+pub type StackTraceId = u64;
+
+static CAPTURE_ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A captured acquisition site: the concise single-line location most
+/// reports want, plus the full multi-frame trace for anyone who needs to see
+/// the whole call path, not just where it bottoms out
+struct CapturedSite {
+    /// The first frame outside Deloxide's own machinery - the caller's actual
+    /// acquisition site
+    top_frame: String,
+    /// All frames outside Deloxide's own machinery, one per line
+    full: String,
+}
+
+lazy_static::lazy_static! {
+    static ref STACKTRACES: Mutex<FxHashMap<StackTraceId, CapturedSite>> = Mutex::new(FxHashMap::default());
+}
+
+/// Enable or disable backtrace capture globally
+///
+/// Called once at startup: by `Deloxide::start()` with its `.with_backtraces()`
+/// setting, or by the FFI `deloxide_init` with `deloxide_enable_backtraces()`'s.
+pub fn set_capture_enabled(enabled: bool) {
+    CAPTURE_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Capture the current call stack and return a compact id for it
+///
+/// # Returns
+/// `Some(id)` resolvable later with [`format_stacktrace`], or `None` if
+/// capture is disabled (the common case, since capturing a backtrace on
+/// every lock operation is expensive).
+pub fn capture() -> Option<StackTraceId> {
+    if !CAPTURE_ENABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+
+    let backtrace = backtrace::Backtrace::new();
+    let site = CapturedSite {
+        top_frame: top_user_frame(&backtrace),
+        full: user_frames(&backtrace),
+    };
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    STACKTRACES.lock().insert(id, site);
+    Some(id)
+}
+
+/// Resolve a previously captured stack-trace id back to its source location
+///
+/// Returns `None` if the id is unknown (e.g. capture was disabled when it
+/// was recorded, or the id was simply never captured).
+pub fn format_stacktrace(id: StackTraceId) -> Option<String> {
+    STACKTRACES.lock().get(&id).map(|site| site.top_frame.clone())
+}
+
+/// Resolve a previously captured stack-trace id to its full, multi-frame
+/// trace rather than just the top acquisition site
+///
+/// Returns `None` if the id is unknown, for the same reasons as
+/// [`format_stacktrace`].
+pub fn format_full_stacktrace(id: StackTraceId) -> Option<String> {
+    STACKTRACES.lock().get(&id).map(|site| site.full.clone())
+}
+
+/// Describe the first frame that isn't inside Deloxide's own locking or
+/// backtrace-capture machinery, i.e. the caller's actual acquisition site
+fn top_user_frame(backtrace: &backtrace::Backtrace) -> String {
+    user_frame_descriptions(backtrace)
+        .next()
+        .unwrap_or_else(|| "<unknown>".to_string())
+}
+
+/// Describe every frame outside Deloxide's own machinery, one per line, most
+/// recent call first - the full call path that led to this acquisition site
+fn user_frames(backtrace: &backtrace::Backtrace) -> String {
+    let frames: Vec<String> = user_frame_descriptions(backtrace).collect();
+    if frames.is_empty() {
+        "<unknown>".to_string()
+    } else {
+        frames.join("\n")
+    }
+}
+
+/// Describe every frame outside Deloxide's own locking or backtrace-capture
+/// machinery, most recent call first
+fn user_frame_descriptions(backtrace: &backtrace::Backtrace) -> impl Iterator<Item = String> {
+    backtrace.frames().iter().filter_map(|frame| {
+        frame.symbols().iter().find_map(|symbol| {
+            let name = symbol.name()?.to_string();
+            if name.contains("backtrace::") || name.contains("deloxide::core::") {
+                return None;
+            }
+
+            Some(match (symbol.filename(), symbol.lineno()) {
+                (Some(file), Some(line)) => format!("{name} ({}:{line})", file.display()),
+                _ => name,
+            })
+        })
+    })
+}
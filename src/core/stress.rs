@@ -6,6 +6,7 @@
 use crate::core::types::{LockId, ThreadId};
 use fxhash::FxHashMap;
 use parking_lot::Mutex;
+use std::collections::VecDeque;
 use std::thread;
 use std::time::Duration;
 
@@ -25,6 +26,10 @@ pub enum StressMode {
     RandomPreemption,
     /// Component-based delays using lock acquisition patterns
     ComponentBased,
+    /// PCT (Probabilistic Concurrency Testing)-style priority scheduling,
+    /// aimed at provoking a latent bug of a given depth rather than just
+    /// perturbing timing. See [`StressConfig::pct_depth`].
+    Pct,
 }
 
 /// Configuration options for stress testing
@@ -38,6 +43,36 @@ pub struct StressConfig {
     pub max_delay_us: u64,
     /// Whether to preempt after lock releases
     pub preempt_after_release: bool,
+    /// Force every `FairMutex` unlock to hand off directly to the longest-waiting
+    /// thread, regardless of how long it has actually been blocked. This widens
+    /// the interleavings a fuzzing run can reach by forcing starvation-avoiding
+    /// handoffs instead of the default "only past the fairness threshold" behavior.
+    pub fair_unlock: bool,
+    /// Seed for deterministic, replayable scheduling. When `Some`, every
+    /// preemption decision is drawn from a per-thread splittable PRNG seeded
+    /// with `seed ^ thread_id` instead of the OS RNG, so a run with the same
+    /// seed and the same per-thread lock acquisition order reaches the same
+    /// decisions bit-for-bit, and can be recorded and later fed back through
+    /// [`replay`]. `None` (the default) keeps the original non-deterministic
+    /// behavior.
+    pub seed: Option<u64>,
+    /// Target bug depth `d` for [`StressMode::Pct`]: `d - 1` priority
+    /// change points are forced into the run. A depth-`d` concurrency bug (one
+    /// that needs `d` specific context switches to reproduce) is hit with
+    /// probability at least `1/(n * k^(d-1))`, where `n` is the number of
+    /// distinct threads seen and `k` is the number of scheduling points -
+    /// higher depths cast a wider net at the cost of perturbing the run more.
+    /// Ignored by every other [`StressMode`].
+    pub pct_depth: usize,
+    /// Estimate `k` of how many PCT scheduling points the run will hit, for
+    /// [`StressMode::Pct`]: change points are drawn uniformly from `[1, k]`
+    /// steps apart, so this should be in the ballpark of the number of
+    /// instrumented lock operations the workload under test actually
+    /// performs. Too small overconcentrates every change point near the
+    /// start of the run; too large spreads them past where the run actually
+    /// ends, degenerating to plain priority scheduling with no forced
+    /// switches. Ignored by every other [`StressMode`].
+    pub pct_estimated_steps: u64,
 }
 
 impl Default for StressConfig {
@@ -47,6 +82,10 @@ impl Default for StressConfig {
             min_delay_us: 250,  // 250us
             max_delay_us: 2000, // 2ms
             preempt_after_release: true,
+            fair_unlock: false,
+            seed: None,
+            pct_depth: 3,
+            pct_estimated_steps: 50,
         }
     }
 }
@@ -75,6 +114,7 @@ impl StressConfig {
             min_delay_us: 500,
             max_delay_us: 5000,
             preempt_after_release: true,
+            ..Default::default()
         }
     }
 
@@ -85,8 +125,64 @@ impl StressConfig {
             min_delay_us: 20,
             max_delay_us: 100,
             preempt_after_release: false,
+            ..Default::default()
         }
     }
+
+    /// Same configuration with deterministic, replayable scheduling enabled
+    ///
+    /// Every preemption decision will be drawn from a per-thread PRNG seeded
+    /// with `seed`, instead of the OS RNG, so the run can be reproduced later
+    /// by replaying its recorded decision log with [`replay`].
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Target bug depth for [`StressMode::Pct`]; see [`StressConfig::pct_depth`]
+    pub fn with_pct_depth(mut self, depth: usize) -> Self {
+        self.pct_depth = depth.max(1);
+        self
+    }
+
+    /// Estimated step count for [`StressMode::Pct`]; see
+    /// [`StressConfig::pct_estimated_steps`]
+    pub fn with_pct_estimated_steps(mut self, estimated_steps: u64) -> Self {
+        self.pct_estimated_steps = estimated_steps.max(1);
+        self
+    }
+}
+
+/// A splittable, deterministic PRNG (SplitMix64) used to give each thread its
+/// own reproducible decision stream when [`StressConfig::seed`] is set.
+/// Seeding each thread's stream with `seed ^ thread_id` (rather than sharing
+/// one global RNG) keeps the sequence reproducible across runs regardless of
+/// the actual OS thread start order, as long as each thread draws in the same
+/// per-thread order both times.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Draw a value uniformly in `[lo, hi]` (inclusive)
+    fn next_range(&mut self, lo: u64, hi: u64) -> u64 {
+        if lo >= hi {
+            return lo;
+        }
+        lo + (self.next_u64() % (hi - lo + 1))
+    }
 }
 
 /// State for tracking lock relationships
@@ -138,12 +234,93 @@ impl ComponentTracker {
     }
 }
 
+/// PCT (Probabilistic Concurrency Testing) priority-scheduling state
+///
+/// Deloxide doesn't run its own cooperative scheduler the way Shuttle's
+/// `PctScheduler` does, so this is a best-effort approximation layered on top
+/// of the existing delay-based stress harness: rather than actually blocking
+/// every thread but the highest-priority one, a thread that isn't currently
+/// the highest priority simply takes the usual stress delay, giving
+/// higher-priority threads a head start at each scheduling point.
+struct PctState {
+    /// The seed this scheduler was created with, so a failing run can report
+    /// it even when [`StressConfig::seed`] wasn't set and one was drawn from
+    /// the OS RNG instead
+    seed: u64,
+    /// Drives both priority assignment and change-point selection; seeded
+    /// from the configured [`StressConfig::seed`] when present so a failing
+    /// seeded run is reproducible, or from the OS RNG otherwise
+    rng: SplitMix64,
+    /// Each thread's priority, assigned the first time it hits a scheduling
+    /// point; higher sorts first
+    priorities: FxHashMap<ThreadId, u64>,
+    /// Global scheduling-point counter, incremented on every call
+    step: u64,
+    /// Step counts, drawn once at scheduler creation, at which the thread
+    /// making that step has its priority demoted below every other known
+    /// thread, forcing a change of who's currently "highest priority"
+    change_points: VecDeque<u64>,
+}
+
+impl PctState {
+    fn new(depth: usize, estimated_steps: u64, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+
+        let mut step = 0u64;
+        let mut change_points = VecDeque::new();
+        for _ in 0..depth.saturating_sub(1) {
+            step += rng.next_range(1, estimated_steps.max(1));
+            change_points.push_back(step);
+        }
+
+        PctState {
+            seed,
+            rng,
+            priorities: FxHashMap::default(),
+            step: 0,
+            change_points,
+        }
+    }
+
+    /// Record one scheduling-point step for `thread_id`, assigning it a
+    /// priority on first sight, and applying a change point if this step hit
+    /// one. Returns whether `thread_id` is (still) the highest-priority
+    /// thread known to the scheduler, i.e. whether it should be let through
+    /// without a backoff delay.
+    fn step(&mut self, thread_id: ThreadId) -> bool {
+        let rng = &mut self.rng;
+        self.priorities
+            .entry(thread_id)
+            .or_insert_with(|| rng.next_u64());
+
+        self.step += 1;
+        if self.change_points.front() == Some(&self.step) {
+            self.change_points.pop_front();
+            let below_everyone = self.priorities.values().copied().min().unwrap_or(0);
+            self.priorities.insert(thread_id, below_everyone.saturating_sub(1));
+        }
+
+        let highest = self.priorities.values().copied().max();
+        self.priorities.get(&thread_id).copied() == highest
+    }
+}
+
 /// Global state for stress testing
 struct StressState {
     /// Track lock relationships
     tracker: ComponentTracker,
     /// Count preemptions per lock
     preemption_counts: FxHashMap<LockId, usize>,
+    /// Per-thread deterministic PRNGs, lazily created the first time a thread
+    /// draws a decision under a seeded [`StressConfig`]
+    thread_rngs: FxHashMap<ThreadId, SplitMix64>,
+    /// When `Some`, a previously recorded decision stream loaded by [`replay`]
+    /// that decisions are drawn from instead of any RNG, forcing the exact
+    /// interleaving that produced the recorded log
+    replay_queues: Option<FxHashMap<ThreadId, VecDeque<Option<u64>>>>,
+    /// Priority-scheduling state for [`StressMode::Pct`], lazily created the
+    /// first time it's needed since it depends on the configured `pct_depth`
+    pct: Option<PctState>,
 }
 
 impl StressState {
@@ -151,6 +328,9 @@ impl StressState {
         StressState {
             tracker: ComponentTracker::new(),
             preemption_counts: FxHashMap::default(),
+            thread_rngs: FxHashMap::default(),
+            replay_queues: None,
+            pct: None,
         }
     }
 
@@ -165,6 +345,52 @@ impl StressState {
     fn track_preemption(&mut self, lock_id: LockId) {
         *self.preemption_counts.entry(lock_id).or_insert(0) += 1;
     }
+
+    /// The deterministic per-thread PRNG for `thread_id`, seeded from
+    /// `seed ^ thread_id` the first time this thread draws a decision
+    fn rng_for_thread(&mut self, thread_id: ThreadId, seed: u64) -> &mut SplitMix64 {
+        self.thread_rngs
+            .entry(thread_id)
+            .or_insert_with(|| SplitMix64::new(seed ^ thread_id as u64))
+    }
+
+    /// Record a PCT scheduling-point step for `thread_id`, lazily creating
+    /// the scheduler (seeded from `seed` if given, otherwise from the OS RNG
+    /// so every run still gets a seed it can report) on first use. Returns
+    /// whether `thread_id` is currently the highest-priority thread known to
+    /// the scheduler.
+    fn pct_step(
+        &mut self,
+        thread_id: ThreadId,
+        depth: usize,
+        estimated_steps: u64,
+        seed: Option<u64>,
+    ) -> bool {
+        let pct = self.pct.get_or_insert_with(|| {
+            PctState::new(depth, estimated_steps, seed.unwrap_or_else(|| rng().random()))
+        });
+        pct.step(thread_id)
+    }
+
+    /// The seed the active PCT scheduler was created with, if one has been
+    /// created yet (i.e. at least one `StressMode::Pct` scheduling point has
+    /// been hit).
+    fn pct_seed(&self) -> Option<u64> {
+        self.pct.as_ref().map(|pct| pct.seed)
+    }
+
+    /// Pop the next replayed decision for `thread_id`, if a decision stream is
+    /// loaded. Returns `Some(None)` if the stream is loaded but exhausted for
+    /// this thread, so callers can tell "no replay loaded" apart from
+    /// "replay loaded, nothing left to draw".
+    fn next_replayed(&mut self, thread_id: ThreadId) -> Option<Option<u64>> {
+        let queues = self.replay_queues.as_mut()?;
+        let decision = queues
+            .get_mut(&thread_id)
+            .and_then(VecDeque::pop_front)
+            .flatten();
+        Some(decision)
+    }
 }
 
 lazy_static::lazy_static! {
@@ -196,27 +422,42 @@ pub fn try_random_preemption(thread_id: ThreadId, lock_id: LockId, held_locks: &
         return None;
     }
 
-    let mut rng = rng();
-
-    if rng.random_range(0..1_000_000) < prob_int {
-        // Track this preemption
-        let mut state = STRESS_STATE.lock();
-        state.track_preemption(lock_id);
-        drop(state); // Release lock before returning
-
-        // Calculate delay
-        let min_us = config.min_delay_us;
-        let max_us = config.max_delay_us;
-        
-        let delay_us = if min_us == max_us {
-            min_us
+    let mut state = STRESS_STATE.lock();
+    let delay_us = if let Some(replayed) = state.next_replayed(thread_id) {
+        replayed
+    } else if let Some(seed) = config.seed {
+        let draw = state.rng_for_thread(thread_id, seed).next_range(0, 999_999);
+        if draw < prob_int {
+            Some(state.rng_for_thread(thread_id, seed).next_range(config.min_delay_us, config.max_delay_us))
         } else {
-            rng.random_range(min_us..=max_us)
-        };
-        Some(delay_us)
+            None
+        }
     } else {
-        None
+        // Release the global lock before drawing from the OS RNG, which doesn't need it.
+        drop(state);
+        let mut rng = rng();
+        let decision = if rng.random_range(0..1_000_000) < prob_int {
+            let min_us = config.min_delay_us;
+            let max_us = config.max_delay_us;
+            Some(if min_us == max_us {
+                min_us
+            } else {
+                rng.random_range(min_us..=max_us)
+            })
+        } else {
+            None
+        };
+        state = STRESS_STATE.lock();
+        decision
+    };
+
+    if delay_us.is_some() {
+        state.track_preemption(lock_id);
     }
+    drop(state);
+
+    record_decision(thread_id, lock_id, delay_us);
+    delay_us
 }
 
 /// Apply component-based delay strategy
@@ -241,24 +482,79 @@ pub fn apply_component_delay(thread_id: ThreadId, lock_id: LockId, held_locks: &
         }
     }
 
-    if should_delay {
-        state.track_preemption(lock_id);
-        drop(state); // Release lock before returning
+    if !should_delay {
+        drop(state);
+        record_decision(thread_id, lock_id, None);
+        return None;
+    }
 
-        // Calculate delay
-        let min_us = config.min_delay_us;
-        let max_us = config.max_delay_us;
-        
+    state.track_preemption(lock_id);
+
+    let min_us = config.min_delay_us;
+    let max_us = config.max_delay_us;
+    let delay_us = if let Some(replayed) = state.next_replayed(thread_id) {
+        replayed.unwrap_or(min_us)
+    } else if let Some(seed) = config.seed {
+        state.rng_for_thread(thread_id, seed).next_range(min_us, max_us)
+    } else {
+        // Release the global lock before drawing from the OS RNG, which doesn't need it.
+        drop(state);
         let mut rng = rng();
-        let delay_us = if min_us == max_us {
+        let delay = if min_us == max_us {
             min_us
         } else {
             rng.random_range(min_us..=max_us)
         };
-        Some(delay_us)
-    } else {
-        None
+        state = STRESS_STATE.lock();
+        delay
+    };
+    drop(state);
+
+    record_decision(thread_id, lock_id, Some(delay_us));
+    Some(delay_us)
+}
+
+/// Record a stress-scheduler decision into the event log so a seeded run can
+/// be reproduced later with [`replay`]. A no-op unless `logging-and-visualization`
+/// is enabled, since there is no log to record into otherwise.
+#[cfg(feature = "logging-and-visualization")]
+fn record_decision(thread_id: ThreadId, lock_id: LockId, delay_us: Option<u64>) {
+    crate::core::logger::log_stress_decision(thread_id, lock_id, delay_us);
+}
+
+#[cfg(not(feature = "logging-and-visualization"))]
+fn record_decision(_thread_id: ThreadId, _lock_id: LockId, _delay_us: Option<u64>) {}
+
+/// Reload a stress-decision stream previously recorded (by a seeded run with
+/// `logging-and-visualization` enabled) from `log_path`, and install it as the
+/// decision source for every subsequent call to [`calculate_stress_delay`].
+///
+/// Decisions are replayed per-thread, in the order they were originally
+/// recorded, so as long as each thread repeats the same sequence of lock
+/// operations, the exact interleaving that produced the log is reproduced
+/// regardless of actual OS thread start order.
+#[cfg(feature = "logging-and-visualization")]
+pub fn replay(log_path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+    use crate::core::logger::LogEntry;
+    use crate::core::types::Events;
+
+    let contents = std::fs::read_to_string(log_path.as_ref())?;
+    let mut queues: FxHashMap<ThreadId, VecDeque<Option<u64>>> = FxHashMap::default();
+
+    for line in contents.lines() {
+        let Ok(entry) = serde_json::from_str::<LogEntry>(line) else {
+            continue;
+        };
+        if entry.event == Events::StressDelay {
+            queues
+                .entry(entry.thread_id)
+                .or_default()
+                .push_back(entry.stress_delay_us);
+        }
     }
+
+    STRESS_STATE.lock().replay_queues = Some(queues);
+    Ok(())
 }
 
 /// Apply stress testing before lock acquisition
@@ -275,5 +571,109 @@ pub fn calculate_stress_delay(
         StressMode::RandomPreemption => try_random_preemption(thread_id, lock_id, held_locks, config),
 
         StressMode::ComponentBased => apply_component_delay(thread_id, lock_id, held_locks, config),
+
+        StressMode::Pct => try_pct_preemption(thread_id, lock_id, held_locks, config),
+    }
+}
+
+/// PCT-style priority scheduling: back off with the usual stress delay
+/// unless this thread is currently the highest-priority one known to the
+/// scheduler, and force a priority change point at the steps chosen when the
+/// scheduler was created. See [`PctState`] for the approximation this makes.
+#[allow(unused_variables)]
+fn try_pct_preemption(thread_id: ThreadId, lock_id: LockId, held_locks: &[LockId], config: &StressConfig) -> Option<u64> {
+    // Only a scheduling point once this thread is actually contending for
+    // something, for the same reason `try_random_preemption` requires it:
+    // backing off a thread that holds nothing yet can't provoke a deadlock,
+    // only desynchronize threads that were about to race into one.
+    if held_locks.is_empty() {
+        return None;
+    }
+
+    let mut state = STRESS_STATE.lock();
+    let is_highest_priority = state.pct_step(
+        thread_id,
+        config.pct_depth,
+        config.pct_estimated_steps,
+        config.seed,
+    );
+    drop(state);
+
+    let delay_us = if is_highest_priority {
+        None
+    } else {
+        let mut state = STRESS_STATE.lock();
+        let delay = if let Some(replayed) = state.next_replayed(thread_id) {
+            replayed.unwrap_or(config.min_delay_us)
+        } else if let Some(seed) = config.seed {
+            state.rng_for_thread(thread_id, seed).next_range(config.min_delay_us, config.max_delay_us)
+        } else {
+            drop(state);
+            let mut rng = rng();
+            let (min_us, max_us) = (config.min_delay_us, config.max_delay_us);
+            let delay = if min_us == max_us {
+                min_us
+            } else {
+                rng.random_range(min_us..=max_us)
+            };
+            state = STRESS_STATE.lock();
+            delay
+        };
+        state.track_preemption(lock_id);
+        Some(delay)
+    };
+
+    record_decision(thread_id, lock_id, delay_us);
+    delay_us
+}
+
+/// The seed behind the currently-active [`StressMode::Pct`] scheduler, if one
+/// has been created yet. Lets a deadlock report include the seed needed to
+/// replay the exact interleaving that produced it, even though the seed may
+/// have been drawn from the OS RNG rather than configured explicitly.
+pub fn active_pct_seed() -> Option<u64> {
+    STRESS_STATE.lock().pct_seed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_mix64_is_deterministic_for_the_same_seed() {
+        let mut a = SplitMix64::new(42);
+        let mut b = SplitMix64::new(42);
+
+        let sequence_a: Vec<u64> = (0..20).map(|_| a.next_u64()).collect();
+        let sequence_b: Vec<u64> = (0..20).map(|_| b.next_u64()).collect();
+
+        assert_eq!(
+            sequence_a, sequence_b,
+            "two PRNGs seeded identically must draw identical sequences"
+        );
+    }
+
+    #[test]
+    fn test_split_mix64_diverges_for_different_seeds() {
+        // This is what makes per-thread streams (seeded with `seed ^ thread_id`)
+        // independent of each other.
+        let mut a = SplitMix64::new(1);
+        let mut b = SplitMix64::new(2);
+
+        let sequence_a: Vec<u64> = (0..20).map(|_| a.next_u64()).collect();
+        let sequence_b: Vec<u64> = (0..20).map(|_| b.next_u64()).collect();
+
+        assert_ne!(sequence_a, sequence_b);
+    }
+
+    #[test]
+    fn test_split_mix64_next_range_stays_within_bounds() {
+        let mut rng = SplitMix64::new(7);
+        for _ in 0..1000 {
+            let value = rng.next_range(100, 200);
+            assert!((100..=200).contains(&value));
+        }
+        // A degenerate range always returns the single valid value.
+        assert_eq!(rng.next_range(50, 50), 50);
     }
 }
@@ -24,11 +24,11 @@
 //! ```
 
 use crate::core::detector;
-use crate::core::types::get_current_thread_id;
+use crate::core::types::{self, DEFAULT_PRIORITY, Priority, get_current_thread_id};
 
 // Re-export all items from std::thread
 pub use std::thread::{
-    AccessError, JoinHandle, LocalKey, Result, Scope, ScopedJoinHandle, Thread, ThreadId,
+    AccessError, LocalKey, Result, Scope, ScopedJoinHandle, Thread, ThreadId,
     available_parallelism, current, panicking, park, park_timeout, sleep, yield_now,
 };
 
@@ -66,6 +66,42 @@ where
     Builder::new().spawn(f).unwrap()
 }
 
+/// Spawns a new thread with deadlock detection and an explicit scheduling
+/// [`Priority`], returning a [`JoinHandle`] for it.
+///
+/// The priority is purely a hint the detector uses to recognize
+/// priority-inversion hazards (a higher-priority thread stuck behind a
+/// lower-priority one that is itself stuck behind a third, higher-priority
+/// thread) via [`DeadlockSource::PriorityInversion`](crate::DeadlockSource::PriorityInversion);
+/// it has no effect on OS thread scheduling. Threads spawned with
+/// [`spawn`] default to [`DEFAULT_PRIORITY`].
+///
+/// # Panics
+///
+/// Panics if the OS fails to create a thread; use [`Builder::priority`] with
+/// [`Builder::spawn`] to recover from such errors.
+///
+/// # Examples
+///
+/// ```rust
+/// use deloxide::thread;
+///
+/// let handle = thread::spawn_with_priority(10, || {
+///     println!("Hello from a high-priority thread!");
+///     42
+/// });
+///
+/// let result = handle.join().unwrap();
+/// assert_eq!(result, 42);
+/// ```
+pub fn spawn_with_priority<F, T>(priority: Priority, f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().priority(priority).spawn(f).unwrap()
+}
+
 /// Thread factory, which can be used in order to configure the properties of a new thread.
 ///
 /// This is a wrapper around [`std::thread::Builder`] that adds deadlock detection
@@ -88,6 +124,7 @@ where
 /// ```
 pub struct Builder {
     inner: std::thread::Builder,
+    priority: Option<Priority>,
 }
 
 impl Builder {
@@ -112,6 +149,7 @@ impl Builder {
     pub fn new() -> Builder {
         Builder {
             inner: std::thread::Builder::new(),
+            priority: None,
         }
     }
 
@@ -152,6 +190,29 @@ impl Builder {
         self
     }
 
+    /// Sets the scheduling [`Priority`] the detector records for the
+    /// thread-to-be, used to recognize priority-inversion hazards. Threads
+    /// built without calling this default to [`DEFAULT_PRIORITY`]. See
+    /// [`spawn_with_priority`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deloxide::thread;
+    ///
+    /// let builder = thread::Builder::new().priority(10);
+    ///
+    /// let handle = builder.spawn(|| {
+    ///     // thread code
+    /// }).unwrap();
+    ///
+    /// handle.join().unwrap();
+    /// ```
+    pub fn priority(mut self, priority: Priority) -> Builder {
+        self.priority = Some(priority);
+        self
+    }
+
     /// Spawns a new thread with deadlock detection by executing the provided
     /// closure on it, returning a [`JoinHandle`] for it.
     ///
@@ -179,11 +240,22 @@ impl Builder {
     {
         // Get the current thread ID (which will be the parent of the new thread)
         let parent_tid = get_current_thread_id();
+        let priority = self.priority.unwrap_or(DEFAULT_PRIORITY);
 
-        self.inner.spawn(move || {
+        // The spawned thread only learns its own deloxide thread ID once it
+        // starts running, but `JoinHandle::join` needs that ID up front (to
+        // register a `joiner -> target` wait-for edge) rather than only once
+        // the join actually completes. Reporting it back over this channel
+        // lets `spawn` block just long enough for the new thread to start -
+        // negligible next to the cost of spawning an OS thread at all.
+        let (tid_tx, tid_rx) = std::sync::mpsc::sync_channel(1);
+
+        let inner = self.inner.spawn(move || {
             let tid = get_current_thread_id();
+            types::set_current_priority(priority);
             // Register thread spawn with parent information
-            detector::thread::on_thread_spawn(tid, Some(parent_tid));
+            detector::thread::on_thread_spawn(tid, Some(parent_tid), priority);
+            let _ = tid_tx.send(tid);
 
             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
 
@@ -194,7 +266,13 @@ impl Builder {
                 Ok(val) => val,
                 Err(payload) => std::panic::resume_unwind(payload),
             }
-        })
+        })?;
+
+        let target = tid_rx
+            .recv()
+            .expect("spawned thread dropped its ID sender before reporting in");
+
+        Ok(JoinHandle { inner, target })
     }
 
     /// Spawns a new scoped thread with deadlock detection by executing the provided
@@ -237,11 +315,13 @@ impl Builder {
     {
         // Get the current thread ID (which will be the parent of the new thread)
         let parent_tid = get_current_thread_id();
+        let priority = self.priority.unwrap_or(DEFAULT_PRIORITY);
 
         self.inner.spawn_scoped(scope, move || {
             let tid = get_current_thread_id();
+            types::set_current_priority(priority);
             // Register thread spawn with parent information
-            detector::thread::on_thread_spawn(tid, Some(parent_tid));
+            detector::thread::on_thread_spawn(tid, Some(parent_tid), priority);
 
             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
 
@@ -262,6 +342,60 @@ impl Default for Builder {
     }
 }
 
+/// An owned permission to join on a thread (block until it terminates), with deadlock detection.
+///
+/// This is a wrapper around [`std::thread::JoinHandle`] that registers a
+/// wait-for dependency with the detector for the duration of
+/// [`join`](JoinHandle::join), so a thread blocked joining another thread
+/// that is itself stuck waiting on a lock the joiner holds is recognized as
+/// a deadlock rather than a plain, if long, wait.
+pub struct JoinHandle<T> {
+    inner: std::thread::JoinHandle<T>,
+    /// The joined thread's deloxide [`types::ThreadId`], reported back by
+    /// the spawned thread itself right after it starts.
+    target: types::ThreadId,
+}
+
+impl<T> JoinHandle<T> {
+    /// Waits for the associated thread to finish, reporting the wait to the
+    /// deadlock detector for its duration.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic on some platforms if a thread attempts to
+    /// join itself or otherwise may create a deadlock with joining threads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use deloxide::thread;
+    ///
+    /// let handle = thread::spawn(|| {
+    ///     42
+    /// });
+    /// assert_eq!(handle.join().unwrap(), 42);
+    /// ```
+    pub fn join(self) -> Result<T> {
+        let joiner = get_current_thread_id();
+        detector::thread::on_thread_join(joiner, self.target);
+
+        let result = self.inner.join();
+
+        detector::thread::on_thread_join_complete(joiner, self.target);
+        result
+    }
+
+    /// Checks if the associated thread has finished running its main function.
+    pub fn is_finished(&self) -> bool {
+        self.inner.is_finished()
+    }
+
+    /// Extracts a handle to the underlying [`Thread`].
+    pub fn thread(&self) -> &Thread {
+        self.inner.thread()
+    }
+}
+
 /// Creates a scope for spawning scoped threads with deadlock detection.
 ///
 /// This is a wrapper around [`std::thread::scope`] that adds deadlock detection
@@ -293,3 +427,113 @@ where
 {
     std::thread::scope(f)
 }
+
+/// Registers the calling thread with the detector, for threads that weren't
+/// started through [`spawn`]/[`Builder::spawn`] - a rayon worker, a tokio
+/// runtime thread, or a raw [`std::thread::spawn`] closure - so wait-for
+/// edges through it are visible to cycle detection.
+///
+/// [`unregister_current`] must be called before the thread actually exits to
+/// avoid leaking a node in the detector's wait-for graph; [`ThreadGuard`] or
+/// [`register_scope`] do this automatically and are usually more convenient.
+///
+/// # Arguments
+/// * `parent` - ID of the thread that's logically responsible for this one
+///   (e.g. the thread that built the pool this thread belongs to), if any
+///
+/// # Returns
+/// This thread's [`types::ThreadId`], the same one
+/// [`get_current_thread_id`] returns
+pub fn register_current(parent: Option<types::ThreadId>) -> types::ThreadId {
+    let tid = get_current_thread_id();
+    detector::thread::on_thread_spawn(tid, parent, DEFAULT_PRIORITY);
+    tid
+}
+
+/// Unregisters the calling thread, mirroring [`register_current`].
+///
+/// Call this right before an externally-spawned thread that called
+/// [`register_current`] actually exits, so its wait-for graph node and
+/// held-lock bookkeeping are cleaned up the same way [`Builder::spawn`]'s
+/// wrapper does for its own threads.
+pub fn unregister_current() {
+    detector::thread::on_thread_exit(get_current_thread_id());
+}
+
+/// RAII guard that registers the calling thread on construction and
+/// unregisters it on drop.
+///
+/// Useful for a long-lived externally-spawned thread (a rayon or tokio
+/// worker) that can't easily wrap its whole body in a closure: holding a
+/// `ThreadGuard` for the thread's lifetime has the same effect as bracketing
+/// it with [`register_current`]/[`unregister_current`].
+///
+/// # Examples
+///
+/// ```rust
+/// use deloxide::thread::ThreadGuard;
+///
+/// std::thread::spawn(|| {
+///     let _guard = ThreadGuard::new(None);
+///     // ... this thread's work, tracked by the detector ...
+/// });
+/// ```
+pub struct ThreadGuard {
+    thread_id: types::ThreadId,
+}
+
+impl ThreadGuard {
+    /// Registers the calling thread and returns a guard that will
+    /// unregister it when dropped.
+    ///
+    /// # Arguments
+    /// * `parent` - ID of the thread that's logically responsible for this
+    ///   one, if any
+    pub fn new(parent: Option<types::ThreadId>) -> Self {
+        ThreadGuard {
+            thread_id: register_current(parent),
+        }
+    }
+
+    /// This thread's [`types::ThreadId`].
+    pub fn thread_id(&self) -> types::ThreadId {
+        self.thread_id
+    }
+}
+
+impl Drop for ThreadGuard {
+    fn drop(&mut self) {
+        detector::thread::on_thread_exit(self.thread_id);
+    }
+}
+
+/// Runs `f` on the calling thread with detector registration bracketing it.
+///
+/// Meant to be called from inside a work-stealing pool's own spawn handler -
+/// e.g. rayon's `ThreadPoolBuilder::spawn_handler`, which hands you a
+/// closure to run on its raw OS thread - so every pool worker is tracked from
+/// first use and cleaned up on teardown, without deloxide depending on the
+/// pool's crate:
+///
+/// ```rust,ignore
+/// rayon::ThreadPoolBuilder::new()
+///     .spawn_handler(|thread| {
+///         std::thread::Builder::new().spawn(move || {
+///             deloxide::thread::register_scope(None, || thread.run());
+///         })?;
+///         Ok(())
+///     })
+///     .build_global()?;
+/// ```
+///
+/// # Arguments
+/// * `parent` - ID of the thread that's logically responsible for this one,
+///   if any
+/// * `f` - The work to run on this thread, with registration already in effect
+pub fn register_scope<F, T>(parent: Option<types::ThreadId>, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let _guard = ThreadGuard::new(parent);
+    f()
+}
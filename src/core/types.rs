@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Thread identifier type
@@ -6,6 +7,18 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 /// Uniquely identifies a thread in the application.
 pub type ThreadId = usize;
 
+/// Logical async task identifier
+///
+/// Shares its representation and the detector's "owner" identity space with
+/// [`ThreadId`] - `acquire_slow`, `complete_acquire` and `release_mutex` only
+/// ever see an opaque id and never call [`get_current_thread_id`] themselves,
+/// so a caller can drive them with a task id instead. Unlike a [`ThreadId`],
+/// which is assigned once per OS thread and never changes, a [`TaskId`] is
+/// assigned once per lock-acquisition future (see `crate::AsyncMutex`), so
+/// ownership recorded under it stays valid even if the task is resumed on a
+/// different OS thread after an `.await`.
+pub type TaskId = ThreadId;
+
 // Global counter for assigning unique thread IDs
 static THREAD_ID_COUNTER: AtomicUsize = AtomicUsize::new(1);
 
@@ -23,6 +36,36 @@ pub fn get_current_thread_id() -> ThreadId {
     THREAD_ID.with(|&id| id)
 }
 
+/// Scheduling priority assigned to a tracked thread
+///
+/// Higher values mean higher priority. Purely a hint the detector uses to
+/// recognize priority-inversion hazards (see [`crate::core::Detector`]'s
+/// wait-for bookkeeping in `acquire_slow`); it has no effect on the OS
+/// scheduler.
+pub type Priority = u8;
+
+/// The priority assigned to a thread spawned without an explicit one (e.g.
+/// via ordinary `thread::spawn` rather than `thread::spawn_with_priority`)
+pub const DEFAULT_PRIORITY: Priority = 0;
+
+thread_local! {
+    static THREAD_PRIORITY: Cell<Priority> = const { Cell::new(DEFAULT_PRIORITY) };
+}
+
+/// Get the current thread's priority, as set by
+/// `crate::thread::spawn_with_priority` (or [`DEFAULT_PRIORITY`] otherwise)
+pub fn get_current_priority() -> Priority {
+    THREAD_PRIORITY.with(Cell::get)
+}
+
+/// Set the current thread's priority
+///
+/// Called once, right after a thread spawned via
+/// `crate::thread::spawn_with_priority` starts running.
+pub fn set_current_priority(priority: Priority) {
+    THREAD_PRIORITY.with(|p| p.set(priority));
+}
+
 /// Lock identifier type
 ///
 /// Uniquely identifies a mutex/lock in the application. Each Mutex
@@ -36,6 +79,14 @@ pub type LockId = usize;
 /// simplicity in logging systems.
 pub type CondvarId = LockId;
 
+/// Process identifier type (used by the `distributed` feature)
+///
+/// Uniquely identifies a Deloxide process participating in cross-process
+/// deadlock detection. Combined with a [`ThreadId`], it forms the composite
+/// node key the distributed coordinator uses in its merged wait-for graph.
+#[cfg(feature = "distributed")]
+pub type ProcessId = u64;
+
 /// Represents the type of thread/lock event that occurred
 ///
 /// These events are used to track the lifecycle of threads and locks
@@ -67,14 +118,73 @@ pub enum Events {
     /// Thread released a RwLock (write access)
     RwWriteReleased,
 
+    /// Thread is attempting to acquire an upgradable read lock on a RwLock
+    RwUpgradableAttempt,
+    /// Thread successfully acquired an upgradable read lock
+    RwUpgradableAcquired,
+    /// Thread released an upgradable read lock without upgrading
+    RwUpgradableReleased,
+    /// Thread's upgradable read lock was upgraded to a write lock
+    RwUpgradeAcquired,
+    /// Thread's write lock was downgraded to an upgradable read lock, without
+    /// releasing shared access
+    RwDowngradedToUpgradable,
+
+    /// A new Condvar is spawned
+    CondvarSpawn,
+    /// The Condvar is exited/dropped
+    CondvarExit,
     /// Thread is beginning to wait on a condition variable
     CondvarWaitBegin,
     /// Thread finished waiting on a condition variable (mutex reacquired)
     CondvarWaitEnd,
+    /// Thread's wait on a condition variable expired before it was notified
+    CondvarWaitTimedOut,
     /// A condition variable notified one waiter
     CondvarNotifyOne,
     /// A condition variable notified all waiters
     CondvarNotifyAll,
+
+    /// A thread gave up on a time-bounded lock acquisition (`lock_for`/`lock_until`)
+    /// because the deadline passed before the lock became available
+    AcquireTimedOut,
+
+    /// A stress-scheduler decision was drawn before a lock operation (see
+    /// [`crate::core::stress`]). `lock_id` is the lock the draw pertains to and
+    /// the chosen delay, if any, is carried in [`crate::core::logger::LogEntry::stress_delay_us`].
+    /// Recorded so a seeded run can be reproduced later with `stress::replay`.
+    StressDelay,
+
+    /// A mutex guard was dropped during an unwind, poisoning the mutex
+    MutexPoisoned,
+    /// An RwLock guard was dropped during an unwind, poisoning the lock
+    RwPoisoned,
+
+    /// A [`crate::FairMutex`] acquisition waited past the lock's configured
+    /// fairness threshold. Not a deadlock - the thread did eventually acquire
+    /// the lock - but a sign of starvation under contention worth surfacing
+    /// to a reader of the log. The wait time is carried in
+    /// [`crate::core::logger::LogEntry::wait_us`].
+    MutexStarvation,
+
+    /// A new Barrier is spawned
+    BarrierSpawn,
+    /// The Barrier is exited/dropped
+    BarrierExit,
+    /// Thread is beginning to wait at a barrier
+    BarrierWaitBegin,
+    /// Thread finished waiting at a barrier (the barrier filled)
+    BarrierWaitEnd,
+
+    /// Thread is beginning to block on `JoinHandle::join` for another thread
+    JoinBegin,
+    /// Thread's `JoinHandle::join` call returned
+    JoinEnd,
+
+    /// Synthetic entry emitted by [`crate::core::logger::EventLogger::with_capacity`]'s
+    /// writer thread when it drains a channel that dropped events under backpressure.
+    /// The number of events dropped is carried in [`crate::core::logger::LogEntry::discarded_count`].
+    LogOverflow,
 }
 
 /// Represents the type of notification sent to a condition variable
@@ -89,6 +199,89 @@ pub enum NotifyKind {
     All,
 }
 
+/// Distinguishes how a `DeadlockInfo` was produced
+///
+/// A deadlock can be reported either because the wait-for graph found an
+/// actual cycle of blocked threads, or (with the `lock-order-graph` feature)
+/// because a thread's lock acquisition order matches a pattern known to be
+/// able to deadlock, even though no thread is blocked yet.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DeadlockSource {
+    /// A real cycle was found in the wait-for graph; threads are actually blocked
+    WaitForGraph,
+    /// A dangerous lock ordering pattern was detected heuristically
+    LockOrderViolation,
+    /// A thread requested a lock it already holds, on a non-reentrant
+    /// primitive (recursive acquisition is only sound on [`crate::ReentrantMutex`]).
+    /// Detected synchronously at the acquisition call site rather than via the
+    /// wait-for graph, since a thread can never appear in a cycle with only
+    /// itself once the common-held-lock false-positive filter is applied.
+    SelfDeadlock,
+    /// Found by the periodic background watchdog (see
+    /// [`crate::Deloxide::with_watchdog`]) rather than reactively at a lock
+    /// attempt. Covers both a wait-for cycle the event-driven checks missed
+    /// and threads simply stalled past the configured threshold (e.g. blocked
+    /// on a condvar that never got notified), which the at-attempt checks
+    /// can't see since no lock-attempt event ever fires for them.
+    Watchdog,
+    /// A thread panicked while still holding a lock that other threads were
+    /// blocked waiting for. Detected synchronously when the panicking
+    /// guard's `Drop` runs, not via the wait-for graph: the panicking thread
+    /// was never waiting on anyone, so its death can never complete a cycle,
+    /// and without this report its waiters would have nothing to ever catch
+    /// the hang.
+    AbandonedLock,
+    /// A classic unbounded priority-inversion hazard: a higher-priority
+    /// thread is blocked on a lock held by a strictly lower-priority
+    /// thread, which is itself blocked behind a third thread of higher
+    /// priority than it. Not a deadlock - every thread involved can still
+    /// make progress - but a real liveness hazard, since the low-priority
+    /// owner (and therefore the high-priority waiter) can be starved
+    /// indefinitely by ordinary scheduling preference for the third
+    /// thread. See [`DeadlockInfo::priority_chain`].
+    PriorityInversion,
+    /// A barrier that can provably never fill: every thread still alive has
+    /// already arrived at it, yet fewer than the required number of parties
+    /// have, so no further arrival is possible (a party died, took a
+    /// different branch, or was never spawned). Detected synchronously, at
+    /// the arrival or thread-exit that makes the shortfall provable, rather
+    /// than via the wait-for graph: the missing parties were never blocked
+    /// on anyone, so there's no cycle to find. See
+    /// [`DeadlockInfo::thread_cycle`] for the arrived threads and
+    /// [`DeadlockInfo::barrier_missing`] for how many are still short.
+    BarrierStarvation,
+    /// A thread parked itself on a condvar while still holding a lock that
+    /// some other thread is (transitively) blocked waiting for. Detected
+    /// synchronously when the wait begins, rather than via the wait-for
+    /// graph: the newly-parked thread releases its condvar mutex but holds
+    /// no wait-for edge of its own while asleep, so nothing would otherwise
+    /// notice that the only way forward - a notify from that other,
+    /// already-stuck thread - can never arrive.
+    CondvarHeldLock,
+    /// A writer has been parked in `RwLock::write`'s queue past the
+    /// configured starvation threshold while readers kept cycling through
+    /// the lock without it ever becoming free for long enough for the
+    /// writer to take its turn. Not a deadlock - every reader still makes
+    /// progress - but a liveness hazard the wait-for graph can't see, since
+    /// the writer genuinely isn't stuck in a cycle, just perpetually
+    /// preempted. Detected on demand by [`crate::core::Detector::check_writer_starvation`]
+    /// rather than reactively. See [`DeadlockInfo::thread_cycle`] for the
+    /// starved writer followed by the readers currently holding the lock,
+    /// and [`DeadlockInfo::stalled_threads`] for how long it's been waiting.
+    WriterStarvation,
+    /// Every currently-live thread is blocked - parked on a condvar or stuck
+    /// acquiring a lock - and at least one of the condvar waiters has no
+    /// timeout, so the `notify` it's waiting for can now provably never be
+    /// called by anyone. Detected synchronously, the moment the last thread
+    /// blocks, rather than via the wait-for graph: a parked condvar waiter
+    /// holds no wait-for edge of its own, so there's no cycle for the normal
+    /// check to find. See [`DeadlockInfo::thread_cycle`] for every blocked
+    /// thread and [`DeadlockInfo::stalled_threads`] for which of them are
+    /// condvar waiters and whether each can still self-recover via its own
+    /// `wait_timeout`.
+    CondvarNotificationStarvation,
+}
+
 /// Represents the result of deadlock detection
 ///
 /// This structure contains detailed information about a detected deadlock,
@@ -97,6 +290,10 @@ pub enum NotifyKind {
 /// be used to diagnose the root cause of the deadlock.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeadlockInfo {
+    /// Whether this deadlock is a confirmed wait-for cycle or a heuristic
+    /// lock order violation
+    pub source: DeadlockSource,
+
     /// List of threads involved in the deadlock cycle
     ///
     /// This is the ordered list of threads that form a cycle in the wait-for graph.
@@ -110,10 +307,220 @@ pub struct DeadlockInfo {
     /// the cycle is waiting to acquire. Each tuple is (thread_id, lock_id).
     pub thread_waiting_for_locks: Vec<(ThreadId, LockId)>,
 
+    /// For a `LockOrderViolation`, the cycle of locks whose acquisition order
+    /// triggered the heuristic. `None` for a real `WaitForGraph` deadlock.
+    pub lock_order_cycle: Option<Vec<LockId>>,
+
     /// Timestamp when the deadlock was detected
     ///
     /// ISO-8601 formatted a timestamp indicating when the deadlock was detected.
     pub timestamp: String,
+
+    /// Reserved for a future asynchronous re-verification handshake; always
+    /// `None` today.
+    pub verification_request: Option<String>,
+
+    /// For a deadlock whose cycle spans multiple processes, the full cycle as
+    /// `(process_id, thread_id)` pairs in cycle order. `None` for a purely
+    /// local deadlock. Only ever populated when the `distributed` feature is
+    /// enabled and a `.with_coordinator(..)`/`.as_coordinator(..)` connection
+    /// reports a cross-process cycle.
+    #[cfg(feature = "distributed")]
+    pub distributed_cycle: Option<Vec<(ProcessId, ThreadId)>>,
+
+    /// Per-thread lock acquisition sites for each thread in `thread_cycle`,
+    /// if backtrace capture was enabled with `Deloxide::with_backtraces()`.
+    /// Empty if backtrace capture was never enabled.
+    pub lock_sites: Vec<ThreadLockSite>,
+
+    /// For a `LockOrderViolation`, where each edge in `lock_order_cycle` was
+    /// established and, if recorded, where its conflicting reverse ordering
+    /// was established. Empty for any other `DeadlockSource`, or if
+    /// backtrace capture was never enabled.
+    pub lock_order_sites: Vec<LockOrderEdgeSite>,
+
+    /// How long each currently-blocked thread the watchdog observed has been
+    /// waiting, for `DeadlockSource::Watchdog` reports. Empty for deadlocks
+    /// detected any other way.
+    pub stalled_threads: Vec<ThreadStall>,
+
+    /// For `DeadlockSource::AbandonedLock`, the panic message of the thread
+    /// that died while holding the lock named in `thread_waiting_for_locks`,
+    /// if it could be recovered. `None` for any other `DeadlockSource`.
+    pub panic_message: Option<String>,
+
+    /// For `DeadlockSource::PriorityInversion`, the hazard chain as
+    /// `(thread_id, priority)` pairs, from the high-priority thread that's
+    /// blocked down to the low-priority owner down to the third thread
+    /// starving it. Empty for any other `DeadlockSource`.
+    pub priority_chain: Vec<(ThreadId, Priority)>,
+
+    /// For `DeadlockSource::BarrierStarvation`, how many more parties the
+    /// barrier named by `thread_cycle`'s arrived threads is still short of.
+    /// `None` for any other `DeadlockSource`.
+    pub barrier_missing: Option<usize>,
+
+    /// Each `thread_cycle` thread's vector clock - as `(other_thread, count)`
+    /// pairs - at the time its Mutex or RwLock wait-for edge was last
+    /// involved in cycle detection, reflecting every other thread's lock
+    /// releases it has causally observed so far. Lets a report reconstruct
+    /// whether the operations in the cycle were actually concurrent or
+    /// whether one happened-before another, the way Miri's `VClock` does for
+    /// its sync primitives. Empty unless the cycle was found via a Mutex or
+    /// RwLock acquire/attempt check; any other `DeadlockSource` doesn't track
+    /// clocks at all.
+    pub thread_vector_clocks: Vec<(ThreadId, Vec<(ThreadId, u64)>)>,
+
+    /// The stress-testing seed in effect when this deadlock was found -
+    /// either an explicit [`crate::core::StressConfig::seed`] (set directly
+    /// or via [`crate::Deloxide::with_fuzzing`]) or, for `StressMode::Pct`,
+    /// one auto-assigned from the OS RNG - so the exact run can be
+    /// reproduced later with `stress::replay`. `None` if stress testing
+    /// isn't seeded.
+    #[cfg(feature = "stress-test")]
+    pub stress_seed: Option<u64>,
+}
+
+/// How long the background watchdog has continuously observed a thread
+/// blocked waiting for a lock or condvar notification
+///
+/// Populated on a `DeadlockInfo` produced by [`crate::Deloxide::with_watchdog`],
+/// [`crate::core::Detector::check_writer_starvation`], or a synchronous
+/// [`DeadlockSource::CondvarNotificationStarvation`] report; see
+/// [`DeadlockInfo::stalled_threads`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ThreadStall {
+    /// The stalled thread
+    pub thread_id: ThreadId,
+    /// How long the thread has been continuously blocked, in milliseconds.
+    /// `0` for a stall detected synchronously rather than by the watchdog's
+    /// periodic polling, since no elapsed time was tracked for it.
+    pub blocked_ms: u64,
+    /// `true` if the thread is parked in [`crate::Condvar::wait`] (or a
+    /// variant of it) rather than blocked acquiring a lock - a lost-wakeup
+    /// stall rather than a lock-cycle one, so callers can tell the two
+    /// apart instead of treating every stall as a contended lock.
+    pub blocked_on_condvar: bool,
+    /// `true` if this thread is blocked in [`crate::Condvar::wait_timeout`]
+    /// (or `wait_timeout_while`) and will therefore wake on its own once the
+    /// deadline passes, even if no `notify` ever arrives. Always `false` for
+    /// a thread not blocked on a condvar at all.
+    pub recoverable: bool,
+}
+
+/// Where a thread in a deadlock cycle acquired the lock it holds and
+/// requested the lock it's waiting for
+///
+/// Only populated when backtrace capture is enabled; see
+/// [`crate::core::stacktrace`] and `Deloxide::with_backtraces()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadLockSite {
+    /// The thread this site describes
+    pub thread_id: ThreadId,
+    /// The lock this thread currently holds within the cycle, if any
+    pub held_lock: Option<LockId>,
+    /// Source location where `held_lock` was acquired
+    pub held_at: Option<String>,
+    /// Full call stack, most recent frame first, at the point `held_lock`
+    /// was acquired - every frame outside Deloxide's own machinery, not just
+    /// `held_at`'s single top frame
+    pub held_backtrace: Option<String>,
+    /// The lock this thread is waiting to acquire
+    pub waiting_lock: LockId,
+    /// Source location where the attempt to acquire `waiting_lock` began
+    pub waiting_at: Option<String>,
+    /// Full call stack, most recent frame first, at the point the attempt to
+    /// acquire `waiting_lock` began - every frame outside Deloxide's own
+    /// machinery, not just `waiting_at`'s single top frame
+    pub waiting_backtrace: Option<String>,
+    /// Whether `waiting_lock`'s last holder panicked while holding it,
+    /// leaving it poisoned. Lets a visualized deadlock distinguish a cycle
+    /// that will never resolve on its own from one where a holder died
+    /// mid-operation, which is a common real cause of hangs.
+    pub waiting_lock_poisoned: bool,
+}
+
+/// Where the two conflicting lock-acquisition orderings behind one edge of a
+/// `LockOrderViolation` cycle were each first established
+///
+/// Only populated when backtrace capture is enabled; see
+/// [`crate::core::stacktrace`] and `Deloxide::with_backtraces()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockOrderEdgeSite {
+    /// Lock acquired first in this ordering
+    pub before: LockId,
+    /// Lock acquired after `before` in this ordering
+    pub after: LockId,
+    /// Source location where `before -> after` was first recorded
+    pub site: Option<String>,
+    /// Source location where the conflicting `after -> before` ordering was
+    /// first recorded, if the graph has ever seen that direction too
+    pub conflicting_site: Option<String>,
+}
+
+/// How the detector reacts when [`Detector::check_lock_order_violation`]
+/// (via `complete_acquire`) finds a lock ordering violation, i.e. a
+/// [`DeadlockInfo`] with `source: DeadlockSource::LockOrderViolation`
+///
+/// Mirrors the `tracing-mutex` crate's `DebugMutex`, which panics the moment
+/// a cyclic lock acquisition order is created rather than waiting for
+/// threads to actually block on it. `LogOnly` keeps Deloxide's default
+/// behavior of reporting the violation through the deadlock callback/log and
+/// letting the offending thread's `lock()` call proceed.
+///
+/// [`Detector::check_lock_order_violation`]: crate::core::Detector
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum LockOrderViolationPolicy {
+    /// Report the violation (callback + log) and let the offending thread's
+    /// `lock()` call proceed; the default
+    #[default]
+    LogOnly,
+    /// Panic the offending thread at its `lock()` call site with the cycle
+    /// and, if `Deloxide::with_backtraces()` is enabled, the conflicting
+    /// acquisition sites
+    Panic,
+    /// Like `Panic`, but calls `std::process::abort()` instead of
+    /// unwinding - for a violation inside a lock held across an FFI boundary
+    /// that can't tolerate unwinding
+    Abort,
+}
+
+/// Which readers a pending `RwLock` writer blocks, controlling whether
+/// [`Detector::attempt_read`](crate::core::Detector) models a new reader as
+/// waiting on a writer that's merely queued (not yet holding the lock)
+///
+/// parking_lot's `RwLock` - the implementation Deloxide's own
+/// `crate::RwLock` wraps - is task-fair: a new reader blocks behind any
+/// writer that's already waiting, so a writer can't be starved by a steady
+/// stream of readers. A naive reader-preferring model misses deadlocks that
+/// only occur under that fairness rule, but also over-reports if the lock
+/// implementation actually being used doesn't have it, so this is
+/// configurable via [`crate::Deloxide::with_rwlock_fairness`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum RwLockFairness {
+    /// A new reader is only blocked by the current writer (if any); a
+    /// writer merely waiting to acquire never blocks a reader. Matches a
+    /// reader-preferring `RwLock` implementation.
+    ReaderPreferring,
+    /// A new reader is blocked by the current writer and by any writer
+    /// already queued behind it, modeling task-fair starvation avoidance;
+    /// the default, matching parking_lot's `RwLock`
+    #[default]
+    WriterPreferring,
+}
+
+/// Snapshot of who, if anyone, currently holds a lock
+///
+/// Returned by query methods like `Mutex::held_state()` so callers can
+/// assert lock discipline in tests without reaching into detector internals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockHeldState {
+    /// The calling thread currently holds this lock
+    HeldByCurrentThread,
+    /// Another thread currently holds this lock
+    HeldByOtherThread(ThreadId),
+    /// No thread currently holds this lock
+    NotHeld,
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use crate::core::locks::condvar::Condvar;
 use crate::core::locks::mutex::MutexGuard;
-use crate::core::detector::condvar::on_condvar_create;
+use crate::core::detector::condvar::create_condvar;
 use crate::ffi::FFI_GUARD;
 use std::cell::RefCell;
 use std::ffi::{c_int, c_ulong, c_void};
@@ -51,7 +51,7 @@ pub unsafe extern "C" fn deloxide_create_condvar_with_creator(
     // Register the specified thread as the creator
     // Note: The condvar detector doesn't currently support custom creator threads
     // so we just create the condvar normally
-    on_condvar_create(condvar.id());
+    create_condvar(condvar.id());
 
     Box::into_raw(condvar) as *mut c_void
 }
@@ -119,7 +119,7 @@ pub unsafe extern "C" fn deloxide_condvar_wait(
     });
 
     // Perform the wait operation
-    condvar_ref.wait(&mut guard);
+    let result = condvar_ref.wait(&mut guard);
 
     // Store the guard back in thread-local storage for this mutex
     FFI_GUARD.with(|map| {
@@ -131,7 +131,10 @@ pub unsafe extern "C" fn deloxide_condvar_wait(
         *cell.borrow_mut() = None;
     });
 
-    0
+    match result {
+        Ok(()) => 0,
+        Err(_poisoned) => -4,
+    }
 }
 
 /// Wait on a condition variable with a timeout.
@@ -184,7 +187,7 @@ pub unsafe extern "C" fn deloxide_condvar_wait_timeout(
 
     // Perform the wait operation with timeout
     let timeout = Duration::from_millis(timeout_ms as u64);
-    let timed_out = condvar_ref.wait_timeout(&mut guard, timeout);
+    let result = condvar_ref.wait_timeout(&mut guard, timeout);
 
     // Store the guard back in thread-local storage for this mutex
     FFI_GUARD.with(|map| {
@@ -196,11 +199,175 @@ pub unsafe extern "C" fn deloxide_condvar_wait_timeout(
         *cell.borrow_mut() = None;
     });
 
-    if timed_out {
-        1 // Timeout
-    } else {
-        0 // Success
+    match result {
+        Ok(true) => 1,   // Timeout
+        Ok(false) => 0,  // Success
+        Err(_poisoned) => -4,
+    }
+}
+
+/// Wait on a condition variable while a C predicate holds true.
+///
+/// Equivalent to calling `deloxide_condvar_wait` in a loop that re-checks
+/// `predicate` after each wakeup, except the re-wait is tracked by the
+/// detector on every iteration rather than only the caller's final, manual
+/// re-entry. `predicate` is called with the re-acquired mutex held and should
+/// return non-zero while the thread should keep waiting.
+///
+/// # Arguments
+/// * `condvar` - Pointer to a condition variable created with `deloxide_create_condvar`.
+/// * `mutex` - Pointer to a mutex that is currently locked by this thread.
+/// * `predicate` - Called with `user_data` after each wakeup; return non-zero to keep waiting.
+/// * `user_data` - Opaque pointer forwarded to `predicate` on every call.
+///
+/// # Returns
+/// * 0 on success (the predicate returned zero)
+/// * -1 if condvar is NULL
+/// * -2 if mutex is NULL
+/// * -3 if mutex is not currently held by this thread
+/// * -4 if the mutex was poisoned by a panic while this thread was waiting
+///
+/// # Safety
+/// - Both pointers must be valid and created with appropriate deloxide functions.
+/// - The mutex must be currently locked by the calling thread.
+/// - `predicate` must be safe to call with `user_data` from this thread at any point during the wait.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_condvar_wait_while(
+    condvar: *mut c_void,
+    mutex: *mut c_void,
+    predicate: extern "C" fn(*mut c_void) -> c_int,
+    user_data: *mut c_void,
+) -> c_int {
+    if condvar.is_null() {
+        return -1;
+    }
+    if mutex.is_null() {
+        return -2;
+    }
+
+    let condvar_ref = unsafe { &*(condvar as *const Condvar) };
+
+    let mut guard = match FFI_GUARD.with(|map| map.borrow_mut().remove(&mutex)) {
+        Some(guard) => guard,
+        None => return -3, // Mutex not held by this thread
+    };
+
+    let mut poisoned = false;
+
+    while predicate(user_data) != 0 {
+        FFI_CONDVAR_WAIT_STATE.with(|cell| {
+            *cell.borrow_mut() = Some((condvar, mutex));
+        });
+
+        let result = condvar_ref.wait(&mut guard);
+
+        FFI_CONDVAR_WAIT_STATE.with(|cell| {
+            *cell.borrow_mut() = None;
+        });
+
+        if result.is_err() {
+            poisoned = true;
+            break;
+        }
+    }
+
+    FFI_GUARD.with(|map| {
+        map.borrow_mut().insert(mutex, guard);
+    });
+
+    if poisoned { -4 } else { 0 }
+}
+
+/// Wait on a condition variable while a C predicate holds true, up to a total deadline.
+///
+/// Like `deloxide_condvar_wait_while`, but `timeout_ms` bounds the *total* time
+/// spent waiting across all iterations rather than each individual wakeup: the
+/// remaining time is recomputed before every re-wait, and the function gives
+/// up with the timeout code if the deadline passes before `predicate` returns zero.
+///
+/// # Arguments
+/// * `condvar` - Pointer to a condition variable created with `deloxide_create_condvar`.
+/// * `mutex` - Pointer to a mutex that is currently locked by this thread.
+/// * `predicate` - Called with `user_data` after each wakeup; return non-zero to keep waiting.
+/// * `user_data` - Opaque pointer forwarded to `predicate` on every call.
+/// * `timeout_ms` - Total time budget, in milliseconds, across every re-wait.
+///
+/// # Returns
+/// * 0 on success (the predicate returned zero)
+/// * 1 if the deadline passed before the predicate returned zero
+/// * -1 if condvar is NULL
+/// * -2 if mutex is NULL
+/// * -3 if mutex is not currently held by this thread
+/// * -4 if the mutex was poisoned by a panic while this thread was waiting
+///
+/// # Safety
+/// - Both pointers must be valid and created with appropriate deloxide functions.
+/// - The mutex must be currently locked by the calling thread.
+/// - `predicate` must be safe to call with `user_data` from this thread at any point during the wait.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_condvar_wait_timeout_while(
+    condvar: *mut c_void,
+    mutex: *mut c_void,
+    predicate: extern "C" fn(*mut c_void) -> c_int,
+    user_data: *mut c_void,
+    timeout_ms: c_ulong,
+) -> c_int {
+    if condvar.is_null() {
+        return -1;
+    }
+    if mutex.is_null() {
+        return -2;
+    }
+
+    let condvar_ref = unsafe { &*(condvar as *const Condvar) };
+
+    let mut guard = match FFI_GUARD.with(|map| map.borrow_mut().remove(&mutex)) {
+        Some(guard) => guard,
+        None => return -3, // Mutex not held by this thread
+    };
+
+    let deadline = Duration::from_millis(timeout_ms as u64);
+    let start = std::time::Instant::now();
+    let mut timed_out = false;
+
+    while predicate(user_data) != 0 {
+        let elapsed = start.elapsed();
+        if elapsed >= deadline {
+            timed_out = true;
+            break;
+        }
+        let remaining = deadline - elapsed;
+
+        FFI_CONDVAR_WAIT_STATE.with(|cell| {
+            *cell.borrow_mut() = Some((condvar, mutex));
+        });
+
+        let result = condvar_ref.wait_timeout(&mut guard, remaining);
+
+        FFI_CONDVAR_WAIT_STATE.with(|cell| {
+            *cell.borrow_mut() = None;
+        });
+
+        match result {
+            Ok(true) => {
+                timed_out = true;
+                break;
+            }
+            Ok(false) => {}
+            Err(_poisoned) => {
+                FFI_GUARD.with(|map| {
+                    map.borrow_mut().insert(mutex, guard);
+                });
+                return -4;
+            }
+        }
     }
+
+    FFI_GUARD.with(|map| {
+        map.borrow_mut().insert(mutex, guard);
+    });
+
+    if timed_out { 1 } else { 0 }
 }
 
 /// Signal one thread waiting on the condition variable.
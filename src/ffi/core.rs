@@ -1,16 +1,114 @@
 use crate::core::detector;
 #[cfg(feature = "logging-and-visualization")]
 use crate::core::logger;
-use crate::ffi::{DEADLOCK_CALLBACK, DEADLOCK_DETECTED, INITIALIZED, IS_LOGGING_ENABLED};
+use crate::core::stacktrace;
+use crate::ffi::{
+    BACKTRACE_CAPTURE_ENABLED, DEADLOCK_CALLBACK, DEADLOCK_DETECTED, INITIALIZED,
+    IS_LOGGING_ENABLED, SCAN_INTERVAL_MS,
+};
+#[cfg(feature = "lock-order-graph")]
+use crate::ffi::LOCK_ORDER_CHECK_ENABLED;
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int};
 use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 #[cfg(feature = "stress-test")]
 use crate::StressMode;
 #[cfg(feature = "stress-test")]
 use crate::ffi::{STRESS_CONFIG, STRESS_MODE};
 
+/// Enable lock-acquisition backtrace capture (call before `deloxide_init`).
+///
+/// When enabled, a backtrace is captured every time a thread starts waiting
+/// for a lock, and the acquisition site for every thread in a reported
+/// deadlock's cycle is attached to the `lock_sites` field of the JSON emitted
+/// to the callback passed to `deloxide_init`. Mirrors `Deloxide::with_backtraces()`
+/// on the Rust side, which `deloxide_init` itself has no equivalent of since it
+/// builds a detector configuration directly instead of going through that builder.
+///
+/// Off by default, since capturing a backtrace on every lock operation is expensive.
+///
+/// # Returns
+/// * `0` on success
+/// * `1` if the detector is already initialized
+///
+/// # Safety
+/// This function writes to a mutable global static and should be called before initialization.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_enable_backtraces() -> c_int {
+    if INITIALIZED.load(Ordering::SeqCst) {
+        return 1; // Already initialized
+    }
+
+    BACKTRACE_CAPTURE_ENABLED.store(true, Ordering::SeqCst);
+    0
+}
+
+/// Configure a background watchdog that periodically scans for deadlocks
+/// (call before `deloxide_init`).
+///
+/// The detector normally only checks for cycles reactively, at the moment a
+/// thread attempts a tracked lock operation, which misses a deadlock formed
+/// entirely among already-blocked threads. When `interval_ms` is non-zero,
+/// `deloxide_init` spawns a background thread that wakes every `interval_ms`
+/// milliseconds, scans the whole wait-for graph for a cycle, and reports it
+/// through the same callback and `deloxide_is_deadlock_detected()` flag as a
+/// reactively-detected deadlock. A thread it has observed blocked for at
+/// least `interval_ms` (also used as the stall threshold) is reported the same
+/// way, even without a cycle. Mirrors `Deloxide::with_watchdog()` on the
+/// Rust side, which `deloxide_init` itself has no equivalent of since it
+/// builds a detector configuration directly instead of going through that
+/// builder.
+///
+/// Passing `0` disables the watchdog (the default).
+///
+/// # Returns
+/// * `0` on success
+/// * `1` if the detector is already initialized
+///
+/// # Safety
+/// This function writes to a mutable global static and should be called before initialization.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_set_scan_interval(interval_ms: u64) -> c_int {
+    if INITIALIZED.load(Ordering::SeqCst) {
+        return 1; // Already initialized
+    }
+
+    SCAN_INTERVAL_MS.store(interval_ms, Ordering::SeqCst);
+    0
+}
+
+/// Enable lock-order-inversion checking (call before `deloxide_init`).
+///
+/// When enabled, every tracked lock acquisition is checked against the
+/// acquisition orders observed so far; an inconsistent order (a potential
+/// deadlock that hasn't happened yet) is reported through the same JSON
+/// callback passed to `deloxide_init`, as a `DeadlockInfo` whose `source` is
+/// `"LockOrderViolation"`. Reported with [`crate::core::types::LockOrderViolationPolicy::LogOnly`];
+/// Mirrors `Deloxide::with_lock_order_checking()` on the Rust side, which
+/// `deloxide_init` itself has no equivalent of since it builds a detector
+/// configuration directly instead of going through that builder.
+///
+/// Off by default.
+///
+/// # Returns
+/// * `0` on success
+/// * `1` if the detector is already initialized
+///
+/// # Safety
+/// This function writes to a mutable global static and should be called before initialization.
+#[cfg(feature = "lock-order-graph")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_enable_lock_order_check() -> c_int {
+    if INITIALIZED.load(Ordering::SeqCst) {
+        return 1; // Already initialized
+    }
+
+    LOCK_ORDER_CHECK_ENABLED.store(true, Ordering::SeqCst);
+    0
+}
+
 /// Initialize deloxide.
 ///
 /// This function initializes the deadlock detector with optional logging and
@@ -44,6 +142,8 @@ pub unsafe extern "C" fn deloxide_init(
             return 1; // Already initialized
         }
 
+        stacktrace::set_capture_enabled(BACKTRACE_CAPTURE_ENABLED.load(Ordering::SeqCst));
+
         // Convert C string to Rust if not NULL
         let log_path_option = if !log_path.is_null() {
             match CStr::from_ptr(log_path).to_str() {
@@ -100,7 +200,9 @@ pub unsafe extern "C" fn deloxide_init(
         let config = detector::DetectorConfig {
             callback: Box::new(deadlock_callback),
             #[cfg(feature = "lock-order-graph")]
-            check_lock_order: false, // FFI doesn't support lock order checking
+            check_lock_order: LOCK_ORDER_CHECK_ENABLED.load(Ordering::SeqCst),
+            #[cfg(feature = "lock-order-graph")]
+            violation_policy: crate::core::types::LockOrderViolationPolicy::LogOnly,
             #[cfg(feature = "stress-test")]
             stress_mode: {
                 #[cfg(feature = "stress-test")]
@@ -108,6 +210,7 @@ pub unsafe extern "C" fn deloxide_init(
                     match STRESS_MODE.load(Ordering::SeqCst) {
                         1 => StressMode::RandomPreemption,
                         2 => StressMode::ComponentBased,
+                        3 => StressMode::Pct,
                         _ => StressMode::None,
                     }
                 }
@@ -134,6 +237,18 @@ pub unsafe extern "C" fn deloxide_init(
             },
             #[cfg(feature = "logging-and-visualization")]
             logger,
+            watchdog: {
+                let interval_ms = SCAN_INTERVAL_MS.load(Ordering::SeqCst);
+                if interval_ms > 0 {
+                    let interval = Duration::from_millis(interval_ms);
+                    Some(detector::WatchdogConfig {
+                        interval,
+                        stall_threshold: interval,
+                    })
+                } else {
+                    None
+                }
+            },
         };
 
         // Initialize detector
@@ -202,3 +317,56 @@ pub unsafe extern "C" fn deloxide_is_logging_enabled() -> c_int {
         0
     }
 }
+
+/// Scan the global detector's wait-for graph right now for a deadlock cycle.
+///
+/// This is an on-demand oracle independent of the reactive detector and any
+/// configured watchdog: it lets a C caller poll for a deadlock at a moment of
+/// its own choosing instead of only reacting to a callback.
+///
+/// # Arguments
+/// * `out_threads` - Buffer the offending thread IDs are written into, in
+///   wait-for order. May be NULL if `capacity` is `0`.
+/// * `capacity` - Number of `usize` slots available at `out_threads`.
+/// * `out_len` - Set to the number of thread IDs in the cycle (even if that
+///   is more than `capacity`, in which case the buffer only receives the
+///   first `capacity` of them).
+///
+/// # Returns
+/// * `1` if a cycle was found
+/// * `0` if no cycle currently exists
+/// * `-1` if `out_len` is NULL
+///
+/// # Safety
+/// - `out_len` must be a valid pointer to a `usize`.
+/// - `out_threads` must be either NULL or a valid pointer to at least `capacity` `usize` slots.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_check_deadlock(
+    out_threads: *mut usize,
+    capacity: usize,
+    out_len: *mut usize,
+) -> c_int {
+    if out_len.is_null() {
+        return -1;
+    }
+
+    let Some(cycle) = detector::check_deadlock() else {
+        unsafe {
+            *out_len = 0;
+        }
+        return 0;
+    };
+
+    unsafe {
+        *out_len = cycle.len();
+    }
+
+    if !out_threads.is_null() {
+        let n = cycle.len().min(capacity);
+        unsafe {
+            std::ptr::copy_nonoverlapping(cycle.as_ptr(), out_threads, n);
+        }
+    }
+
+    1
+}
@@ -0,0 +1,231 @@
+use crate::core::detector::mutex::create_mutex;
+use crate::core::locks::fair_mutex::FairMutex;
+use crate::core::locks::poison::TryLockError;
+use crate::core::types::ThreadId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::os::raw::c_int;
+
+thread_local! {
+    static FFI_FAIR_GUARD: RefCell<HashMap<*mut c_void, crate::core::locks::fair_mutex::FairMutexGuard<'static, ()>>> = RefCell::new(HashMap::new());
+}
+
+/// Create a new tracked fair mutex.
+///
+/// Behaves like a mutex created with `deloxide_create_mutex`, except that once
+/// the longest-waiting thread has blocked past the fairness threshold, unlocking
+/// hands the lock directly to it instead of releasing it to open competition.
+///
+/// # Returns
+/// * Void pointer to the mutex, or NULL on allocation failure
+///
+/// # Safety
+/// - The returned pointer is a raw pointer to a heap allocation and must be freed by `deloxide_destroy_fair_mutex`.
+/// - Any usage from C must ensure not to free or move the returned pointer by other means.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_create_fair_mutex() -> *mut c_void {
+    let mutex = Box::new(FairMutex::new(()));
+    Box::into_raw(mutex) as *mut c_void
+}
+
+/// Create a new tracked fair mutex with specified creator thread ID.
+///
+/// Similar to deloxide_create_fair_mutex(), but allows specifying which thread
+/// should be considered the "owner" for resource tracking purposes.
+///
+/// # Arguments
+/// * `creator_thread_id` - ID of the thread to be registered as the creator of this mutex.
+///
+/// # Returns
+/// * Void pointer to the mutex, or NULL on allocation failure
+///
+/// # Safety
+/// - The returned pointer is a raw pointer to a heap allocation and must be freed by `deloxide_destroy_fair_mutex`.
+/// - Any usage from C must ensure not to free or move the returned pointer by other means.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_create_fair_mutex_with_creator(
+    creator_thread_id: usize,
+) -> *mut c_void {
+    let mutex = Box::new(FairMutex::new(()));
+
+    // Register the specified thread as the creator
+    create_mutex(mutex.id(), Some(creator_thread_id as ThreadId));
+
+    Box::into_raw(mutex) as *mut c_void
+}
+
+/// Destroy a tracked fair mutex.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_fair_mutex`.
+///
+/// # Safety
+/// - The caller must ensure that `mutex` is not used by any thread after this function is called.
+/// - The pointer must be one previously obtained from `deloxide_create_fair_mutex` (i.e., it must not be a stack pointer or null pointer).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_destroy_fair_mutex(mutex: *mut c_void) {
+    if !mutex.is_null() {
+        unsafe {
+            drop(Box::from_raw(mutex as *mut FairMutex<()>));
+        }
+    }
+}
+
+/// Lock a tracked fair mutex.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_fair_mutex`.
+///
+/// # Returns
+/// * `0` on success
+/// * `-5` if the lock was poisoned (a previous holder panicked while holding
+///   it) - the guard is still acquired and must still be released with
+///   `deloxide_mutex_unlock_fair`
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `FairMutex<()>`.
+/// - Must not call this twice on the same mutex from the same thread without unlocking first.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_lock_fair_mutex(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    let poisoned;
+    unsafe {
+        let mutex_ref = &*(mutex as *const FairMutex<()>);
+        let guard = match mutex_ref.lock() {
+            Ok(guard) => {
+                poisoned = false;
+                guard
+            }
+            Err(e) => {
+                poisoned = true;
+                e.into_inner()
+            }
+        };
+
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_FAIR_GUARD.with(|map| {
+            map.borrow_mut().insert(mutex, std::mem::transmute(guard));
+        });
+    }
+
+    if poisoned { -5 } else { 0 }
+}
+
+/// Unlock a tracked fair mutex.
+///
+/// Dropping the stashed guard lets `FairMutex` decide, based on how long the
+/// longest-waiting thread has blocked, whether to hand the lock directly to
+/// that thread or release it to open competition (see
+/// [`crate::FairMutex`]); the caller does not choose this directly.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_fair_mutex`.
+///
+/// # Returns
+/// * `0` on success
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The pointer must be valid (i.e., a previously created `FairMutex<()>`).
+/// - The mutex must have been previously locked by the current thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_mutex_unlock_fair(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    // Drop the guard we stashed above; this runs FairMutexGuard's fairness
+    // decision and actually unlocks the mutex.
+    FFI_FAIR_GUARD.with(|map| {
+        map.borrow_mut().remove(&mutex);
+    });
+
+    0
+}
+
+/// Try to lock a tracked fair mutex without blocking.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_fair_mutex`.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the lock and must release it with `deloxide_mutex_unlock_fair`)
+/// * `1` if the mutex is currently held by another thread
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `FairMutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_fair_mutex_try_lock(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const FairMutex<()>);
+        let guard = match mutex_ref.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return 1,
+            // `try_lock` never blocks, so deadlock recovery never gets a
+            // chance to pick this thread as a victim.
+            Err(TryLockError::Abandoned) => unreachable!("try_lock never waits"),
+        };
+
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_FAIR_GUARD.with(|map| {
+            map.borrow_mut().insert(mutex, std::mem::transmute(guard));
+        });
+    }
+
+    0
+}
+
+/// Get the creator thread ID of a fair mutex.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_fair_mutex`.
+///
+/// # Returns
+/// * Thread ID of the creator thread, or 0 if the mutex is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `FairMutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_get_fair_mutex_creator(mutex: *mut c_void) -> usize {
+    if mutex.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const FairMutex<()>);
+        mutex_ref.creator_thread_id()
+    }
+}
+
+/// Check whether a fair mutex is poisoned.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_fair_mutex`.
+///
+/// # Returns
+/// * `1` if the mutex is poisoned, `0` if it is not (or if `mutex` is NULL)
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `FairMutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_fair_mutex_is_poisoned(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const FairMutex<()>);
+        mutex_ref.is_poisoned() as c_int
+    }
+}
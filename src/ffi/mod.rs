@@ -8,7 +8,9 @@
 /// including initialization, mutex tracking, thread tracking, and deadlock detection.
 mod condvar;
 mod core;
+mod fair_mutex;
 mod mutex;
+mod reentrant_mutex;
 mod rwlock;
 mod showcase;
 mod stress;
@@ -19,7 +21,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::c_void;
 use std::os::raw::c_char;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicU64};
 
 // We'll keep each Rust guard alive here until the C code calls unlock.
 thread_local! {
@@ -32,6 +34,27 @@ static INITIALIZED: AtomicBool = AtomicBool::new(false);
 static mut DEADLOCK_DETECTED: AtomicBool = AtomicBool::new(false);
 static IS_LOGGING_ENABLED: AtomicBool = AtomicBool::new(false);
 
+/// Set by `deloxide_enable_backtraces()` before `deloxide_init()`; read by
+/// `deloxide_init` to turn on lock-acquisition backtrace capture, since the
+/// FFI path builds a `DetectorConfig` directly rather than going through
+/// `Deloxide::start()` (which reads this off its own `.with_backtraces()` builder flag instead).
+static BACKTRACE_CAPTURE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Set by `deloxide_set_scan_interval()` before `deloxide_init()`; read by
+/// `deloxide_init` to configure the background watchdog, since the FFI path
+/// builds a `DetectorConfig` directly rather than going through
+/// `Deloxide::start()` (which reads this off its own `.with_watchdog()`
+/// builder option instead). `0` means the watchdog stays disabled.
+static SCAN_INTERVAL_MS: AtomicU64 = AtomicU64::new(0);
+
+/// Set by `deloxide_enable_lock_order_check()` before `deloxide_init()`; read
+/// by `deloxide_init` to turn on lock-order-inversion checking, since the
+/// FFI path builds a `DetectorConfig` directly rather than going through
+/// `Deloxide::start()` (which reads this off its own
+/// `.with_lock_order_checking()` builder option instead).
+#[cfg(feature = "lock-order-graph")]
+static LOCK_ORDER_CHECK_ENABLED: AtomicBool = AtomicBool::new(false);
+
 // Optional callback function provided by C code
 static mut DEADLOCK_CALLBACK: Option<extern "C" fn(*const c_char)> = None;
 
@@ -41,6 +64,6 @@ use crate::StressConfig;
 use std::sync::atomic::AtomicU8;
 
 #[cfg(feature = "stress-test")]
-static STRESS_MODE: AtomicU8 = AtomicU8::new(0); // 0=None, 1=Random, 2=Component
+static STRESS_MODE: AtomicU8 = AtomicU8::new(0); // 0=None, 1=Random, 2=Component, 3=Pct
 #[cfg(feature = "stress-test")]
 static mut STRESS_CONFIG: Option<StressConfig> = None;
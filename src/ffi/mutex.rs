@@ -1,8 +1,10 @@
 use crate::core::detector::mutex::create_mutex;
+use crate::core::locks::poison::TryLockError;
 use crate::ffi::FFI_GUARD;
 use crate::{Mutex, ThreadId};
 use std::ffi::c_void;
 use std::os::raw::c_int;
+use std::time::Duration;
 
 /// Create a new tracked mutex.
 ///
@@ -77,6 +79,10 @@ pub unsafe extern "C" fn deloxide_destroy_mutex(mutex: *mut c_void) {
 ///
 /// # Returns
 /// * `0` on success
+/// * `-5` if the lock was poisoned (a previous holder panicked while holding
+///   it) - the guard is still acquired and must still be released with
+///   `deloxide_unlock_mutex`, mirroring how [`crate::PoisonError::into_inner`]
+///   always hands the guard back to Rust callers
 /// * `-1` if the mutex pointer is NULL
 ///
 /// # Safety
@@ -88,9 +94,19 @@ pub unsafe extern "C" fn deloxide_lock_mutex(mutex: *mut c_void) -> c_int {
         return -1;
     }
 
+    let poisoned;
     unsafe {
         let mutex_ref = &*(mutex as *const Mutex<()>);
-        let guard = mutex_ref.lock();
+        let guard = match mutex_ref.lock() {
+            Ok(guard) => {
+                poisoned = false;
+                guard
+            }
+            Err(e) => {
+                poisoned = true;
+                e.into_inner()
+            }
+        };
 
         #[allow(clippy::missing_transmute_annotations)]
         FFI_GUARD.with(|map| {
@@ -98,7 +114,7 @@ pub unsafe extern "C" fn deloxide_lock_mutex(mutex: *mut c_void) -> c_int {
         });
     }
 
-    0
+    if poisoned { -5 } else { 0 }
 }
 
 /// Unlock a tracked mutex.
@@ -129,6 +145,84 @@ pub unsafe extern "C" fn deloxide_unlock_mutex(mutex: *mut c_void) -> c_int {
     0
 }
 
+/// Try to lock a tracked mutex without blocking.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_mutex`.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the lock and must release it with `deloxide_unlock_mutex`)
+/// * `1` if the mutex is currently held by another thread
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `Mutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_mutex_try_lock(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const Mutex<()>);
+        let guard = match mutex_ref.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return 1,
+            // `try_lock` never blocks, so deadlock recovery never gets a
+            // chance to pick this thread as a victim.
+            Err(TryLockError::Abandoned) => unreachable!("try_lock never waits"),
+        };
+
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_GUARD.with(|map| {
+            map.borrow_mut().insert(mutex, std::mem::transmute(guard));
+        });
+    }
+
+    0
+}
+
+/// Lock a tracked mutex, giving up if it isn't acquired within `timeout_ms`.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_mutex`.
+/// * `timeout_ms` - Maximum time to wait, in milliseconds.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the lock and must release it with `deloxide_unlock_mutex`)
+/// * `1` if the timeout elapsed before the lock became available
+/// * `2` if this thread was chosen as the victim to break a detected deadlock
+///   cycle (see [`crate::Deloxide::with_deadlock_recovery`]) and gave up
+///   waiting instead of blocking forever
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `Mutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_mutex_lock_for(mutex: *mut c_void, timeout_ms: u64) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const Mutex<()>);
+        let guard = match mutex_ref.lock_for(Duration::from_millis(timeout_ms)) {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return 1,
+            Err(TryLockError::Abandoned) => return 2,
+        };
+
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_GUARD.with(|map| {
+            map.borrow_mut().insert(mutex, std::mem::transmute(guard));
+        });
+    }
+
+    0
+}
+
 /// Get the creator thread ID of a mutex.
 ///
 /// Returns the ID of the thread that created the specified mutex.
@@ -152,3 +246,27 @@ pub unsafe extern "C" fn deloxide_get_mutex_creator(mutex: *mut c_void) -> usize
         mutex_ref.creator_thread_id()
     }
 }
+
+/// Check whether a mutex is poisoned.
+///
+/// A mutex becomes poisoned when a thread panics while holding its guard.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_mutex`.
+///
+/// # Returns
+/// * `1` if the mutex is poisoned, `0` if it is not (or if `mutex` is NULL)
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `Mutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_mutex_is_poisoned(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const Mutex<()>);
+        mutex_ref.is_poisoned() as c_int
+    }
+}
@@ -0,0 +1,257 @@
+use crate::core::detector::mutex::create_mutex;
+use crate::core::locks::poison::TryLockError;
+use crate::core::locks::reentrant_mutex::ReentrantMutex;
+use crate::core::types::ThreadId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::os::raw::c_int;
+
+// Each thread can hold multiple guards on the same reentrant mutex at once
+// (one per nested lock call), so guards are stacked per mutex pointer and
+// unlock pops the most recently pushed one.
+thread_local! {
+    static FFI_REENTRANT_GUARD: RefCell<HashMap<*mut c_void, Vec<crate::core::locks::reentrant_mutex::ReentrantMutexGuard<'static, ()>>>> = RefCell::new(HashMap::new());
+}
+
+/// Create a new tracked reentrant mutex.
+///
+/// Creates a reentrant mutex that will be tracked by the deadlock detector.
+/// The current thread will be recorded as the creator of this mutex.
+///
+/// # Returns
+/// * Void pointer to the mutex, or NULL on allocation failure
+///
+/// # Safety
+/// - The returned pointer is a raw pointer to a heap allocation and must be freed by `deloxide_destroy_reentrant_mutex`.
+/// - Any usage from C must ensure not to free or move the returned pointer by other means.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_create_reentrant_mutex() -> *mut c_void {
+    let mutex = Box::new(ReentrantMutex::new(()));
+    Box::into_raw(mutex) as *mut c_void
+}
+
+/// Create a new tracked reentrant mutex with specified creator thread ID.
+///
+/// # Arguments
+/// * `creator_thread_id` - ID of the thread to be registered as the creator of this mutex.
+///
+/// # Returns
+/// * Void pointer to the mutex, or NULL on allocation failure
+///
+/// # Safety
+/// - The returned pointer is a raw pointer to a heap allocation and must be freed by `deloxide_destroy_reentrant_mutex`.
+/// - Any usage from C must ensure not to free or move the returned pointer by other means.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_create_reentrant_mutex_with_creator(
+    creator_thread_id: usize,
+) -> *mut c_void {
+    let mutex = Box::new(ReentrantMutex::new(()));
+
+    // Register the specified thread as the creator
+    create_mutex(mutex.id(), Some(creator_thread_id as ThreadId));
+
+    Box::into_raw(mutex) as *mut c_void
+}
+
+/// Destroy a tracked reentrant mutex.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_reentrant_mutex`.
+///
+/// # Safety
+/// - The caller must ensure that `mutex` is not used by any thread after this function is called.
+/// - The pointer must be one previously obtained from `deloxide_create_reentrant_mutex` (i.e., it must not be a stack pointer or null pointer).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_destroy_reentrant_mutex(mutex: *mut c_void) {
+    if !mutex.is_null() {
+        unsafe {
+            drop(Box::from_raw(mutex as *mut ReentrantMutex<()>));
+        }
+    }
+}
+
+/// Lock a tracked reentrant mutex.
+///
+/// Attempts to acquire the lock on a reentrant mutex while tracking the
+/// operation for deadlock detection. A thread that already holds this mutex
+/// may call this again without blocking; each such call must be matched by
+/// its own call to `deloxide_unlock_reentrant_mutex`.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_reentrant_mutex`.
+///
+/// # Returns
+/// * `0` on success
+/// * `-5` if the lock was poisoned (a previous holder panicked while holding
+///   it) - the guard is still acquired and must still be released with
+///   `deloxide_unlock_reentrant_mutex`
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `ReentrantMutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_lock_reentrant_mutex(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    let poisoned;
+    unsafe {
+        let mutex_ref = &*(mutex as *const ReentrantMutex<()>);
+        let guard = match mutex_ref.lock() {
+            Ok(guard) => {
+                poisoned = false;
+                guard
+            }
+            Err(e) => {
+                poisoned = true;
+                e.into_inner()
+            }
+        };
+
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_REENTRANT_GUARD.with(|map| {
+            map.borrow_mut()
+                .entry(mutex)
+                .or_default()
+                .push(std::mem::transmute(guard));
+        });
+    }
+
+    if poisoned { -5 } else { 0 }
+}
+
+/// Unlock a tracked reentrant mutex.
+///
+/// Releases the most recently acquired lock held by the current thread on
+/// this mutex. Only the call that brings the recursion count back to zero
+/// actually reports the release to the deadlock detector.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_reentrant_mutex`.
+///
+/// # Returns
+/// * `0` on success
+/// * `-1` if the mutex pointer is NULL
+///
+/// # Safety
+/// - The pointer must be valid (i.e., a previously created `ReentrantMutex<()>`).
+/// - The mutex must have been previously locked by the current thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_unlock_reentrant_mutex(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    // Drop the most recently stashed guard; this actually unlocks (or
+    // decrements the recursion count of) the ReentrantMutex.
+    FFI_REENTRANT_GUARD.with(|map| {
+        if let Some(stack) = map.borrow_mut().get_mut(&mutex) {
+            stack.pop();
+        }
+    });
+
+    0
+}
+
+/// Try to lock a tracked reentrant mutex without blocking.
+///
+/// A thread that already holds this mutex always succeeds immediately,
+/// incrementing its recursion count; each successful call must be matched
+/// by its own call to `deloxide_unlock_reentrant_mutex`.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_reentrant_mutex`.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the lock and must release it with `deloxide_unlock_reentrant_mutex`)
+/// * `1` if the lock is currently held by another thread
+/// * `-1` if the mutex pointer is NULL
+/// * `-5` if the lock was poisoned (a previous holder panicked while holding
+///   it) - the guard is still acquired and must still be released with
+///   `deloxide_unlock_reentrant_mutex`
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `ReentrantMutex<()>`.
+/// - Must use `deloxide_unlock_reentrant_mutex` to unlock on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_try_lock_reentrant_mutex(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return -1;
+    }
+
+    let poisoned;
+    unsafe {
+        let mutex_ref = &*(mutex as *const ReentrantMutex<()>);
+        let guard = match mutex_ref.try_lock() {
+            Ok(guard) => {
+                poisoned = false;
+                guard
+            }
+            Err(TryLockError::Poisoned(e)) => {
+                poisoned = true;
+                e.into_inner()
+            }
+            Err(TryLockError::WouldBlock) => return 1,
+            Err(TryLockError::Abandoned) => unreachable!("try_lock never waits"),
+        };
+
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_REENTRANT_GUARD.with(|map| {
+            map.borrow_mut()
+                .entry(mutex)
+                .or_default()
+                .push(std::mem::transmute(guard));
+        });
+    }
+
+    if poisoned { -5 } else { 0 }
+}
+
+/// Get the creator thread ID of a reentrant mutex.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_reentrant_mutex`.
+///
+/// # Returns
+/// * Thread ID of the creator thread, or 0 if the mutex is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `ReentrantMutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_get_reentrant_mutex_creator(mutex: *mut c_void) -> usize {
+    if mutex.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const ReentrantMutex<()>);
+        mutex_ref.creator_thread_id()
+    }
+}
+
+/// Check whether a reentrant mutex is poisoned.
+///
+/// A reentrant mutex becomes poisoned when a thread panics while holding one
+/// of its guards.
+///
+/// # Arguments
+/// * `mutex` - Pointer to a mutex created with `deloxide_create_reentrant_mutex`.
+///
+/// # Returns
+/// * `1` if the mutex is poisoned, `0` if it is not (or if `mutex` is NULL)
+///
+/// # Safety
+/// - The caller must pass a valid pointer to a `ReentrantMutex<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_reentrant_mutex_is_poisoned(mutex: *mut c_void) -> c_int {
+    if mutex.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let mutex_ref = &*(mutex as *const ReentrantMutex<()>);
+        mutex_ref.is_poisoned() as c_int
+    }
+}
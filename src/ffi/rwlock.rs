@@ -1,13 +1,25 @@
 use crate::core::detector::rwlock::create_rwlock;
+use crate::core::locks::poison::TryLockError;
 use crate::core::locks::rwlock::RwLock;
 use crate::core::types::ThreadId;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::{c_int, c_void};
+use std::time::Duration;
 
-// Each thread can hold one read and one write guard at a time (per-thread tracking)
+// Each thread has a single write-guard slot shared by all rwlocks, so it
+// holds at most one write guard at a time across every rwlock; storing a
+// new one drops (and so releases) the previous one. A thread may hold
+// several read guards on the same rwlock (recursive read locking), so read
+// guards are stacked per rwlock pointer, mirroring the mutex `FFI_GUARD` map.
 thread_local! {
-    static FFI_RW_READ_GUARD: RefCell<Option<crate::core::locks::rwlock::RwLockReadGuard<'static, ()>>> = const {RefCell::new(None)};
+    static FFI_RW_READ_GUARD: RefCell<HashMap<*mut c_void, Vec<crate::core::locks::rwlock::RwLockReadGuard<'static, ()>>>> = RefCell::new(HashMap::new());
     static FFI_RW_WRITE_GUARD: RefCell<Option<crate::core::locks::rwlock::RwLockWriteGuard<'static, ()>>> = const {RefCell::new(None)};
+    // Upgradable read locks are exclusive per-rwlock (only one thread may hold
+    // the upgradable read lock on a given rwlock at a time), but a thread may
+    // hold it on several different rwlocks at once, so guards are keyed by
+    // rwlock pointer, mirroring `FFI_RW_READ_GUARD`.
+    static FFI_RW_UPGRADABLE_GUARD: RefCell<HashMap<*mut c_void, crate::core::locks::rwlock::RwLockUpgradableReadGuard<'static, ()>>> = RefCell::new(HashMap::new());
 }
 
 /// Create a new tracked RwLock (reader-writer lock).
@@ -61,6 +73,10 @@ pub unsafe extern "C" fn deloxide_destroy_rwlock(rwlock: *mut c_void) {
 
 /// Lock an RwLock for reading.
 ///
+/// A thread may call this more than once on the same `rwlock` before
+/// unlocking: each call pushes its own read guard, and each must be matched
+/// by its own call to `deloxide_rw_unlock_read`.
+///
 /// # Arguments
 /// * `rwlock` - Pointer to an RwLock.
 ///
@@ -69,7 +85,6 @@ pub unsafe extern "C" fn deloxide_destroy_rwlock(rwlock: *mut c_void) {
 /// * `-1` if pointer is NULL
 ///
 /// # Safety
-/// - Do not call twice from the same thread without unlocking.
 /// - Must use `deloxide_rw_unlock_read` to unlock.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn deloxide_rw_lock_read(rwlock: *mut c_void) -> c_int {
@@ -77,13 +92,14 @@ pub unsafe extern "C" fn deloxide_rw_lock_read(rwlock: *mut c_void) -> c_int {
         return -1;
     }
     let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
-    let guard = rwlock_ref.read();
+    let guard = rwlock_ref.read().unwrap_or_else(|e| e.into_inner());
     unsafe {
-        FFI_RW_READ_GUARD.with(|slot| {
-            *slot.borrow_mut() = Some(std::mem::transmute::<
-                crate::core::locks::rwlock::RwLockReadGuard<'_, ()>,
-                crate::core::locks::rwlock::RwLockReadGuard<'_, ()>,
-            >(guard))
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_RW_READ_GUARD.with(|map| {
+            map.borrow_mut()
+                .entry(rwlock)
+                .or_default()
+                .push(std::mem::transmute(guard));
         });
     }
     0
@@ -91,6 +107,9 @@ pub unsafe extern "C" fn deloxide_rw_lock_read(rwlock: *mut c_void) -> c_int {
 
 /// Unlock an RwLock after reading.
 ///
+/// Releases the most recently acquired read guard held by the current thread
+/// on this `rwlock`.
+///
 /// # Arguments
 /// * `rwlock` - Pointer to an RwLock.
 ///
@@ -102,12 +121,96 @@ pub unsafe extern "C" fn deloxide_rw_unlock_read(rwlock: *mut c_void) -> c_int {
     if rwlock.is_null() {
         return -1;
     }
-    FFI_RW_READ_GUARD.with(|slot| {
-        let _ = slot.borrow_mut().take();
+    FFI_RW_READ_GUARD.with(|map| {
+        if let Some(stack) = map.borrow_mut().get_mut(&rwlock) {
+            stack.pop();
+        }
     });
     0
 }
 
+/// Try to lock an RwLock for reading without blocking.
+///
+/// Like `deloxide_rw_lock_read`, a thread may hold more than one read guard
+/// on the same `rwlock` at a time.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the read lock and must release it with `deloxide_rw_unlock_read`)
+/// * `1` if the lock is currently held exclusively by another thread
+/// * `-1` if the pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+/// - Must use `deloxide_rw_unlock_read` to unlock on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_try_read(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    let guard = match rwlock_ref.try_read() {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => return 1,
+        Err(TryLockError::Abandoned) => unreachable!("try_read never waits"),
+    };
+    unsafe {
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_RW_READ_GUARD.with(|map| {
+            map.borrow_mut()
+                .entry(rwlock)
+                .or_default()
+                .push(std::mem::transmute(guard));
+        });
+    }
+    0
+}
+
+/// Lock an RwLock for reading, giving up after `timeout_ms` milliseconds.
+///
+/// Like `deloxide_rw_lock_read`, a thread may hold more than one read guard
+/// on the same `rwlock` at a time.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+/// * `timeout_ms` - Maximum time to wait, in milliseconds.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the read lock and must release it with `deloxide_rw_unlock_read`)
+/// * `1` if the timeout elapsed before the lock was acquired
+/// * `2` if the caller was chosen as a deadlock victim and had its wait aborted
+/// * `-1` if the pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+/// - Must use `deloxide_rw_unlock_read` to unlock on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_read_for(rwlock: *mut c_void, timeout_ms: u64) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    let guard = match rwlock_ref.read_for(Duration::from_millis(timeout_ms)) {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => return 1,
+        Err(TryLockError::Abandoned) => return 2,
+    };
+    unsafe {
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_RW_READ_GUARD.with(|map| {
+            map.borrow_mut()
+                .entry(rwlock)
+                .or_default()
+                .push(std::mem::transmute(guard));
+        });
+    }
+    0
+}
+
 /// Lock an RwLock for writing.
 ///
 /// # Arguments
@@ -126,7 +229,7 @@ pub unsafe extern "C" fn deloxide_rw_lock_write(rwlock: *mut c_void) -> c_int {
         return -1;
     }
     let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
-    let guard = rwlock_ref.write();
+    let guard = rwlock_ref.write().unwrap_or_else(|e| e.into_inner());
     unsafe {
         FFI_RW_WRITE_GUARD.with(|slot| {
             *slot.borrow_mut() = Some(std::mem::transmute::<
@@ -158,6 +261,210 @@ pub unsafe extern "C" fn deloxide_rw_unlock_write(rwlock: *mut c_void) -> c_int
     0
 }
 
+/// Try to lock an RwLock for writing without blocking.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the write lock and must release it with `deloxide_rw_unlock_write`)
+/// * `1` if the lock is currently held (for reading or writing) by another thread
+/// * `-1` if the pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+/// - Must use `deloxide_rw_unlock_write` to unlock on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_try_write(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    let guard = match rwlock_ref.try_write() {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => return 1,
+        Err(TryLockError::Abandoned) => unreachable!("try_write never waits"),
+    };
+    unsafe {
+        FFI_RW_WRITE_GUARD.with(|slot| {
+            *slot.borrow_mut() = Some(std::mem::transmute::<
+                crate::core::locks::rwlock::RwLockWriteGuard<'_, ()>,
+                crate::core::locks::rwlock::RwLockWriteGuard<'_, ()>,
+            >(guard))
+        });
+    }
+
+    0
+}
+
+/// Lock an RwLock for writing, giving up after `timeout_ms` milliseconds.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+/// * `timeout_ms` - Maximum time to wait, in milliseconds.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the write lock and must release it with `deloxide_rw_unlock_write`)
+/// * `1` if the timeout elapsed before the lock was acquired
+/// * `2` if the caller was chosen as a deadlock victim and had its wait aborted
+/// * `-1` if the pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+/// - Do not call twice from the same thread without unlocking.
+/// - Must use `deloxide_rw_unlock_write` to unlock on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_write_for(rwlock: *mut c_void, timeout_ms: u64) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    let guard = match rwlock_ref.write_for(Duration::from_millis(timeout_ms)) {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => return 1,
+        Err(TryLockError::Abandoned) => return 2,
+    };
+    unsafe {
+        FFI_RW_WRITE_GUARD.with(|slot| {
+            *slot.borrow_mut() = Some(std::mem::transmute::<
+                crate::core::locks::rwlock::RwLockWriteGuard<'_, ()>,
+                crate::core::locks::rwlock::RwLockWriteGuard<'_, ()>,
+            >(guard))
+        });
+    }
+
+    0
+}
+
+/// Lock an RwLock for an upgradable read.
+///
+/// Like a plain read lock, this allows other threads to hold concurrent read
+/// locks, but at most one thread may hold the upgradable read lock at a
+/// time, and it may later be upgraded to a write lock with
+/// `deloxide_rw_upgrade`.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+///
+/// # Returns
+/// * `0` on success
+/// * `-1` if pointer is NULL
+///
+/// # Safety
+/// - Do not call twice from the same thread on the same `rwlock` without unlocking or upgrading.
+/// - Must use `deloxide_rw_unlock_upgradable_read` to unlock, or `deloxide_rw_upgrade` to upgrade.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_lock_upgradable_read(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    let guard = rwlock_ref
+        .upgradable_read()
+        .unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_RW_UPGRADABLE_GUARD.with(|map| {
+            map.borrow_mut().insert(rwlock, std::mem::transmute(guard));
+        });
+    }
+    0
+}
+
+/// Try to lock an RwLock for an upgradable read without blocking.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the upgradable read lock)
+/// * `1` if the upgradable read lock is already held by another thread, or the lock is held exclusively
+/// * `-1` if the pointer is NULL
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+/// - Must use `deloxide_rw_unlock_upgradable_read` to unlock, or `deloxide_rw_upgrade` to upgrade, on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_try_upgradable_read(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    let guard = match rwlock_ref.try_upgradable_read() {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => return 1,
+        Err(TryLockError::Abandoned) => unreachable!("try_upgradable_read never waits"),
+    };
+    unsafe {
+        #[allow(clippy::missing_transmute_annotations)]
+        FFI_RW_UPGRADABLE_GUARD.with(|map| {
+            map.borrow_mut().insert(rwlock, std::mem::transmute(guard));
+        });
+    }
+    0
+}
+
+/// Unlock an RwLock after an upgradable read, without upgrading.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock.
+///
+/// # Returns
+/// * `0` on success
+/// * `-1` if pointer is NULL
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_unlock_upgradable_read(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    FFI_RW_UPGRADABLE_GUARD.with(|map| {
+        map.borrow_mut().remove(&rwlock);
+    });
+    0
+}
+
+/// Upgrade a held upgradable read lock into an exclusive write lock.
+///
+/// Blocks until all current readers release their read locks. If another
+/// thread is itself upgrading and the two upgrades wait on each other's
+/// readers, this is reported as a deadlock.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock on which the calling thread currently
+///   holds the upgradable read lock.
+///
+/// # Returns
+/// * `0` on success (the caller now holds the write lock and must release it with `deloxide_rw_unlock_write`)
+/// * `-1` if the pointer is NULL
+/// * `-3` if the calling thread does not hold the upgradable read lock on this `rwlock`
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+/// - Must use `deloxide_rw_unlock_write` to unlock on success.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rw_upgrade(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return -1;
+    }
+    let guard = match FFI_RW_UPGRADABLE_GUARD.with(|map| map.borrow_mut().remove(&rwlock)) {
+        Some(guard) => guard,
+        None => return -3,
+    };
+    let write_guard = guard.upgrade().unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        FFI_RW_WRITE_GUARD.with(|slot| {
+            *slot.borrow_mut() = Some(std::mem::transmute::<
+                crate::core::locks::rwlock::RwLockWriteGuard<'_, ()>,
+                crate::core::locks::rwlock::RwLockWriteGuard<'_, ()>,
+            >(write_guard))
+        });
+    }
+    0
+}
+
 /// Get the creator thread ID of an RwLock.
 ///
 /// # Arguments
@@ -173,3 +480,24 @@ pub unsafe extern "C" fn deloxide_get_rwlock_creator(rwlock: *mut c_void) -> usi
     let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
     rwlock_ref.creator_thread_id()
 }
+
+/// Check whether an RwLock is poisoned.
+///
+/// An RwLock becomes poisoned when a thread panics while holding one of its guards.
+///
+/// # Arguments
+/// * `rwlock` - Pointer to an RwLock created with `deloxide_create_rwlock`.
+///
+/// # Returns
+/// * `1` if the lock is poisoned, `0` if it is not (or if `rwlock` is NULL)
+///
+/// # Safety
+/// - The caller must pass a valid pointer to an `RwLock<()>`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_rwlock_is_poisoned(rwlock: *mut c_void) -> c_int {
+    if rwlock.is_null() {
+        return 0;
+    }
+    let rwlock_ref = unsafe { &*(rwlock as *const RwLock<()>) };
+    rwlock_ref.is_poisoned() as c_int
+}
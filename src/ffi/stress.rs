@@ -8,6 +8,11 @@ use crate::ffi::{INITIALIZED, STRESS_CONFIG, STRESS_MODE};
 #[cfg(feature = "stress-test")]
 use std::sync::atomic::Ordering;
 
+#[cfg(all(feature = "stress-test", feature = "logging-and-visualization"))]
+use std::ffi::CStr;
+#[cfg(all(feature = "stress-test", feature = "logging-and-visualization"))]
+use std::os::raw::c_char;
+
 /// Enable random preemption stress testing (only with "stress-test" feature)
 ///
 /// This function enables stress testing with random preemption before lock
@@ -46,6 +51,70 @@ pub unsafe extern "C" fn deloxide_enable_random_stress(
                 min_delay_us,
                 max_delay_us,
                 preempt_after_release: true,
+                fair_unlock: false,
+                seed: None,
+                pct_depth: 3,
+                pct_estimated_steps: 50,
+            });
+        }
+
+        0
+    }
+
+    #[cfg(not(feature = "stress-test"))]
+    {
+        // Return error if stress-test feature is not enabled
+        -1
+    }
+}
+
+/// Enable seeded active fuzzing: random preemption stress testing with a
+/// fixed, replayable seed (only with "stress-test" feature)
+///
+/// Like [`deloxide_enable_random_stress`], but every preemption decision is
+/// drawn from a deterministic per-thread PRNG seeded from `seed` instead of
+/// the OS RNG, so a run that surfaces a deadlock can be reproduced
+/// bit-for-bit later. Mirrors `Deloxide::with_fuzzing()` on the Rust side.
+///
+/// # Arguments
+/// * `seed` - Seed driving the deterministic preemption decisions
+/// * `probability` - Probability of preemption (0.0-1.0)
+/// * `min_delay_us` - Minimum delay duration in microseconds
+/// * `max_delay_us` - Maximum delay duration in microseconds
+///
+/// # Returns
+/// * `0` on success
+/// * `1` if already initialized
+/// * `-1` if stress-test feature is not enabled
+///
+/// # Safety
+/// This function writes to mutable static variables and should be called before initialization.
+#[unsafe(no_mangle)]
+#[allow(unused_variables)]
+pub unsafe extern "C" fn deloxide_enable_fuzzing(
+    seed: c_ulong,
+    probability: c_double,
+    min_delay_us: c_ulong,
+    max_delay_us: c_ulong,
+) -> c_int {
+    #[cfg(feature = "stress-test")]
+    {
+        if INITIALIZED.load(Ordering::SeqCst) {
+            return 1; // Already initialized
+        }
+
+        STRESS_MODE.store(1, Ordering::SeqCst);
+
+        unsafe {
+            STRESS_CONFIG = Some(crate::core::stress::StressConfig {
+                preemption_probability: probability,
+                min_delay_us,
+                max_delay_us,
+                preempt_after_release: true,
+                fair_unlock: false,
+                seed: Some(seed as u64),
+                pct_depth: 3,
+                pct_estimated_steps: 50,
             });
         }
 
@@ -59,6 +128,67 @@ pub unsafe extern "C" fn deloxide_enable_random_stress(
     }
 }
 
+/// Deterministically reproduce a previously recorded deadlock (only with the
+/// "stress-test" and "logging-and-visualization" features)
+///
+/// Loads the stress-decision stream recorded in the log at `trace_path` by a
+/// prior [`deloxide_enable_fuzzing`] run and installs it as the decision
+/// source for every subsequent stress scheduling point (see
+/// [`crate::core::stress::replay`]). As long as the threads in this run
+/// repeat the same sequence of lock operations the original run made, they
+/// hit the exact same preemption decisions in the exact same order, so a
+/// deadlock the original run surfaced reoccurs on demand - invaluable for
+/// turning a one-off fuzzing find into a reliable regression test.
+///
+/// Must be called before `deloxide_init`, like every other
+/// `deloxide_enable_*_stress` function, since the decision stream needs to
+/// be installed before the detector starts handing out scheduling decisions.
+///
+/// # Arguments
+/// * `trace_path` - Path to the log file recorded by the run being reproduced,
+///   as a null-terminated C string
+///
+/// # Returns
+/// * `0` on success
+/// * `1` if already initialized
+/// * `-1` if `trace_path` is NULL or contains invalid UTF-8
+/// * `-2` if the trace file couldn't be read or parsed
+/// * `-3` if the "stress-test" or "logging-and-visualization" feature is not enabled
+///
+/// # Safety
+/// The caller must ensure `trace_path` is either NULL or a valid
+/// null-terminated string.
+#[unsafe(no_mangle)]
+#[allow(unused_variables)]
+pub unsafe extern "C" fn deloxide_replay_from_trace(trace_path: *const c_char) -> c_int {
+    #[cfg(all(feature = "stress-test", feature = "logging-and-visualization"))]
+    {
+        if INITIALIZED.load(Ordering::SeqCst) {
+            return 1; // Already initialized
+        }
+
+        if trace_path.is_null() {
+            return -1;
+        }
+
+        let path = match unsafe { CStr::from_ptr(trace_path) }.to_str() {
+            Ok(s) => s,
+            Err(_) => return -1,
+        };
+
+        match crate::core::stress::replay(path) {
+            Ok(()) => 0,
+            Err(_) => -2,
+        }
+    }
+
+    #[cfg(not(all(feature = "stress-test", feature = "logging-and-visualization")))]
+    {
+        // Return error if the required features are not enabled
+        -3
+    }
+}
+
 /// Enable component-based stress testing (only with "stress-test" feature)
 ///
 /// This function enables stress testing with targeted delays based on lock
@@ -95,6 +225,75 @@ pub unsafe extern "C" fn deloxide_enable_component_stress(
                 min_delay_us,
                 max_delay_us,
                 preempt_after_release: true,
+                fair_unlock: false,
+                seed: None,
+                pct_depth: 3,
+                pct_estimated_steps: 50,
+            });
+        }
+
+        0
+    }
+
+    #[cfg(not(feature = "stress-test"))]
+    {
+        // Return error if stress-test feature is not enabled
+        -1
+    }
+}
+
+/// Enable PCT (Probabilistic Concurrency Testing)-style priority scheduling
+/// stress testing (only with "stress-test" feature)
+///
+/// Unlike [`deloxide_enable_random_stress`] and
+/// [`deloxide_enable_component_stress`], which perturb timing with no
+/// particular bug in mind, this mode assigns each thread a random priority
+/// and forces `depth - 1` priority-demotion change points into the run,
+/// giving a provable lower bound of `1 / (n * k^(d-1))` on hitting any
+/// latent bug of depth `d`, where `n` is the number of distinct threads
+/// actually seen and `k` is `estimated_steps`. See
+/// [`crate::core::stress::StressConfig::pct_depth`] and
+/// [`crate::core::stress::StressConfig::pct_estimated_steps`] for the
+/// Rust-side equivalents.
+///
+/// # Arguments
+/// * `num_threads` - Expected number of distinct threads in the run; `n` in
+///   the probability bound above. Not required for the scheduler to work -
+///   threads are assigned a priority the first time they're seen, the same
+///   way they're discovered through `deloxide_register_thread_spawn` - but
+///   informs how wide a net `depth` actually casts for this workload.
+/// * `depth` - Target bug depth `d`; see
+///   [`crate::core::stress::StressConfig::pct_depth`]
+/// * `estimated_steps` - Estimated scheduling-point count `k`; see
+///   [`crate::core::stress::StressConfig::pct_estimated_steps`]
+///
+/// # Returns
+/// * `0` on success
+/// * `1` if already initialized
+/// * `-1` if stress-test feature is not enabled
+///
+/// # Safety
+/// This function writes to mutable static variables and should be called before initialization.
+#[unsafe(no_mangle)]
+#[allow(unused_variables)]
+pub unsafe extern "C" fn deloxide_enable_pct_stress(
+    num_threads: c_ulong,
+    depth: c_ulong,
+    estimated_steps: c_ulong,
+) -> c_int {
+    #[cfg(feature = "stress-test")]
+    {
+        if INITIALIZED.load(Ordering::SeqCst) {
+            return 1; // Already initialized
+        }
+
+        STRESS_MODE.store(3, Ordering::SeqCst);
+
+        unsafe {
+            STRESS_CONFIG = Some(StressConfig {
+                pct_depth: (depth as usize).max(1),
+                pct_estimated_steps: estimated_steps.max(1),
+                ..StressConfig::default()
             });
         }
 
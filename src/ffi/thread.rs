@@ -52,6 +52,52 @@ pub unsafe extern "C" fn deloxide_register_thread_exit(thread_id: usize) -> c_in
     0 // Success
 }
 
+/// Register the calling thread with the global detector.
+///
+/// Unlike [`deloxide_register_thread_spawn`], which takes an explicit
+/// `thread_id` supplied by the caller, this registers whichever thread is
+/// actually making the call - the same auto-detection
+/// [`deloxide_get_thread_id`] uses - so it can be dropped straight into a
+/// foreign thread pool's own worker startup hook (e.g. a rayon
+/// `spawn_handler`) without that hook having to first obtain and thread an
+/// ID of its own.
+///
+/// # Arguments
+/// * `parent_id` - ID of the parent thread responsible for this one, or 0 for no parent.
+///
+/// # Returns
+/// The calling thread's ID, as an unsigned long.
+///
+/// # Safety
+/// This function is safe to call from FFI contexts.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_register_thread(parent_id: usize) -> usize {
+    let parent = if parent_id == 0 {
+        None
+    } else {
+        Some(parent_id as ThreadId)
+    };
+    crate::core::thread::register_current(parent) as usize
+}
+
+/// Unregister the calling thread from the global detector.
+///
+/// Call this right before a thread registered with
+/// [`deloxide_register_thread`] actually exits. Mirrors
+/// [`deloxide_register_thread_exit`], but (like [`deloxide_register_thread`])
+/// operates on the calling thread rather than an explicit `thread_id`.
+///
+/// # Returns
+/// * `0` on success
+///
+/// # Safety
+/// This function is safe to call from FFI contexts.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn deloxide_unregister_thread() -> c_int {
+    crate::core::thread::unregister_current();
+    0 // Success
+}
+
 /// Get the current thread ID.
 ///
 /// Returns a unique identifier for the current thread that can be used
@@ -46,18 +46,18 @@
 //! let a_clone = Arc::clone(&mutex_a);
 //! let b_clone = Arc::clone(&mutex_b);
 //! let t1 = thread::spawn(move || {
-//!     let lock_a = a_clone.lock();
+//!     let lock_a = a_clone.lock().unwrap();
 //!     thread::sleep(Duration::from_millis(100));
-//!     let lock_b = b_clone.lock();
+//!     let lock_b = b_clone.lock().unwrap();
 //! });
 //!
 //! // Second thread: Lock B, then try to lock A (potential deadlock)
 //! let a_clone = Arc::clone(&mutex_a);
 //! let b_clone = Arc::clone(&mutex_b);
 //! let t2 = thread::spawn(move || {
-//!     let lock_b = b_clone.lock();
+//!     let lock_b = b_clone.lock().unwrap();
 //!     thread::sleep(Duration::from_millis(100));
-//!     let lock_a = a_clone.lock();
+//!     let lock_a = a_clone.lock().unwrap();
 //! });
 //! ```
 //!
@@ -83,7 +83,7 @@
 //! for i in 0..3 {
 //!     let rwlock_clone = Arc::clone(&rwlock);
 //!     thread::spawn(move || {
-//!         let read_guard = rwlock_clone.read();
+//!         let read_guard = rwlock_clone.read().unwrap();
 //!         println!("Reader {} acquired read lock", i);
 //!         thread::sleep(Duration::from_millis(50));
 //!         // Read lock is automatically released when guard is dropped
@@ -93,14 +93,51 @@
 //! // Writer thread that tries to upgrade (potential deadlock with readers)
 //! let rwlock_clone = Arc::clone(&rwlock);
 //! thread::spawn(move || {
-//!     let read_guard = rwlock_clone.read();
+//!     let read_guard = rwlock_clone.read().unwrap();
 //!     println!("Writer acquired read lock, attempting to upgrade...");
 //!     thread::sleep(Duration::from_millis(25));
-//!     let write_guard = rwlock_clone.write(); // This will deadlock!
+//!     let write_guard = rwlock_clone.write().unwrap(); // This will deadlock!
 //!     println!("Writer acquired write lock");
 //! });
 //! ```
 //!
+//! ### Reentrant Mutex Example
+//!
+//! ```rust
+//! use deloxide::{Deloxide, ReentrantMutex};
+//!
+//! let _ = Deloxide::new().start();
+//!
+//! let mutex = ReentrantMutex::new(0);
+//!
+//! // The owning thread may re-acquire the lock without deadlocking itself
+//! let guard1 = mutex.lock().unwrap();
+//! let guard2 = mutex.lock().unwrap();
+//! assert_eq!(*guard1, *guard2);
+//! ```
+//!
+//! ### Barrier Example
+//!
+//! ```rust
+//! use deloxide::{Barrier, thread};
+//! use std::sync::Arc;
+//!
+//! let barrier = Arc::new(Barrier::new(3));
+//! let mut handles = Vec::new();
+//!
+//! for _ in 0..3 {
+//!     let barrier = Arc::clone(&barrier);
+//!     handles.push(thread::spawn(move || {
+//!         // All three threads rendezvous here before any of them continues
+//!         barrier.wait();
+//!     }));
+//! }
+//!
+//! for handle in handles {
+//!     handle.join().unwrap();
+//! }
+//! ```
+//!
 //! ### Condvar Example
 //!
 //! ```rust
@@ -117,9 +154,9 @@
 //! // Thread waiting on condition
 //! thread::spawn(move || {
 //!     let (mutex, condvar) = (&pair2.0, &pair2.1);
-//!     let mut ready = mutex.lock();
+//!     let mut ready = mutex.lock().unwrap();
 //!     while !*ready {
-//!         condvar.wait(&mut ready);
+//!         condvar.wait(&mut ready).unwrap();
 //!     }
 //! });
 //!
@@ -128,7 +165,7 @@
 //! thread::spawn(move || {
 //!     thread::sleep(Duration::from_millis(50));
 //!     let (mutex, condvar) = (&pair3.0, &pair3.1);
-//!     let mut ready = mutex.lock();
+//!     let mut ready = mutex.lock().unwrap();
 //!     *ready = true;
 //!     condvar.notify_one();
 //! });
@@ -166,6 +203,13 @@
 //! # }
 //! ```
 //!
+//! `showcase`/`showcase_this` upload the encoded log to a hosted renderer, which
+//! needs network access and isn't appropriate for sensitive lock/thread topology.
+//! [`showcase_to_file`](crate::showcase_to_file) and
+//! [`showcase_local_server`](crate::showcase_local_server) render the same
+//! visualization entirely offline instead, either as a standalone HTML file or
+//! from a short-lived local server, so the log data never leaves the machine.
+//!
 //! ## Lock Order Graph (optional feature)
 //!
 //! Enable the `lock-order-graph` feature to detect potential deadlocks by tracking
@@ -193,6 +237,42 @@
 //!             }
 //!             DeadlockSource::LockOrderViolation => {
 //!                 println!("⚠️  SUSPECTED DEADLOCK! Dangerous lock ordering pattern.");
+//!                 for site in &info.lock_order_sites {
+//!                     println!(
+//!                         "  lock {} before {} acquired here {:?}, conflicting {} before {} acquired here {:?}",
+//!                         site.before, site.after, site.site, site.after, site.before, site.conflicting_site
+//!                     );
+//!                 }
+//!             }
+//!             DeadlockSource::SelfDeadlock => {
+//!                 println!("🔁 Thread re-acquired a lock it already holds.");
+//!             }
+//!             DeadlockSource::Watchdog => {
+//!                 println!("🐢 Background watchdog found a stalled or cyclic thread.");
+//!             }
+//!             DeadlockSource::AbandonedLock => {
+//!                 println!("💀 A thread panicked while still holding a lock: {:?}", info.panic_message);
+//!             }
+//!             DeadlockSource::PriorityInversion => {
+//!                 println!("⏳ Priority inversion hazard: {:?}", info.priority_chain);
+//!             }
+//!             DeadlockSource::BarrierStarvation => {
+//!                 println!(
+//!                     "🚧 Barrier can never fill: {:?} arrived, {:?} more needed",
+//!                     info.thread_cycle, info.barrier_missing
+//!                 );
+//!             }
+//!             DeadlockSource::CondvarHeldLock => {
+//!                 println!("😴 Thread parked on a condvar while still holding a needed lock.");
+//!             }
+//!             DeadlockSource::WriterStarvation => {
+//!                 println!("✍️  Writer starved past threshold: {:?}", info.stalled_threads);
+//!             }
+//!             DeadlockSource::CondvarNotificationStarvation => {
+//!                 println!(
+//!                     "🔕 Every live thread is blocked, no one left to notify: {:?}",
+//!                     info.stalled_threads
+//!                 );
 //!             }
 //!         }
 //!     })
@@ -231,6 +311,10 @@
 //!         min_delay_us: 200,
 //!         max_delay_us: 1500,
 //!         preempt_after_release: true,
+//!         fair_unlock: false,
+//!         seed: None,
+//!         pct_depth: 3,
+//!         pct_estimated_steps: 50,
 //!     })
 //!     .start()
 //!     .unwrap();
@@ -239,21 +323,73 @@
 
 mod core;
 pub use core::{
-    Deloxide,
+    Deloxide, FlushGuard,
+    detector::{blockers_of, detect_all_deadlocks, in_cycle, reachable_from},
+    locks::barrier::{Barrier, BarrierWaitResult},
     locks::condvar::Condvar,
-    locks::mutex::{Mutex, MutexGuard},
-    locks::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+    locks::debug::{
+        DebugMutex, DebugMutexGuard, DebugRwLock, DebugRwLockReadGuard, DebugRwLockWriteGuard,
+    },
+    locks::fair_mutex::{DEFAULT_FAIRNESS_THRESHOLD, FairMutex, FairMutexGuard, WaitStats},
+    locks::mutex::{MappedMutexGuard, Mutex, MutexGuard, OwnedMutexGuard},
+    locks::once::{LazyLock, Once, OnceLock},
+    locks::priority_mutex::{PriorityMutex, PriorityMutexGuard},
+    locks::reentrant_mutex::{ReentrantMutex, ReentrantMutexGuard},
+    locks::rwlock::{RwLock, RwLockReadGuard, RwLockUpgradableReadGuard, RwLockWriteGuard},
     thread,
-    types::{DeadlockInfo, DeadlockSource, LockId, ThreadId},
+    types::{
+        DEFAULT_PRIORITY, DeadlockInfo, DeadlockSource, LockHeldState, LockId, LockOrderEdgeSite,
+        LockOrderViolationPolicy, Priority, RwLockFairness, ThreadId, ThreadLockSite, ThreadStall,
+    },
 };
 
 #[cfg(feature = "stress-test")]
 pub use core::{StressConfig, StressMode};
 
+/// Reload a decision stream recorded by a seeded [`StressConfig`] run, forcing
+/// the exact same interleaving on replay. See [`core::stress::replay`].
+#[cfg(all(feature = "stress-test", feature = "logging-and-visualization"))]
+pub use core::stress::replay as replay_stress_log;
+
+/// Deterministic, systematically-varied RwLock schedule exploration,
+/// inspired by loom's bounded model checking. See [`core::explore`].
+#[cfg(feature = "schedule-explore")]
+pub use core::explore::{
+    Choice as ScheduleChoice, ExploreOutcome, current_trace as current_schedule_trace,
+    explore as explore_schedules, replay as replay_schedule, reset as reset_schedule_explorer,
+};
+
+/// On-demand lock-order inversion reporting. See [`core::detector::report_lock_order`]
+/// and [`core::detector::take_lock_order_violations`].
+#[cfg(feature = "lock-order-graph")]
+pub use core::detector::{report_lock_order, take_lock_order_violations};
+
+#[cfg(feature = "distributed")]
+pub use core::{
+    distributed::DistributedNodeId,
+    types::ProcessId,
+};
+
+#[cfg(feature = "async")]
+pub use core::{
+    locks::async_mutex::{AsyncMutex, AsyncMutexGuard},
+    locks::async_rwlock::{AsyncRwLock, AsyncRwLockReadGuard, AsyncRwLockWriteGuard},
+    types::TaskId,
+};
+
 #[cfg(feature = "logging-and-visualization")]
 mod showcase;
 #[cfg(feature = "logging-and-visualization")]
-pub use showcase::{showcase, showcase_this};
+pub use showcase::{
+    Compressor, DeadlockCompact, ReplayEvent, Trace, decode_url_to_events,
+    decode_url_to_events_with_passphrase, replay_trace, showcase, showcase_encrypted,
+    showcase_local_server, showcase_this, showcase_to_file, showcase_with_compressor,
+};
+#[cfg(feature = "logging-and-visualization")]
+pub use core::LogFormat;
+#[cfg(feature = "logging-and-visualization")]
+pub use core::detector::flush_global_detector_logs as flush_logs;
+pub use core::logger::flush_on_panic;
 
 pub mod ffi;
 
@@ -1,15 +1,35 @@
 use crate::core::types::DeadlockInfo;
+use crate::showcase::compression::Compressor;
+use crate::showcase::encryption;
 use anyhow::{Context, Result};
 use base64::alphabet::URL_SAFE;
 use base64::engine::{Engine as _, general_purpose};
-use flate2::Compression;
-use flate2::write::GzEncoder;
 use rmp_serde;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 
+/// Magic bytes identifying a deloxide replay blob, checked by
+/// [`decode_url_to_events`] before trusting the MessagePack payload that
+/// follows them
+const REPLAY_MAGIC: [u8; 4] = *b"DLXR";
+
+/// Current replay format version, prefixed (after [`REPLAY_MAGIC`]) onto every
+/// blob [`process_log_for_url`] produces
+///
+/// Bump this whenever the compact [`Event`] tuple shape or
+/// [`parse_log_entry`]'s event codes change in a way that would make an old
+/// blob decode into the wrong thing; [`decode_url_to_events`] rejects any
+/// blob whose version it doesn't recognize instead of silently misreading it.
+const REPLAY_VERSION: u8 = 1;
+
+/// Log files above this size make [`encode_log`] switch to [`encode_log_stream`]
+/// so the full event list and MessagePack buffer are never built in memory at
+/// once; below it, the simpler in-memory path is fine and is what the test
+/// suite exercises.
+const STREAM_THRESHOLD_BYTES: u64 = 8 * 1024 * 1024;
+
 /// Converts a log file to a compact, compressed, encoded format suitable for URL parameters
 ///
 /// This function processes a Deloxide log file and converts it into a format that can be
@@ -21,6 +41,9 @@ use std::path::Path;
 /// 4. Compress using GZIP
 /// 5. Encode using Base64URL for safe transmission in URLs
 ///
+/// Equivalent to [`encode_log_with_compressor`] with [`Compressor::Gzip`], which
+/// has always been this pipeline's compression stage.
+///
 /// # Arguments
 /// * `log_path` - Path to the original log file
 ///
@@ -34,54 +57,274 @@ use std::path::Path;
 /// - Failed to compress or encode the data
 /// ```
 pub(crate) fn process_log_for_url<P: AsRef<Path>>(log_path: P) -> Result<String> {
-    // Parse the input file
-    let file = File::open(log_path).context("Failed to open log file")?;
-    let reader = BufReader::new(file);
+    encode_log_with_compressor(log_path, Compressor::Gzip)
+}
+
+/// Like [`process_log_for_url`], but with the compression stage configurable
+///
+/// The motivation is that gzip at [`flate2::Compression::best()`] dominates
+/// encode latency for 50k-100k-event logs, while [`Compressor::Lz4`] compresses
+/// an order of magnitude faster (at a modest ratio cost) and [`Compressor::Zstd`]
+/// gives both better ratios and a tunable level - callers processing very large
+/// logs can trade ratio for latency without touching the rest of the pipeline.
+///
+/// # Arguments
+/// * `log_path` - Path to the original log file
+/// * `compressor` - Which codec to compress the MessagePack payload with
+///
+/// # Errors
+/// Returns an error if:
+/// - Failed to open the log file
+/// - Failed to read or parse the log file
+/// - Failed to compress or encode the data
+pub(crate) fn encode_log_with_compressor<P: AsRef<Path>>(
+    log_path: P,
+    compressor: Compressor,
+) -> Result<String> {
+    encode_log(log_path, compressor, None)
+}
+
+/// Like [`encode_log_with_compressor`], but with an optional passphrase to
+/// encrypt the encoded blob
+///
+/// Lock-trace logs leak thread/resource topology and timing that users may
+/// not want exposed to anyone who sees the shared URL. When `passphrase` is
+/// `Some`, the compressed-and-checksummed blob is encrypted with
+/// ChaCha20-Poly1305 (see [`crate::showcase::encryption`]) before base64, and
+/// [`decode_url_to_events_with_passphrase`] requires the same passphrase to
+/// read it back; when `None`, the pipeline behaves exactly like
+/// [`encode_log_with_compressor`].
+///
+/// # Arguments
+/// * `log_path` - Path to the original log file
+/// * `compressor` - Which codec to compress the MessagePack payload with
+/// * `passphrase` - If present, encrypts the blob so only holders of the same
+///   passphrase can decode it
+///
+/// # Errors
+/// Returns an error if:
+/// - Failed to open the log file
+/// - Failed to read or parse the log file
+/// - Failed to compress, encrypt, or encode the data
+pub(crate) fn encode_log<P: AsRef<Path>>(
+    log_path: P,
+    compressor: Compressor,
+    passphrase: Option<&str>,
+) -> Result<String> {
+    let log_path = log_path.as_ref();
+    let log_size = std::fs::metadata(log_path)
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+
+    // Large logs (and any codec that supports it) go through the streaming
+    // encoder so the full event list and MessagePack buffer are never built
+    // in memory at once; see `encode_log_stream`'s doc comment for why
+    // `Compressor::Lz4` is excluded.
+    let compressed = if log_size > STREAM_THRESHOLD_BYTES && !matches!(compressor, Compressor::Lz4)
+    {
+        let mut compressed = Vec::new();
+        encode_log_stream(log_path, &mut compressed, compressor)?;
+        compressed
+    } else {
+        // Parse the input file
+        let file = File::open(log_path).context("Failed to open log file")?;
+        let reader = BufReader::new(file);
+
+        // Create compact data structure
+        let mut compact_events = Vec::new();
+        let mut terminal_deadlock: Option<DeadlockCompact> = None;
+
+        // Process each line
+        for line in reader.lines() {
+            let line = line.context("Failed to read line from log file")?;
+            if let Ok(entry) = serde_json::from_str::<LogEntry>(&line) {
+                // Process each log entry
+                let event = parse_log_entry(entry).context("Failed to parse log entry")?;
+                compact_events.push(event);
+            } else if let Ok(dl) = serde_json::from_str::<DeadlockRecord>(&line) {
+                terminal_deadlock = Some(DeadlockCompact {
+                    thread_cycle: dl.deadlock.thread_cycle.iter().map(|&t| t as u64).collect(),
+                    thread_waiting_for_locks: dl
+                        .deadlock
+                        .thread_waiting_for_locks
+                        .iter()
+                        .map(|&(t, l)| (t as u64, l as u64))
+                        .collect(),
+                    timestamp: dl.deadlock.timestamp.clone(),
+                });
+            }
+        }
+
+        // Encode as a fixed 2-tuple: [events, deadlock_or_null]
+        let compact_output: (Events, Option<DeadlockCompact>) =
+            (compact_events, terminal_deadlock);
+
+        // 1. Convert to MessagePack
+        let msgpack = rmp_serde::to_vec(&compact_output)
+            .context("Failed to convert data to MessagePack")?;
+
+        // 1b. Prefix the version/magic header so the format can evolve safely -
+        // see `decode_url_to_events`, which reverses this exact pipeline.
+        let mut payload = Vec::with_capacity(REPLAY_MAGIC.len() + 1 + msgpack.len());
+        payload.extend_from_slice(&REPLAY_MAGIC);
+        payload.push(REPLAY_VERSION);
+        payload.extend_from_slice(&msgpack);
+
+        // 2. Compress with the selected codec
+        compressor.compress(&payload)?
+    };
 
-    // Create compact data structure
-    let mut compact_events = Vec::new();
+    // 2b. Prepend the codec's one-byte tag so `decode_url_to_events` can
+    // dispatch to the matching decompressor without out-of-band configuration.
+    let mut tagged = Vec::with_capacity(1 + compressed.len());
+    tagged.push(compressor.tag());
+    tagged.extend_from_slice(&compressed);
+
+    // 2c. Prepend a CRC32C checksum of the compressed bytes so a truncated or
+    // corrupted blob (e.g. a shared URL mangled by an email client or chat
+    // app) is rejected immediately by `decode_url_to_events` instead of
+    // surfacing a cryptic panic or error deep inside decompression.
+    let checksum = crc32c::crc32c(&compressed);
+    let mut checked = Vec::with_capacity(4 + tagged.len());
+    checked.extend_from_slice(&checksum.to_le_bytes());
+    checked.extend_from_slice(&tagged);
+
+    // 2d. Optionally encrypt everything so far behind a passphrase, prefixing
+    // a one-byte flag so `decode_url_to_events_with_passphrase` knows whether
+    // to decrypt before parsing the checksum/tag/compressed layers.
+    let final_bytes = match passphrase {
+        Some(passphrase) => {
+            let ciphertext = encryption::encrypt(&checked, passphrase)?;
+            let mut out = Vec::with_capacity(1 + ciphertext.len());
+            out.push(1u8);
+            out.extend_from_slice(&ciphertext);
+            out
+        }
+        None => {
+            let mut out = Vec::with_capacity(1 + checked.len());
+            out.push(0u8);
+            out.extend_from_slice(&checked);
+            out
+        }
+    };
+
+    // 3. Apply Base64URL encoding
+    let base64_engine = general_purpose::GeneralPurpose::new(&URL_SAFE, general_purpose::PAD);
+    let encoded = base64_engine.encode(final_bytes);
+
+    Ok(encoded)
+}
+
+/// Compress `log_path` without ever holding its full event list or
+/// MessagePack buffer in memory at once
+///
+/// [`encode_log`]'s in-memory path builds a full `Vec<CompactEvent>` and a
+/// full MessagePack `Vec<u8>` before compressing - fine for modestly sized
+/// logs, wasteful at 100k+ events. This makes two bounded-memory passes over
+/// `log_path` instead: the first counts how many event records it contains
+/// and captures the terminal deadlock record (small and fixed-size, so
+/// holding it isn't a problem), since MessagePack array headers are
+/// length-prefixed and can't be rewritten after the fact the way a JSON
+/// array can be left open-ended. The second pass streams each record
+/// straight through a MessagePack array writer wrapped in `compressor`'s
+/// streaming writer ([`Compressor::stream_writer`]) into `writer`, so memory
+/// use stays O(buffer) regardless of log length.
+///
+/// [`Compressor::Lz4`] isn't supported here - its block format needs the
+/// uncompressed length up front, which defeats the point of streaming; use
+/// [`Compressor::Gzip`] or [`Compressor::Zstd`] instead.
+///
+/// Unlike [`encode_log`], `writer` receives the raw compressed bytes with no
+/// tag, checksum, encryption, or base64 stage - those are applied uniformly
+/// by [`encode_log`] itself after calling this for large logs.
+///
+/// # Errors
+/// Returns an error if `log_path` can't be opened or read, if a log entry
+/// carries an event type [`parse_log_entry`] doesn't know, if `compressor`
+/// is [`Compressor::Lz4`], or if writing to `writer` fails. Lines that are
+/// neither a log entry nor a terminal deadlock record are skipped.
+pub(crate) fn encode_log_stream<P: AsRef<Path>, W: Write>(
+    log_path: P,
+    writer: W,
+    compressor: Compressor,
+) -> Result<()> {
+    let log_path = log_path.as_ref();
+
+    // Pass 1: count event records and capture the terminal deadlock, if any -
+    // both O(1) extra memory per line, never holding more than the current
+    // line and a handful of small fields.
+    let mut event_count: u64 = 0;
     let mut terminal_deadlock: Option<DeadlockCompact> = None;
+    {
+        let file = File::open(log_path).context("Failed to open log file")?;
+        for line in BufReader::new(file).lines() {
+            let line = line.context("Failed to read line from log file")?;
+            if serde_json::from_str::<LogEntry>(&line).is_ok() {
+                event_count += 1;
+            } else if let Ok(dl) = serde_json::from_str::<DeadlockRecord>(&line) {
+                terminal_deadlock = Some(DeadlockCompact {
+                    thread_cycle: dl.deadlock.thread_cycle.iter().map(|&t| t as u64).collect(),
+                    thread_waiting_for_locks: dl
+                        .deadlock
+                        .thread_waiting_for_locks
+                        .iter()
+                        .map(|&(t, l)| (t as u64, l as u64))
+                        .collect(),
+                    timestamp: dl.deadlock.timestamp.clone(),
+                });
+            }
+        }
+    }
 
-    // Process each line
-    for line in reader.lines() {
+    // Pass 2: stream each record straight into the compressor, never
+    // collecting them into a `Vec<Event>` first.
+    let file = File::open(log_path).context("Failed to re-open log file for streaming")?;
+    let mut sink = compressor.stream_writer(writer)?;
+
+    sink.write_all(&REPLAY_MAGIC)
+        .context("Failed to write replay magic")?;
+    sink.write_all(&[REPLAY_VERSION])
+        .context("Failed to write replay version")?;
+
+    rmp::encode::write_array_len(&mut sink, 2)
+        .context("Failed to write MessagePack header")?;
+    rmp::encode::write_array_len(&mut sink, event_count as u32)
+        .context("Failed to write MessagePack events array length")?;
+
+    for line in BufReader::new(file).lines() {
         let line = line.context("Failed to read line from log file")?;
         if let Ok(entry) = serde_json::from_str::<LogEntry>(&line) {
-            // Process each log entry
             let event = parse_log_entry(entry).context("Failed to parse log entry")?;
-            compact_events.push(event);
-        } else if let Ok(dl) = serde_json::from_str::<DeadlockRecord>(&line) {
-            terminal_deadlock = Some(DeadlockCompact {
-                thread_cycle: dl.deadlock.thread_cycle.iter().map(|&t| t as u64).collect(),
-                thread_waiting_for_locks: dl
-                    .deadlock
-                    .thread_waiting_for_locks
-                    .iter()
-                    .map(|&(t, l)| (t as u64, l as u64))
-                    .collect(),
-                timestamp: dl.deadlock.timestamp.clone(),
-            });
+            write_compact_event(&mut sink, event).context("Failed to write compact event")?;
         }
     }
 
-    // Encode as a fixed 2-tuple: [events, deadlock_or_null]
-    let compact_output: (Events, Option<DeadlockCompact>) = (compact_events, terminal_deadlock);
+    // The terminal deadlock is tiny, so it's simplest to serialize it as one
+    // self-contained MessagePack value and splice its bytes in directly,
+    // rather than hand-writing its (small, fixed) shape field by field.
+    let deadlock_bytes = rmp_serde::to_vec(&terminal_deadlock)
+        .context("Failed to encode terminal deadlock")?;
+    sink.write_all(&deadlock_bytes)
+        .context("Failed to write terminal deadlock")?;
 
-    // 1. Convert to MessagePack
-    let msgpack =
-        rmp_serde::to_vec(&compact_output).context("Failed to convert data to MessagePack")?;
+    sink.finish().context("Failed to finish compression")?;
 
-    // 2. Apply Gzip compression
-    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
-    encoder
-        .write_all(&msgpack)
-        .context("Failed to compress data")?;
-    let compressed = encoder.finish().context("Failed to finish compression")?;
-
-    // 3. Apply Base64URL encoding
-    let base64_engine = general_purpose::GeneralPurpose::new(&URL_SAFE, general_purpose::PAD);
-    let encoded = base64_engine.encode(compressed);
+    Ok(())
+}
 
-    Ok(encoded)
+/// Write one compact [`Event`] tuple as a 7-element MessagePack array - the
+/// streaming equivalent of what `rmp_serde` does for the whole `Events` vec
+fn write_compact_event<W: Write>(sink: &mut W, event: Event) -> Result<()> {
+    let (sequence, thread_id, lock_id, event_code, timestamp, parent_id, woken_thread) = event;
+    rmp::encode::write_array_len(sink, 7)?;
+    rmp::encode::write_uint(sink, sequence)?;
+    rmp::encode::write_uint(sink, thread_id)?;
+    rmp::encode::write_uint(sink, lock_id)?;
+    rmp::encode::write_uint(sink, event_code as u64)?;
+    rmp::encode::write_f64(sink, timestamp)?;
+    rmp::encode::write_uint(sink, parent_id)?;
+    rmp::encode::write_uint(sink, woken_thread)?;
+    Ok(())
 }
 
 /// Log entry structure from the file (simplified - no graph data)
@@ -103,6 +346,9 @@ struct LogEntry {
     /// Optional thread ID that was woken by condvar notify
     #[serde(default)]
     woken_thread: Option<u64>,
+    /// For a `StressDelay` event, the delay in microseconds that was chosen
+    #[serde(default)]
+    stress_delay_us: Option<u64>,
 }
 
 // Compact Event format: (sequence, thread_id, lock_id, event_code, timestamp, parent_id, woken_thread)
@@ -111,7 +357,7 @@ type Event = (u64, u64, u64, u8, f64, u64, u64);
 
 type Events = Vec<Event>;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeadlockCompact {
     pub thread_cycle: Vec<u64>,
     pub thread_waiting_for_locks: Vec<(u64, u64)>,
@@ -150,6 +396,10 @@ fn parse_log_entry(entry: LogEntry) -> Result<Event> {
         "CondvarSpawn" => 6u8,
         "CondvarExit" => 7u8,
 
+        // Barrier lifecycle
+        "BarrierSpawn" => 8u8,
+        "BarrierExit" => 9u8,
+
         // Mutex interactions
         "MutexAttempt" => 10u8,
         "MutexAcquired" => 11u8,
@@ -168,13 +418,41 @@ fn parse_log_entry(entry: LogEntry) -> Result<Event> {
         "CondvarWaitEnd" => 31u8,
         "CondvarNotifyOne" => 32u8,
         "CondvarNotifyAll" => 33u8,
+        "CondvarWaitTimedOut" => 48u8,
+
+        // Poisoning (distinguishes panic-induced stalls from true deadlocks)
+        "MutexPoisoned" => 40u8,
+        "RwPoisoned" => 41u8,
+
+        // RwLock upgradable-read interactions
+        "RwUpgradableAttempt" => 42u8,
+        "RwUpgradableAcquired" => 43u8,
+        "RwUpgradableReleased" => 44u8,
+        "RwUpgradeAcquired" => 45u8,
+        "RwDowngradedToUpgradable" => 51u8,
+
+        // Barrier interactions
+        "BarrierWaitBegin" => 46u8,
+        "BarrierWaitEnd" => 47u8,
+
+        // Time-bounded acquisition giving up before its deadline
+        "AcquireTimedOut" => 49u8,
+
+        // A stress-scheduler decision (see `core::stress::replay`)
+        "StressDelay" => 50u8,
 
         other => anyhow::bail!("Invalid event type: '{}'", other),
     };
 
-    // Convert parent_id and woken_thread to u64, using 0 to represent None
+    // Convert parent_id and woken_thread to u64, using 0 to represent None.
+    // StressDelay events don't have a woken thread, so that slot is reused to
+    // carry the chosen delay in microseconds (0 meaning "no delay chosen").
     let parent_id = entry.parent_id.unwrap_or(0);
-    let woken_thread = entry.woken_thread.unwrap_or(0);
+    let woken_thread = if event_code == 50u8 {
+        entry.stress_delay_us.unwrap_or(0)
+    } else {
+        entry.woken_thread.unwrap_or(0)
+    };
 
     let compact_event = (
         entry.sequence,
@@ -194,6 +472,249 @@ struct DeadlockRecord {
     deadlock: DeadlockInfo,
 }
 
+/// One decoded log event from a [`Trace`], the reverse of [`parse_log_entry`]'s
+/// compact tuple
+#[derive(Debug, Clone)]
+pub struct ReplayEvent {
+    /// Sequence number for deterministic ordering
+    pub sequence: u64,
+    /// ID of the thread involved in the event
+    pub thread_id: u64,
+    /// ID of the lock involved in the event
+    pub lock_id: u64,
+    /// Type of event
+    pub event: crate::core::types::Events,
+    /// Timestamp when the event occurred
+    pub timestamp: f64,
+    /// Parent/creator thread ID, for Spawn events
+    pub parent_id: Option<u64>,
+    /// Thread ID that was woken by a condvar notify
+    pub woken_thread: Option<u64>,
+    /// For a `StressDelay` event, the delay in microseconds that was chosen
+    pub stress_delay_us: Option<u64>,
+}
+
+/// A decoded replay: every event [`process_log_for_url`] encoded, plus the
+/// terminal deadlock record if the run ended in one
+///
+/// Produced by [`decode_url_to_events`] and consumed by
+/// [`crate::showcase::replay::replay_trace`].
+pub struct Trace {
+    events: Vec<ReplayEvent>,
+    deadlock: Option<DeadlockCompact>,
+}
+
+impl Trace {
+    /// Every event in the trace, in the order [`process_log_for_url`] encoded them
+    pub fn events(&self) -> &[ReplayEvent] {
+        &self.events
+    }
+
+    /// The terminal deadlock record, if the run that produced this trace ended in one
+    pub fn deadlock(&self) -> Option<&DeadlockCompact> {
+        self.deadlock.as_ref()
+    }
+}
+
+/// Reverse an [`Event`] tuple back into a typed [`ReplayEvent`]
+///
+/// The mirror of [`parse_log_entry`]: every event code from 10 up is matched
+/// back to the [`crate::core::types::Events`] variant it came from, while the
+/// spawn/exit codes 0-9 all map to `Events::Spawn` (even) or `Events::Exit`
+/// (odd), so the kind of object that was spawned or exited is not recovered.
+///
+/// # Errors
+/// Returns an error if the event code isn't one [`parse_log_entry`] produces.
+fn event_from_compact(event: Event) -> Result<ReplayEvent> {
+    use crate::core::types::Events;
+
+    let (sequence, thread_id, lock_id, event_code, timestamp, parent_id, woken_thread) = event;
+
+    let kind = match event_code {
+        0 | 2 | 4 | 6 | 8 => Events::Spawn,
+        1 | 3 | 5 | 7 | 9 => Events::Exit,
+
+        10 => Events::MutexAttempt,
+        11 => Events::MutexAcquired,
+        12 => Events::MutexReleased,
+
+        20 => Events::RwReadAttempt,
+        21 => Events::RwReadAcquired,
+        22 => Events::RwReadReleased,
+        23 => Events::RwWriteAttempt,
+        24 => Events::RwWriteAcquired,
+        25 => Events::RwWriteReleased,
+
+        30 => Events::CondvarWaitBegin,
+        31 => Events::CondvarWaitEnd,
+        32 => Events::CondvarNotifyOne,
+        33 => Events::CondvarNotifyAll,
+        48 => Events::CondvarWaitTimedOut,
+
+        40 => Events::MutexPoisoned,
+        41 => Events::RwPoisoned,
+
+        42 => Events::RwUpgradableAttempt,
+        43 => Events::RwUpgradableAcquired,
+        44 => Events::RwUpgradableReleased,
+        45 => Events::RwUpgradeAcquired,
+        51 => Events::RwDowngradedToUpgradable,
+
+        46 => Events::BarrierWaitBegin,
+        47 => Events::BarrierWaitEnd,
+
+        49 => Events::AcquireTimedOut,
+        50 => Events::StressDelay,
+
+        other => anyhow::bail!("Invalid event code: {}", other),
+    };
+
+    // parent_id/woken_thread were packed as u64 with 0 meaning None; StressDelay
+    // reuses the woken_thread slot to carry its chosen delay (see `parse_log_entry`).
+    let parent_id = if parent_id == 0 { None } else { Some(parent_id) };
+    let (woken_thread, stress_delay_us) = if event_code == 50 {
+        (None, if woken_thread == 0 { None } else { Some(woken_thread) })
+    } else {
+        (
+            if woken_thread == 0 { None } else { Some(woken_thread) },
+            None,
+        )
+    };
+
+    Ok(ReplayEvent {
+        sequence,
+        thread_id,
+        lock_id,
+        event: kind,
+        timestamp,
+        parent_id,
+        woken_thread,
+        stress_delay_us,
+    })
+}
+
+/// Decode a URL-encoded blob produced by [`process_log_for_url`] back into a [`Trace`]
+///
+/// Reverses every stage of the encoding pipeline in order: Base64URL decode,
+/// decompress with whichever [`Compressor`] its tag byte names, validate the
+/// [`REPLAY_MAGIC`]/[`REPLAY_VERSION`] header, then MessagePack-deserialize
+/// the remaining bytes and reconstruct each event with [`event_from_compact`].
+///
+/// Fails with a clear error if the blob was produced with a passphrase - use
+/// [`decode_url_to_events_with_passphrase`] for those.
+///
+/// # Errors
+/// Returns an error if the blob isn't valid Base64URL/compressed/MessagePack,
+/// if its checksum doesn't match (corrupted or truncated blob), if the header
+/// is missing or its magic/version doesn't match, if it was encrypted, or if
+/// it contains an event code [`event_from_compact`] doesn't recognize.
+pub fn decode_url_to_events(encoded: &str) -> Result<Trace> {
+    decode_url_to_events_impl(encoded, None)
+}
+
+/// Like [`decode_url_to_events`], but for a blob encrypted with
+/// [`encode_log`]'s `passphrase` argument
+///
+/// # Errors
+/// Same as [`decode_url_to_events`], plus an error if `passphrase` doesn't
+/// match the one the blob was encrypted with, or the blob wasn't encrypted.
+pub fn decode_url_to_events_with_passphrase(encoded: &str, passphrase: &str) -> Result<Trace> {
+    decode_url_to_events_impl(encoded, Some(passphrase))
+}
+
+fn decode_url_to_events_impl(encoded: &str, passphrase: Option<&str>) -> Result<Trace> {
+    let base64_engine = general_purpose::GeneralPurpose::new(&URL_SAFE, general_purpose::PAD);
+    let final_bytes = base64_engine
+        .decode(encoded)
+        .context("Failed to decode Base64URL data")?;
+
+    if final_bytes.is_empty() {
+        anyhow::bail!("Replay blob is too short to contain an encryption flag");
+    }
+    let (&encryption_flag, rest) = final_bytes.split_first().unwrap();
+    let checked = match (encryption_flag, passphrase) {
+        (0, None) => rest.to_vec(),
+        (0, Some(_)) => anyhow::bail!("This blob isn't encrypted; call decode_url_to_events instead"),
+        (1, Some(passphrase)) => encryption::decrypt(rest, passphrase)?,
+        (1, None) => anyhow::bail!(
+            "This blob is encrypted; call decode_url_to_events_with_passphrase instead"
+        ),
+        (other, _) => anyhow::bail!("Unknown encryption flag: {}", other),
+    };
+
+    if checked.len() < 4 + 1 {
+        anyhow::bail!("Replay blob is too short to contain a checksum and codec tag");
+    }
+    let (checksum_bytes, tagged) = checked.split_at(4);
+    let expected_checksum = u32::from_le_bytes(checksum_bytes.try_into().unwrap());
+
+    let (&tag, compressed) = tagged.split_first().unwrap();
+    let actual_checksum = crc32c::crc32c(compressed);
+    if actual_checksum != expected_checksum {
+        anyhow::bail!(
+            "ChecksumMismatch: replay blob is corrupted or truncated (expected CRC32C {:#010x}, got {:#010x})",
+            expected_checksum,
+            actual_checksum
+        );
+    }
+
+    let payload = Compressor::decompress(tag, compressed)?;
+
+    if payload.len() < REPLAY_MAGIC.len() + 1 {
+        anyhow::bail!("Replay blob is too short to contain a header");
+    }
+    let (header, msgpack) = payload.split_at(REPLAY_MAGIC.len() + 1);
+    let (magic, version) = header.split_at(REPLAY_MAGIC.len());
+    if magic != REPLAY_MAGIC {
+        anyhow::bail!("Not a deloxide replay blob (bad magic)");
+    }
+    if version[0] != REPLAY_VERSION {
+        anyhow::bail!(
+            "Unsupported replay format version {} (expected {})",
+            version[0],
+            REPLAY_VERSION
+        );
+    }
+
+    let (compact_events, deadlock): (Events, Option<DeadlockCompact>) =
+        rmp_serde::from_slice(msgpack).context("Failed to decode MessagePack data")?;
+
+    let events = compact_events
+        .into_iter()
+        .map(event_from_compact)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Trace { events, deadlock })
+}
+
+/// Stitch every newline-delimited JSON record in a log file into one JSON
+/// array, without going through the compact MessagePack/gzip/base64 pipeline
+/// [`process_log_for_url`] uses to stay inside a URL's length limit.
+///
+/// Used by the offline showcase exports ([`crate::showcase::offline`]), which
+/// write to a local file or serve from a local HTTP server and so have no
+/// such limit to economize for. Each line is already a complete JSON value
+/// (either a `LogEntry` or a terminal deadlock record), so this only trims
+/// and concatenates the non-empty lines; it neither parses nor validates them.
+///
+/// # Errors
+/// Returns an error if the log file can't be opened or read.
+pub(crate) fn read_log_as_json_array<P: AsRef<Path>>(log_path: P) -> Result<String> {
+    let file = File::open(log_path).context("Failed to open log file")?;
+    let reader = BufReader::new(file);
+
+    let mut entries = Vec::new();
+    for line in reader.lines() {
+        let line = line.context("Failed to read line from log file")?;
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            entries.push(trimmed.to_string());
+        }
+    }
+
+    Ok(format!("[{}]", entries.join(",")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +766,19 @@ mod tests {
             ("CondvarWaitEnd", 31u8),
             ("CondvarNotifyOne", 32u8),
             ("CondvarNotifyAll", 33u8),
+            ("CondvarWaitTimedOut", 48u8),
+            ("MutexPoisoned", 40u8),
+            ("RwPoisoned", 41u8),
+            ("RwUpgradableAttempt", 42u8),
+            ("RwUpgradableAcquired", 43u8),
+            ("RwUpgradableReleased", 44u8),
+            ("RwUpgradeAcquired", 45u8),
+            ("RwDowngradedToUpgradable", 51u8),
+            ("BarrierSpawn", 8u8),
+            ("BarrierExit", 9u8),
+            ("BarrierWaitBegin", 46u8),
+            ("BarrierWaitEnd", 47u8),
+            ("AcquireTimedOut", 49u8),
         ];
 
         for (event_name, expected_code) in events {
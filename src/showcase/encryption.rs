@@ -0,0 +1,68 @@
+use aead::rand_core::RngCore;
+use aead::{Aead, AeadCore, KeyInit, OsRng};
+use anyhow::{Context, Result};
+use argon2::Argon2;
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+/// Random per-encryption salt length, fed to Argon2id alongside the passphrase
+const SALT_LEN: usize = 16;
+
+/// ChaCha20-Poly1305 nonce length
+const NONCE_LEN: usize = 12;
+
+/// Derive a 32-byte ChaCha20-Poly1305 key from `passphrase` and `salt` via Argon2id
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key> {
+    let mut key_bytes = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key_bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to derive key from passphrase via Argon2id: {e}"))?;
+    Ok(*Key::from_slice(&key_bytes))
+}
+
+/// Encrypt `plaintext` under a key derived from `passphrase`
+///
+/// Returns `salt (16 bytes) || nonce (12 bytes) || ciphertext`; the salt and
+/// nonce are generated fresh on every call so the same passphrase never
+/// reuses a key/nonce pair, and both are stored alongside the ciphertext
+/// since [`decrypt`] needs them to re-derive the same key.
+pub(crate) fn encrypt(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new(&key);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|e| anyhow::anyhow!("Failed to encrypt showcase payload: {e}"))?;
+
+    let mut out = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Reverse [`encrypt`]: split the salt/nonce header off `data`, re-derive the
+/// key from `passphrase`, and decrypt the remaining ciphertext
+///
+/// # Errors
+/// Returns an error if `data` is too short to contain a salt and nonce, or if
+/// decryption fails (wrong passphrase or corrupted/tampered ciphertext -
+/// ChaCha20-Poly1305's authentication tag makes the two indistinguishable).
+pub(crate) fn decrypt(data: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    if data.len() < SALT_LEN + NONCE_LEN {
+        anyhow::bail!("Encrypted showcase blob is too short to contain a salt and nonce");
+    }
+    let (salt, rest) = data.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(passphrase, salt)?;
+    let cipher = ChaCha20Poly1305::new(&key);
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    cipher
+        .decrypt(nonce, ciphertext)
+        .context("Failed to decrypt showcase payload (wrong passphrase or corrupted blob)")
+}
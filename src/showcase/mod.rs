@@ -1,5 +1,15 @@
+pub mod compression;
 pub mod encoder;
-use encoder::process_log_for_url;
+pub(crate) mod encryption;
+pub mod offline;
+pub mod replay;
+use encoder::{encode_log, encode_log_with_compressor, process_log_for_url};
+pub use compression::Compressor;
+pub use encoder::{
+    DeadlockCompact, ReplayEvent, Trace, decode_url_to_events, decode_url_to_events_with_passphrase,
+};
+pub use offline::{showcase_local_server, showcase_to_file};
+pub use replay::replay_trace;
 
 use crate::core::detector::flush_global_detector_logs;
 use crate::core::logger::{self};
@@ -48,6 +58,58 @@ pub fn showcase<P: AsRef<Path>>(log_path: P) -> Result<()> {
     Ok(())
 }
 
+/// Like [`showcase`], but with the encode pipeline's compression codec configurable
+///
+/// Gzip at its best setting dominates encode latency for 50k-100k-event logs;
+/// pass [`Compressor::Lz4`] for roughly an order-of-magnitude faster encoding
+/// at a modest ratio cost, or [`Compressor::Zstd`] for a tunable middle ground.
+///
+/// # Errors
+/// Returns an error if:
+/// - Failed to read the log file
+/// - Failed to process the log file
+/// - Failed to open the browser
+pub fn showcase_with_compressor<P: AsRef<Path>>(log_path: P, compressor: Compressor) -> Result<()> {
+    let encoded_log = encode_log_with_compressor(&log_path, compressor)
+        .context("Failed to process log file for URL")?;
+
+    let showcase_url = format!("https://deloxide.vercel.app/?logs={encoded_log}");
+
+    webbrowser::open(&showcase_url).context("Failed to open browser")?;
+
+    Ok(())
+}
+
+/// Like [`showcase_with_compressor`], but encrypts the encoded blob with `passphrase`
+///
+/// Lock-trace logs leak thread/resource topology and timing a user may not
+/// want exposed to anyone who opens the shared URL. The blob is encrypted
+/// with ChaCha20-Poly1305 under a key derived from `passphrase` via Argon2id
+/// (see [`crate::showcase::encryption`]), so the link is only useful to
+/// someone who also has the passphrase, shared out-of-band. The viewer must
+/// be given the same passphrase to decode it, e.g. via
+/// [`decode_url_to_events_with_passphrase`].
+///
+/// # Errors
+/// Returns an error if:
+/// - Failed to read the log file
+/// - Failed to process, encrypt, or encode the log file
+/// - Failed to open the browser
+pub fn showcase_encrypted<P: AsRef<Path>>(
+    log_path: P,
+    compressor: Compressor,
+    passphrase: &str,
+) -> Result<()> {
+    let encoded_log = encode_log(&log_path, compressor, Some(passphrase))
+        .context("Failed to process log file for URL")?;
+
+    let showcase_url = format!("https://deloxide.vercel.app/?logs={encoded_log}");
+
+    webbrowser::open(&showcase_url).context("Failed to open browser")?;
+
+    Ok(())
+}
+
 /// Showcase the current active log file
 ///
 /// This is a convenience function that showcases the log file that was specified
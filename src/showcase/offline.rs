@@ -0,0 +1,150 @@
+//! Offline, self-contained showcase export
+//!
+//! `showcase`/`showcase_this` upload the encoded log to a hosted renderer at
+//! `https://deloxide.vercel.app`, which leaks lock/thread topology to a third
+//! party and requires network access. The functions here instead render a
+//! minimal, dependency-free visualization inline and keep the log data on
+//! the developer's machine: [`showcase_to_file`] writes it to a standalone
+//! HTML file, and [`showcase_local_server`] serves the same page from a
+//! short-lived `127.0.0.1` HTTP server for logs too large to comfortably
+//! hand around as a file (or just to skip the `file://` prompt some browsers
+//! show for local scripts).
+
+use super::encoder::read_log_as_json_array;
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::Write;
+use std::net::TcpListener;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const TEMPLATE_HEAD: &str = include_str!("offline_template_head.html");
+const TEMPLATE_TAIL: &str = include_str!("offline_template_tail.html");
+
+/// How long [`showcase_local_server`] keeps accepting connections before
+/// shutting down
+const SERVER_LIFETIME: Duration = Duration::from_secs(30);
+
+/// Build the standalone HTML page embedding `log_path`'s events inline
+fn build_standalone_html<P: AsRef<Path>>(log_path: P) -> Result<String> {
+    let events_json = read_log_as_json_array(&log_path)
+        .context("Failed to read log file for offline showcase export")?;
+
+    Ok(format!("{TEMPLATE_HEAD}{events_json}{TEMPLATE_TAIL}"))
+}
+
+/// Export a log file as a single standalone HTML file and open it in the
+/// default browser
+///
+/// Unlike [`crate::showcase`], this never leaves the machine: the encoded
+/// log is embedded directly in the HTML file as a script, so opening it is
+/// just opening a local `file://` URL.
+///
+/// # Arguments
+/// * `log_path` - Path to the log file to visualize
+/// * `out_html` - Path to write the standalone HTML file to
+///
+/// # Errors
+/// Returns an error if the log file can't be read, the HTML file can't be
+/// written, or the browser can't be opened
+///
+/// # Example
+///
+/// ```no_run
+/// use deloxide::showcase_to_file;
+///
+/// showcase_to_file("deadlock_log.json", "deadlock_report.html")
+///     .expect("Failed to export offline showcase");
+/// ```
+pub fn showcase_to_file<P: AsRef<Path>, Q: AsRef<Path>>(log_path: P, out_html: Q) -> Result<()> {
+    let html = build_standalone_html(log_path)?;
+    fs::write(out_html.as_ref(), html).context("Failed to write standalone showcase HTML file")?;
+
+    let url = format!("file://{}", out_html.as_ref().display());
+    webbrowser::open(&url).context("Failed to open browser")?;
+
+    Ok(())
+}
+
+/// Serve a log file's visualization from a short-lived local HTTP server
+///
+/// Renders the same standalone page as [`showcase_to_file`], but serves it
+/// from `http://127.0.0.1:<port>/` instead of writing a file, so very large
+/// logs aren't limited by anything a browser imposes on local files or
+/// `file://` URLs. The server accepts connections for a brief window (long
+/// enough for the browser to load the page and any follow-up requests) and
+/// then shuts down.
+///
+/// # Arguments
+/// * `log_path` - Path to the log file to visualize
+///
+/// # Errors
+/// Returns an error if the log file can't be read, a local port can't be
+/// bound, or the browser can't be opened
+///
+/// # Example
+///
+/// ```no_run
+/// use deloxide::showcase_local_server;
+///
+/// showcase_local_server("deadlock_log.json")
+///     .expect("Failed to serve offline showcase");
+/// ```
+pub fn showcase_local_server<P: AsRef<Path>>(log_path: P) -> Result<()> {
+    let html = build_standalone_html(log_path)?;
+
+    let listener =
+        TcpListener::bind("127.0.0.1:0").context("Failed to bind local showcase server")?;
+    let addr = listener
+        .local_addr()
+        .context("Failed to read local showcase server address")?;
+    listener
+        .set_nonblocking(true)
+        .context("Failed to configure local showcase server")?;
+
+    std::thread::spawn(move || {
+        let deadline = Instant::now() + SERVER_LIFETIME;
+        while Instant::now() < deadline {
+            match listener.accept() {
+                Ok((mut stream, _)) => {
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        html.len(),
+                        html
+                    );
+                    let _ = stream.write_all(response.as_bytes());
+                }
+                Err(_) => std::thread::sleep(Duration::from_millis(20)),
+            }
+        }
+    });
+
+    let url = format!("http://{addr}/");
+    webbrowser::open(&url).context("Failed to open browser")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write as _;
+
+    #[test]
+    fn test_build_standalone_html_embeds_the_log_inline() {
+        let dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let log_path = dir.path().join("sample.log");
+        let mut file = fs::File::create(&log_path).expect("Failed to create log file");
+        writeln!(
+            file,
+            r#"{{"sequence":0,"thread_id":1,"lock_id":2,"event":"MutexSpawn","timestamp":1.0}}"#
+        )
+        .unwrap();
+
+        let html = build_standalone_html(&log_path).expect("Failed to build standalone HTML");
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("MutexSpawn"));
+        assert!(html.contains("LOG_DATA"));
+    }
+}
@@ -0,0 +1,82 @@
+//! Offline replay driver for a decoded [`Trace`]
+//!
+//! Feeds a [`Trace`]'s events into a fresh, standalone [`Detector`] (never the
+//! process-wide `GLOBAL_DETECTOR`) in sequence order, so a reported deadlock
+//! can be reproduced from its log alone, without re-running the original
+//! program.
+
+use crate::core::Detector;
+use crate::core::types::{DEFAULT_PRIORITY, DeadlockInfo, Events, LockId, ThreadId};
+use crate::showcase::encoder::{ReplayEvent, Trace};
+use fxhash::FxHashMap;
+
+/// Replay every event in `trace` through a fresh [`Detector`] and return the
+/// first deadlock it reproduces, if any
+///
+/// Only thread lifecycle and mutex attempt/acquire/release events are
+/// replayed. The compact event format (see [`crate::showcase::encoder`])
+/// collapses every kind of lock's spawn/exit into the same generic
+/// [`Events::Spawn`]/[`Events::Exit`] codes a thread spawn/exit uses, with no
+/// way to tell a mutex's spawn from an RwLock's, a Condvar's, or a Barrier's -
+/// so rather than guess, this only acts on the unambiguous case
+/// (`lock_id == 0`, a thread event) and otherwise ignores RwLock, Condvar,
+/// and Barrier events entirely. A deadlock that only manifests through one of
+/// those primitives won't be reproduced here even if it's present in the
+/// trace.
+///
+/// Events are replayed in `sequence` order, independent of the order they
+/// appear in [`Trace::events`].
+pub fn replay_trace(trace: &Trace) -> Option<DeadlockInfo> {
+    let mut detector = Detector::new();
+    let mut owners: FxHashMap<LockId, ThreadId> = FxHashMap::default();
+
+    let mut events: Vec<&ReplayEvent> = trace.events().iter().collect();
+    events.sort_by_key(|event| event.sequence);
+
+    for event in events {
+        let thread_id = event.thread_id as ThreadId;
+        let lock_id = event.lock_id as LockId;
+
+        match event.event {
+            Events::Spawn if lock_id == 0 => {
+                let parent_id = event.parent_id.map(|id| id as ThreadId);
+                detector.on_thread_spawn(thread_id, parent_id, DEFAULT_PRIORITY);
+            }
+            Events::Exit if lock_id == 0 => {
+                if let Some(info) = detector.on_thread_exit(thread_id) {
+                    return Some(info);
+                }
+            }
+            Events::MutexAttempt => {
+                let potential_owner = owners.get(&lock_id).copied();
+                let (cycle, inversion) =
+                    detector.acquire_slow(thread_id, lock_id, potential_owner, None);
+
+                if let Some(info) = inversion {
+                    return Some(info);
+                }
+                if let Some(cycle) = cycle {
+                    let info = if cycle.as_slice() == [thread_id] {
+                        detector.extract_self_deadlock_info(thread_id, lock_id)
+                    } else {
+                        detector.extract_deadlock_info(cycle)
+                    };
+                    return Some(info);
+                }
+            }
+            Events::MutexAcquired => {
+                owners.insert(lock_id, thread_id);
+                if let Some(info) = detector.complete_acquire(thread_id, lock_id, None) {
+                    return Some(info);
+                }
+            }
+            Events::MutexReleased => {
+                owners.remove(&lock_id);
+                detector.release_mutex(thread_id, lock_id);
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
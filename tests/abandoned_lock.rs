@@ -0,0 +1,44 @@
+use deloxide::{DeadlockSource, Mutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+#[test]
+fn test_panic_while_holding_lock_reports_abandoned_lock() {
+    let harness = start_detector();
+
+    let mutex = Arc::new(Mutex::new(0));
+
+    let owner = Arc::clone(&mutex);
+    let owner_handle = thread::spawn(move || {
+        let _guard = owner.lock().unwrap();
+        thread::sleep(Duration::from_millis(200));
+        panic!("owner thread dies while still holding the lock");
+    });
+
+    // Give the owner a head start so it's actually holding the lock before
+    // this thread blocks on it.
+    thread::sleep(Duration::from_millis(50));
+
+    let waiter = Arc::clone(&mutex);
+    let waiter_handle = thread::spawn(move || {
+        let _ = waiter.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.source, DeadlockSource::AbandonedLock);
+    assert_eq!(info.thread_cycle.len(), 1, "owner is the sole cycle entry");
+    assert_eq!(info.thread_waiting_for_locks.len(), 1);
+    assert!(
+        info.panic_message
+            .as_deref()
+            .is_some_and(|msg| msg.contains("owner thread dies")),
+        "expected the owner's panic message to be recovered: {:?}",
+        info.panic_message
+    );
+
+    let _ = owner_handle.join();
+    waiter_handle.join().unwrap();
+    assert!(mutex.is_poisoned());
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "async")]
+
+use deloxide::{AsyncMutex, thread};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::time::Duration;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+// A waker that does nothing; these tests only need enough of an executor to
+// poll a future a fixed number of times, not to actually react to wakeups.
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    fn no_op(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+    unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+}
+
+/// Poll `fut` once, returning `true` if it resolved.
+fn poll_once<F: Future + Unpin>(fut: &mut F) -> bool {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    matches!(Pin::new(fut).poll(&mut cx), Poll::Ready(_))
+}
+
+#[test]
+fn test_dropping_a_pending_lock_future_retracts_its_wait_edge() {
+    let harness = start_detector();
+
+    let mutex = Arc::new(AsyncMutex::new(0));
+
+    // Hold the lock on a background thread so the lock future below stays pending.
+    let holder_mutex = Arc::clone(&mutex);
+    let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+    let holder = thread::spawn(move || {
+        futures_lite_block_on(async {
+            let _guard = holder_mutex.lock().await;
+            release_rx.recv().ok();
+        });
+    });
+
+    thread::sleep(Duration::from_millis(50));
+
+    // Poll the lock future once so it registers as a waiter, then drop it
+    // without ever completing - as `select!`/a timeout would.
+    let mut pending = mutex.lock();
+    assert!(
+        !poll_once(&mut pending),
+        "lock future should still be pending while the holder keeps the guard"
+    );
+    drop(pending);
+
+    release_tx.send(()).unwrap();
+    holder.join().unwrap();
+
+    // If the dropped future's wait-for edge wasn't retracted, it would still
+    // be sitting in the graph as a stale edge; a later unrelated cycle
+    // through the same task id could then spuriously appear as a deadlock.
+    // The lock should simply work again, with no phantom report.
+    futures_lite_block_on(async {
+        let mut guard = mutex.lock().await;
+        *guard += 1;
+    });
+    assert_eq!(*mutex.try_lock().unwrap(), 1);
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+/// A minimal single-future executor: spins polling until the future resolves.
+/// Sufficient for these tests since the futures involved only ever wake via
+/// another thread eventually releasing the lock, not via the waker itself.
+fn futures_lite_block_on<F: Future>(mut fut: F) -> F::Output {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    // SAFETY: `fut` is not moved again after being pinned here.
+    let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+        std::thread::yield_now();
+    }
+}
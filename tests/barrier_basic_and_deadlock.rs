@@ -0,0 +1,63 @@
+use deloxide::{Barrier, Mutex, thread};
+use std::sync::Arc;
+mod common;
+use common::{DEADLOCK_TIMEOUT, NO_DEADLOCK_TIMEOUT, assert_no_deadlock, expect_deadlock, start_detector};
+
+#[test]
+fn test_barrier_releases_all_parties_without_deadlock() {
+    let harness = start_detector();
+
+    let barrier = Arc::new(Barrier::new(3));
+    let mut handles = Vec::new();
+    for _ in 0..3 {
+        let barrier = Arc::clone(&barrier);
+        handles.push(thread::spawn(move || barrier.wait()));
+    }
+
+    let mut leaders = 0;
+    for handle in handles {
+        if handle.join().unwrap().is_leader() {
+            leaders += 1;
+        }
+    }
+    assert_eq!(leaders, 1, "Exactly one thread should be the leader");
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_barrier_as_rendezvous_for_guaranteed_two_lock_deadlock() {
+    let harness = start_detector();
+
+    let mutex_a = Arc::new(Mutex::new(0));
+    let mutex_b = Arc::new(Mutex::new(0));
+    // Ensures both threads have taken their first lock before either attempts the
+    // second, making the classic AB-BA deadlock below deterministic instead of
+    // relying on a sleep-based race.
+    let barrier = Arc::new(Barrier::new(2));
+
+    let a1 = Arc::clone(&mutex_a);
+    let b1 = Arc::clone(&mutex_b);
+    let barrier1 = Arc::clone(&barrier);
+    let _t1 = thread::spawn(move || {
+        let _guard_a = a1.lock().unwrap();
+        barrier1.wait();
+        let _guard_b = b1.lock().unwrap();
+    });
+
+    let a2 = Arc::clone(&mutex_a);
+    let b2 = Arc::clone(&mutex_b);
+    let barrier2 = Arc::clone(&barrier);
+    let _t2 = thread::spawn(move || {
+        let _guard_b = b2.lock().unwrap();
+        barrier2.wait();
+        let _guard_a = a2.lock().unwrap();
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(
+        info.thread_cycle.len(),
+        2,
+        "Deadlock should involve the two threads blocked on each other's mutex"
+    );
+}
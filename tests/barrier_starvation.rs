@@ -0,0 +1,46 @@
+use deloxide::{Barrier, DeadlockSource, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+#[test]
+fn test_barrier_starvation_reported_when_a_party_exits_without_arriving() {
+    let harness = start_detector();
+
+    // Two parties expected. One thread arrives and blocks; the other "takes a
+    // different branch" and exits without ever calling wait(). Once it exits,
+    // the arrived thread is the only live thread left, so the barrier can
+    // provably never fill.
+    let barrier = Arc::new(Barrier::new(2));
+
+    let arriving = Arc::clone(&barrier);
+    let _arriving_thread = thread::spawn(move || {
+        arriving.wait();
+    });
+
+    // Give the first thread time to actually arrive before the second one
+    // exits, so the exit is what makes the shortfall provable.
+    thread::sleep(Duration::from_millis(50));
+
+    let missing_thread = thread::spawn(|| {
+        // Never calls wait() on the barrier.
+    });
+    missing_thread.join().unwrap();
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.source, DeadlockSource::BarrierStarvation);
+    assert_eq!(
+        info.thread_cycle.len(),
+        1,
+        "only the arrived thread should be named"
+    );
+    assert_eq!(
+        info.barrier_missing,
+        Some(1),
+        "exactly one more party was needed"
+    );
+
+    // Don't wait for the arrived thread to complete since it's permanently
+    // blocked on a barrier that can never fill.
+}
@@ -24,12 +24,12 @@ fn test_condvar_cycle_deadlock() {
         let cv = Arc::clone(&cv);
         let ready = ready.clone();
         thread::spawn(move || {
-            let mut guard_a = m_a.lock();
+            let mut guard_a = m_a.lock().unwrap();
             while !*guard_a {
-                cv.wait(&mut guard_a); // releases A while asleep
+                cv.wait(&mut guard_a).unwrap(); // releases A while asleep
             }
             // now holds A again, tries to lock B  → deadlock
-            let _guard_b = m_b.lock();
+            let _guard_b = m_b.lock().unwrap();
             ready.store(true, Ordering::SeqCst); // never reached
         });
     }
@@ -43,9 +43,9 @@ fn test_condvar_cycle_deadlock() {
             // Small delay to ensure thread 1 gets to wait first
             std::thread::sleep(Duration::from_millis(10));
 
-            let _guard_b = m_b.lock(); // hold B first
+            let _guard_b = m_b.lock().unwrap(); // hold B first
             {
-                let mut guard_a = m_a.lock(); // now also A
+                let mut guard_a = m_a.lock().unwrap(); // now also A
                 *guard_a = true;
                 cv.notify_one();
                 drop(guard_a); // release A, keep B
@@ -55,7 +55,7 @@ fn test_condvar_cycle_deadlock() {
             std::thread::sleep(Duration::from_millis(10));
 
             // try to lock A again  → blocks (cycle)
-            let _guard_a2 = m_a.lock();
+            let _guard_a2 = m_a.lock().unwrap();
         }
     });
 
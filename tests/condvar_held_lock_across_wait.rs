@@ -0,0 +1,84 @@
+use deloxide::{Condvar, Mutex as DMutex, Thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+/// A thread that parks on a condvar while still holding some other lock can
+/// create a deadlock that never shows up as a wait-for-graph cycle in the
+/// usual sense: the sleeping thread holds no wait-for edge at all while
+/// parked, so nothing but this synchronous held-lock check would ever catch
+/// it before the (much slower) watchdog stall timer.
+///
+/// Chain: B waits on `cv` (bound to `m`). C locks `m`, notifies B (giving B a
+/// synthetic wait-for edge onto C for `m`), then - still holding `m` - blocks
+/// trying to lock `l`, which A holds. A, still holding `l`, then itself waits
+/// on the very same `cv` (bound to a different mutex `n`) - so the only
+/// thread that could ever notify B's real reacquire of `m` along (C) is
+/// transitively stuck waiting on a lock (`l`) that A now goes to sleep while
+/// still holding.
+#[test]
+fn test_held_lock_across_condvar_wait_is_a_deadlock() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(())); // bound mutex for B's wait
+    let n = Arc::new(DMutex::new(())); // bound mutex for A's wait
+    let l = Arc::new(DMutex::new(())); // the lock A holds across its wait
+    let cv = Arc::new(Condvar::new());
+
+    // Thread B: waits on `cv`/`m`. Once woken it can never actually finish
+    // reacquiring `m`, since C (the thread that wakes it) never lets go of it.
+    {
+        let m = Arc::clone(&m);
+        let cv = Arc::clone(&cv);
+        Thread::spawn(move || {
+            let mut guard = m.lock().unwrap();
+            println!("B: waiting on cv");
+            cv.wait(&mut guard).unwrap();
+            // Never reached: C holds `m` forever.
+            println!("B: woke up (unreachable)");
+            false
+        });
+    }
+
+    // Thread C: takes `m`, wakes B, then blocks forever trying to take `l`
+    // (held by A) - all while still holding `m`.
+    {
+        let m = Arc::clone(&m);
+        let l = Arc::clone(&l);
+        let cv = Arc::clone(&cv);
+        Thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            let _guard = m.lock().unwrap();
+            println!("C: got m, notifying B");
+            cv.notify_one();
+            println!("C: trying to get l...");
+            let _l_guard = l.lock().unwrap();
+            // Never reached: A holds `l` forever.
+            println!("C: got l (unreachable)");
+            false
+        });
+    }
+
+    // Thread A: holds `l` and `n` from the start, then waits on the same
+    // `cv` (bound to `n`) - while still holding `l`, which C is stuck on.
+    let _thread_a = {
+        let l = Arc::clone(&l);
+        let n = Arc::clone(&n);
+        let cv = Arc::clone(&cv);
+        Thread::spawn(move || {
+            let _l_guard = l.lock().unwrap();
+            let mut n_guard = n.lock().unwrap();
+            println!("A: got l and n, sleeping before waiting on cv");
+            std::thread::sleep(Duration::from_millis(150));
+            println!("A: waiting on cv while still holding l");
+            cv.wait(&mut n_guard).unwrap();
+            // Never reached if the held-lock hazard is caught synchronously.
+            println!("A: woke up (unreachable)");
+            false
+        })
+    };
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.thread_cycle.len(), 2);
+}
@@ -0,0 +1,40 @@
+use deloxide::{Condvar, Mutex as DMutex};
+mod common;
+use common::start_detector;
+
+#[test]
+#[should_panic(expected = "must always be used with the same mutex")]
+fn test_wait_with_different_mutex_panics() {
+    let _harness = start_detector();
+
+    let a = DMutex::new(());
+    let b = DMutex::new(());
+    let cv = Condvar::new();
+
+    let mut guard_a = a.lock().unwrap();
+    cv.wait_timeout(&mut guard_a, std::time::Duration::from_millis(1))
+        .unwrap();
+    drop(guard_a);
+
+    // Reusing the same condvar with a different mutex is undefined
+    // behavior for the underlying primitive and must be rejected.
+    let mut guard_b = b.lock().unwrap();
+    cv.wait_timeout(&mut guard_b, std::time::Duration::from_millis(1))
+        .unwrap();
+}
+
+#[test]
+fn test_wait_with_same_mutex_repeatedly_is_fine() {
+    let harness = start_detector();
+
+    let m = DMutex::new(());
+    let cv = Condvar::new();
+
+    for _ in 0..3 {
+        let mut guard = m.lock().unwrap();
+        cv.wait_timeout(&mut guard, std::time::Duration::from_millis(1))
+            .unwrap();
+    }
+
+    common::assert_no_deadlock(&harness, common::NO_DEADLOCK_TIMEOUT);
+}
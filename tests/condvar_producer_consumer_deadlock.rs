@@ -21,7 +21,7 @@ fn test_condvar_producer_consumer_deadlock() {
 
         Thread::spawn(move || {
             // Producer holds buffer mutex
-            let mut buffer = buffer_mutex.lock();
+            let mut buffer = buffer_mutex.lock().unwrap();
             println!("Producer: Got buffer mutex");
 
             // Initialize buffer to be "full" to force waiting
@@ -32,7 +32,7 @@ fn test_condvar_producer_consumer_deadlock() {
             // Simulate buffer being full - wait for consumer to make space
             while buffer.len() >= 5 {
                 println!("Producer: Buffer full, waiting for space...");
-                producer_cv.wait(&mut buffer); // Releases buffer_mutex while waiting
+                producer_cv.wait(&mut buffer).unwrap(); // Releases buffer_mutex while waiting
             }
             // Buffer mutex is reacquired here
             println!("Producer: Woke up, buffer mutex reacquired");
@@ -40,7 +40,7 @@ fn test_condvar_producer_consumer_deadlock() {
             // Try to access consumer resource → DEADLOCK
             // Consumer holds consumer_mutex and is trying to get buffer_mutex
             println!("Producer: Trying to get consumer resource...");
-            let _consumer_resource = consumer_mutex.lock();
+            let _consumer_resource = consumer_mutex.lock().unwrap();
 
             // This code is never reached
             buffer.push(42);
@@ -59,12 +59,12 @@ fn test_condvar_producer_consumer_deadlock() {
             std::thread::sleep(Duration::from_millis(50));
 
             // Consumer holds its resource first
-            let _consumer_resource = consumer_mutex.lock();
+            let _consumer_resource = consumer_mutex.lock().unwrap();
             println!("Consumer: Got consumer mutex");
 
             // Actually make space in the buffer so producer can proceed
             {
-                let mut buffer = buffer_mutex.lock();
+                let mut buffer = buffer_mutex.lock().unwrap();
                 if !buffer.is_empty() {
                     buffer.pop();
                     println!("Consumer: Removed item from buffer, space available");
@@ -81,7 +81,7 @@ fn test_condvar_producer_consumer_deadlock() {
             // Try to access buffer → DEADLOCK
             // Producer holds buffer_mutex and is trying to get consumer_mutex (which we hold)
             println!("Consumer: Trying to get buffer mutex...");
-            let _buffer = buffer_mutex.lock();
+            let _buffer = buffer_mutex.lock().unwrap();
 
             // This code is never reached
             println!("Consumer: Got buffer mutex");
@@ -19,10 +19,10 @@ fn test_condvar_spurious_wakeup_no_deadlock() {
         let cv = Arc::clone(&cv);
         let notify_count = Arc::clone(&notify_count);
         thread::spawn(move || {
-            let mut g = m.lock();
+            let mut g = m.lock().unwrap();
             // Typical condvar loop against spurious wakeups
             while !*g {
-                cv.wait(&mut g);
+                cv.wait(&mut g).unwrap();
             }
             notify_count.fetch_add(1, Ordering::SeqCst);
         });
@@ -36,7 +36,7 @@ fn test_condvar_spurious_wakeup_no_deadlock() {
 
     // Set predicate and notify once to complete
     {
-        let mut g = m.lock();
+        let mut g = m.lock().unwrap();
         *g = true;
     }
     cv.notify_one();
@@ -0,0 +1,49 @@
+use deloxide::{Condvar, Mutex as DMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+#[test]
+fn test_wait_timeout_expires_without_notify() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(()));
+    let cv = Arc::new(Condvar::new());
+
+    let mut guard = m.lock().unwrap();
+    let timed_out = cv.wait_timeout(&mut guard, Duration::from_millis(50)).unwrap();
+    drop(guard);
+
+    assert!(timed_out, "wait_timeout should report a timeout when never notified");
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_wait_timeout_woken_by_notify() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(false));
+    let cv = Arc::new(Condvar::new());
+
+    {
+        let m = Arc::clone(&m);
+        let cv = Arc::clone(&cv);
+        thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            let mut g = m.lock().unwrap();
+            *g = true;
+            drop(g);
+            cv.notify_one();
+        });
+    }
+
+    let mut guard = m.lock().unwrap();
+    let timed_out = cv
+        .wait_timeout_while(&mut guard, Duration::from_secs(3), |ready| !*ready)
+        .unwrap();
+    drop(guard);
+
+    assert!(!timed_out, "wait should complete via notify before the deadline");
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
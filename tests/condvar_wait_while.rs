@@ -0,0 +1,67 @@
+use deloxide::{Condvar, Mutex as DMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+#[test]
+fn test_wait_while_survives_multiple_notifications_before_condition_holds() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(0));
+    let cv = Arc::new(Condvar::new());
+
+    {
+        let m = Arc::clone(&m);
+        let cv = Arc::clone(&cv);
+        thread::spawn(move || {
+            for _ in 0..3 {
+                std::thread::sleep(Duration::from_millis(20));
+                let mut g = m.lock().unwrap();
+                *g += 1;
+                drop(g);
+                // Each notification re-wakes the waiter, which must re-check
+                // the predicate and correctly re-register its wait with the
+                // detector rather than leaving stale bookkeeping behind.
+                cv.notify_one();
+            }
+        });
+    }
+
+    let mut guard = m.lock().unwrap();
+    cv.wait_while(&mut guard, |count| *count < 3).unwrap();
+    assert_eq!(*guard, 3);
+    drop(guard);
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_wait_timeout_while_times_out_after_looping_on_a_false_alarm() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(false));
+    let cv = Arc::new(Condvar::new());
+
+    {
+        let m = Arc::clone(&m);
+        let cv = Arc::clone(&cv);
+        thread::spawn(move || {
+            // A spurious-style notification that never actually satisfies the
+            // predicate; the waiter must loop once, then genuinely time out
+            // rather than hanging or reporting a phantom deadlock.
+            std::thread::sleep(Duration::from_millis(20));
+            let _g = m.lock().unwrap();
+            cv.notify_one();
+        });
+    }
+
+    let mut guard = m.lock().unwrap();
+    let timed_out = cv
+        .wait_timeout_while(&mut guard, Duration::from_millis(100), |ready| !*ready)
+        .unwrap();
+    drop(guard);
+
+    assert!(timed_out, "predicate never became false, so this must time out");
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
@@ -0,0 +1,70 @@
+use deloxide::{DeadlockSource, Deloxide, Mutex as DMutex, thread};
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// With recovery enabled, the victim a test's own callback picks out of a
+/// genuine AB-BA cycle must have its pending wait-for edge retracted and its
+/// blocked `lock_for` call return `Abandoned` instead of hanging - while
+/// the other thread in the cycle is left to carry on blocking normally.
+#[test]
+fn test_configured_victim_is_abandoned_instead_of_deadlocking() {
+    let (tx, rx) = mpsc::channel();
+
+    let lock1 = Arc::new(DMutex::new(()));
+    let lock2 = Arc::new(DMutex::new(()));
+    let victim_lock1 = Arc::clone(&lock1);
+
+    Deloxide::new()
+        .with_deadlock_recovery(move |info| {
+            // Always pick the thread waiting on `lock1` (the one holding
+            // `lock2` and blocked trying to acquire `lock1`) as the victim.
+            info.thread_cycle
+                .iter()
+                .copied()
+                .find(|&t| info.thread_waiting_for_locks.contains(&(t, victim_lock1.id())))
+        })
+        .callback(move |info| {
+            let _ = tx.send(info);
+        })
+        .start()
+        .expect("Failed to initialize detector");
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    let holder = thread::spawn(move || {
+        let _g1 = l1.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        // Never the victim: just waits out the deadlock report, then
+        // finishes normally once `lock2` is released by the other thread.
+        let _g2 = l2.lock_for(Duration::from_secs(30));
+        true
+    });
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    let victim = thread::spawn(move || {
+        let _g2 = l2.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        match l1.lock_for(Duration::from_secs(30)) {
+            Err(e) => format!("{e:?}") == "Abandoned",
+            Ok(_) => false,
+        }
+    });
+
+    let info = rx
+        .recv_timeout(Duration::from_secs(3))
+        .expect("Deadlock should have been detected");
+    assert_eq!(info.source, DeadlockSource::WaitForGraph);
+
+    assert!(
+        victim.join().unwrap(),
+        "The configured victim should have bailed out with Abandoned"
+    );
+    // Once the victim drops `lock2` (never acquired `lock1`), the other
+    // thread's `lock_for(lock2)` can finally succeed.
+    assert!(
+        holder.join().unwrap(),
+        "The non-victim thread should still complete"
+    );
+}
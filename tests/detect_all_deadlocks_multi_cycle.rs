@@ -0,0 +1,78 @@
+use deloxide::{Mutex, detect_all_deadlocks, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, start_detector};
+
+/// Two independent AB-BA deadlocks, on two unrelated pairs of mutexes,
+/// running at the same time. `detect_all_deadlocks` must report both as
+/// separate groups - unlike the reactive callback (or `check_deadlock`),
+/// which each only ever surface one cycle at a time - since a live system
+/// can have several independent deadlocks at once.
+#[test]
+fn test_detect_all_deadlocks_reports_every_independent_cycle() {
+    let harness = start_detector();
+
+    let mutex_a = Arc::new(Mutex::new("A"));
+    let mutex_b = Arc::new(Mutex::new("B"));
+    let mutex_c = Arc::new(Mutex::new("C"));
+    let mutex_d = Arc::new(Mutex::new("D"));
+
+    let a = Arc::clone(&mutex_a);
+    let b = Arc::clone(&mutex_b);
+    thread::spawn(move || {
+        let _guard_a = a.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_b = b.lock().unwrap();
+    });
+    let a = Arc::clone(&mutex_a);
+    let b = Arc::clone(&mutex_b);
+    thread::spawn(move || {
+        let _guard_b = b.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_a = a.lock().unwrap();
+    });
+
+    let c = Arc::clone(&mutex_c);
+    let d = Arc::clone(&mutex_d);
+    thread::spawn(move || {
+        let _guard_c = c.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_d = d.lock().unwrap();
+    });
+    let c = Arc::clone(&mutex_c);
+    let d = Arc::clone(&mutex_d);
+    thread::spawn(move || {
+        let _guard_d = d.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_c = c.lock().unwrap();
+    });
+
+    // Wait for both reactive callbacks to fire - one per cycle - before
+    // taking the snapshot, so neither cycle is still mid-formation.
+    let first = harness
+        .rx
+        .recv_timeout(DEADLOCK_TIMEOUT)
+        .expect("first deadlock");
+    let second = harness
+        .rx
+        .recv_timeout(DEADLOCK_TIMEOUT)
+        .expect("second deadlock");
+
+    let mut groups = detect_all_deadlocks();
+    assert_eq!(groups.len(), 2, "expected two independent deadlocked groups");
+    for group in &mut groups {
+        assert_eq!(group.len(), 2);
+        group.sort();
+    }
+    groups.sort();
+
+    let mut cycle1 = first.thread_cycle.clone();
+    let mut cycle2 = second.thread_cycle.clone();
+    cycle1.sort();
+    cycle2.sort();
+    let mut expected = vec![cycle1, cycle2];
+    expected.sort();
+
+    assert_eq!(groups, expected);
+}
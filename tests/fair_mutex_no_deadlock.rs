@@ -0,0 +1,34 @@
+use deloxide::{FairMutex, thread};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+#[test]
+fn test_fair_mutex_no_deadlock() {
+    let harness = start_detector();
+
+    let mutex = Arc::new(FairMutex::new(0));
+    let total_acquisitions = Arc::new(AtomicUsize::new(0));
+    let mut handles = Vec::new();
+
+    for _ in 0..4 {
+        let mutex = Arc::clone(&mutex);
+        let total_acquisitions = Arc::clone(&total_acquisitions);
+        handles.push(thread::spawn(move || {
+            for _ in 0..50 {
+                let mut guard = mutex.lock().unwrap();
+                *guard += 1;
+                total_acquisitions.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(*mutex.lock().unwrap(), 200);
+    assert_eq!(total_acquisitions.load(Ordering::SeqCst), 200);
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
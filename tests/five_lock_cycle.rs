@@ -23,15 +23,15 @@ fn test_five_lock_cycle_deadlock() {
     {
         let (l0, l1, l2, l3, l4) = (a.clone(), b.clone(), c.clone(), d.clone(), e.clone());
         handles.push(thread::spawn(move || {
-            let _g0 = l0.lock();
+            let _g0 = l0.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g1 = l1.lock();
+            let _g1 = l1.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g2 = l2.lock();
+            let _g2 = l2.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g3 = l3.lock();
+            let _g3 = l3.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g4 = l4.lock();
+            let _g4 = l4.lock().unwrap();
         }));
     }
 
@@ -40,15 +40,15 @@ fn test_five_lock_cycle_deadlock() {
         let (l0, l1, l2, l3, l4) = (b.clone(), c.clone(), d.clone(), e.clone(), a.clone());
         handles.push(thread::spawn(move || {
             thread::sleep(Duration::from_micros(100)); // Stagger start
-            let _g0 = l0.lock();
+            let _g0 = l0.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g1 = l1.lock();
+            let _g1 = l1.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g2 = l2.lock();
+            let _g2 = l2.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g3 = l3.lock();
+            let _g3 = l3.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g4 = l4.lock();
+            let _g4 = l4.lock().unwrap();
         }));
     }
 
@@ -57,15 +57,15 @@ fn test_five_lock_cycle_deadlock() {
         let (l0, l1, l2, l3, l4) = (c.clone(), d.clone(), e.clone(), a.clone(), b.clone());
         handles.push(thread::spawn(move || {
             thread::sleep(Duration::from_micros(200)); // Stagger start
-            let _g0 = l0.lock();
+            let _g0 = l0.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g1 = l1.lock();
+            let _g1 = l1.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g2 = l2.lock();
+            let _g2 = l2.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g3 = l3.lock();
+            let _g3 = l3.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g4 = l4.lock();
+            let _g4 = l4.lock().unwrap();
         }));
     }
 
@@ -74,15 +74,15 @@ fn test_five_lock_cycle_deadlock() {
         let (l0, l1, l2, l3, l4) = (d.clone(), e.clone(), a.clone(), b.clone(), c.clone());
         handles.push(thread::spawn(move || {
             thread::sleep(Duration::from_micros(300)); // Stagger start
-            let _g0 = l0.lock();
+            let _g0 = l0.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g1 = l1.lock();
+            let _g1 = l1.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g2 = l2.lock();
+            let _g2 = l2.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g3 = l3.lock();
+            let _g3 = l3.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g4 = l4.lock();
+            let _g4 = l4.lock().unwrap();
         }));
     }
 
@@ -91,15 +91,15 @@ fn test_five_lock_cycle_deadlock() {
         let (l0, l1, l2, l3, l4) = (e.clone(), a.clone(), b.clone(), c.clone(), d.clone());
         handles.push(thread::spawn(move || {
             thread::sleep(Duration::from_micros(400)); // Stagger start
-            let _g0 = l0.lock();
+            let _g0 = l0.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g1 = l1.lock();
+            let _g1 = l1.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g2 = l2.lock();
+            let _g2 = l2.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g3 = l3.lock();
+            let _g3 = l3.lock().unwrap();
             thread::sleep(Duration::from_millis(50));
-            let _g4 = l4.lock();
+            let _g4 = l4.lock().unwrap();
         }));
     }
 
@@ -0,0 +1,56 @@
+use deloxide::{DeadlockInfo, Deloxide, Mutex, thread};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_deadlock_reports_lock_acquisition_sites() {
+    let (tx, rx) = mpsc::channel::<DeadlockInfo>();
+
+    Deloxide::new()
+        .with_backtraces()
+        .callback(move |info| {
+            let _ = tx.send(info);
+        })
+        .start()
+        .expect("Failed to initialize detector");
+
+    let mutex_a = Arc::new(Mutex::new("Resource A"));
+    let mutex_b = Arc::new(Mutex::new("Resource B"));
+
+    let a = Arc::clone(&mutex_a);
+    let b = Arc::clone(&mutex_b);
+    thread::spawn(move || {
+        let _guard_a = a.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_b = b.lock().unwrap();
+    });
+
+    let a = Arc::clone(&mutex_a);
+    let b = Arc::clone(&mutex_b);
+    thread::spawn(move || {
+        let _guard_b = b.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_a = a.lock().unwrap();
+    });
+
+    let info = rx
+        .recv_timeout(Duration::from_secs(3))
+        .expect("No deadlock detected within timeout");
+
+    assert_eq!(info.lock_sites.len(), info.thread_cycle.len());
+    assert!(
+        info.lock_sites
+            .iter()
+            .all(|site| site.held_at.is_some() && site.waiting_at.is_some()),
+        "expected every cycle thread's acquisition and wait sites to be captured: {:?}",
+        info.lock_sites
+    );
+    assert!(
+        info.lock_sites
+            .iter()
+            .all(|site| site.held_backtrace.is_some() && site.waiting_backtrace.is_some()),
+        "expected every cycle thread's full acquisition and wait backtraces to be captured: {:?}",
+        info.lock_sites
+    );
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "lock-order-graph")]
+
+use deloxide::{Mutex, report_lock_order, thread};
+use std::sync::Arc;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+/// Thread one always locks X then Y; thread two always locks Y then X - a
+/// classic inverted acquisition order - but thread one fully releases both
+/// locks before thread two ever starts, so no wait-for edge (let alone an
+/// actual deadlock) ever forms. `report_lock_order` audits every ordering
+/// ever observed, not just live wait-for edges, so it must still flag the
+/// pair as a lock-order-violation cycle.
+#[test]
+fn test_report_lock_order_flags_never_contended_inversion() {
+    let harness = start_detector();
+
+    let lock_x = Arc::new(Mutex::new("x"));
+    let lock_y = Arc::new(Mutex::new("y"));
+
+    let x = Arc::clone(&lock_x);
+    let y = Arc::clone(&lock_y);
+    thread::spawn(move || {
+        let _guard_x = x.lock().unwrap();
+        let _guard_y = y.lock().unwrap();
+    })
+    .join()
+    .unwrap();
+
+    let x = Arc::clone(&lock_x);
+    let y = Arc::clone(&lock_y);
+    thread::spawn(move || {
+        let _guard_y = y.lock().unwrap();
+        let _guard_x = x.lock().unwrap();
+    })
+    .join()
+    .unwrap();
+
+    // Neither a genuine wait-for deadlock, since the two threads never ran
+    // concurrently, nor (on this LogOnly-by-default policy) a panic.
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+
+    let mut violations = report_lock_order();
+    assert_eq!(violations.len(), 1, "expected exactly one inverted-order group");
+
+    let mut cycle = violations.remove(0);
+    cycle.sort();
+    let mut expected = vec![lock_x.id(), lock_y.id()];
+    expected.sort();
+    assert_eq!(cycle, expected);
+}
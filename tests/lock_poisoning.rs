@@ -0,0 +1,83 @@
+use deloxide::{Mutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+#[test]
+fn test_lock_returns_poison_error_after_panic_and_clears_on_clear_poison() {
+    let _harness = start_detector();
+
+    let mutex = Arc::new(Mutex::new(0));
+    let m = Arc::clone(&mutex);
+    let _ = thread::spawn(move || {
+        let _guard = m.lock().unwrap();
+        panic!("intentionally poisoning the mutex");
+    })
+    .join();
+
+    assert!(mutex.is_poisoned());
+    match mutex.lock() {
+        Ok(_) => panic!("expected a PoisonError"),
+        Err(poisoned) => {
+            // The guard is still handed back so callers can recover.
+            let guard = poisoned.into_inner();
+            assert_eq!(*guard, 0);
+        }
+    }
+
+    mutex.clear_poison();
+    assert!(!mutex.is_poisoned());
+    assert!(mutex.lock().is_ok());
+}
+
+#[test]
+fn test_deadlock_cycle_reports_poisoned_lock() {
+    let harness = start_detector();
+
+    let mutex_x = Arc::new(Mutex::new(0));
+    let mutex_y = Arc::new(Mutex::new(0));
+
+    // Poison mutex_x up front by panicking while holding it.
+    let x = Arc::clone(&mutex_x);
+    let _ = thread::spawn(move || {
+        let _guard = x.lock().unwrap();
+        panic!("intentionally poisoning mutex_x");
+    })
+    .join();
+    assert!(mutex_x.is_poisoned());
+
+    // Thread 1: recovers from the poisoning and keeps holding mutex_x, then
+    // waits for mutex_y.
+    let x = Arc::clone(&mutex_x);
+    let y = Arc::clone(&mutex_y);
+    thread::spawn(move || {
+        let _guard_x = x.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        thread::sleep(Duration::from_millis(100));
+        let _guard_y = y.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    });
+
+    // Thread 2: holds mutex_y, then waits for the poisoned mutex_x, closing the cycle.
+    let y = Arc::clone(&mutex_y);
+    let x = Arc::clone(&mutex_x);
+    thread::spawn(move || {
+        let _guard_y = y.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        thread::sleep(Duration::from_millis(100));
+        let _guard_x = x.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.thread_cycle.len(), 2);
+
+    let poisoned_sites: Vec<_> = info
+        .lock_sites
+        .iter()
+        .filter(|site| site.waiting_lock_poisoned)
+        .collect();
+    assert_eq!(
+        poisoned_sites.len(),
+        1,
+        "expected exactly one cycle member to be waiting on the poisoned lock: {:?}",
+        info.lock_sites
+    );
+}
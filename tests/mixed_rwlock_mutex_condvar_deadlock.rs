@@ -21,14 +21,14 @@ fn test_mixed_rwlock_mutex_condvar_deadlock() {
 
         Thread::spawn(move || {
             // Reader gets read access to shared data
-            let data_guard = shared_data.read();
+            let data_guard = shared_data.read().unwrap();
             println!("Reader: Got read lock on data: {:?}", *data_guard);
 
             // Wait for data processing to be ready
-            let mut processor_state = processor_mutex.lock();
+            let mut processor_state = processor_mutex.lock().unwrap();
             while *processor_state == "idle" {
                 println!("Reader: Waiting for processor to be ready...");
-                data_ready_cv.wait(&mut processor_state); // Releases processor_mutex while waiting
+                data_ready_cv.wait(&mut processor_state).unwrap(); // Releases processor_mutex while waiting
             }
             // processor_mutex is reacquired here, but we still hold the RwLock read guard
 
@@ -40,7 +40,7 @@ fn test_mixed_rwlock_mutex_condvar_deadlock() {
             drop(processor_state); // Release the mutex from wait
 
             // Now try to get it again for "final processing"
-            let _final_processor_access = processor_mutex.lock();
+            let _final_processor_access = processor_mutex.lock().unwrap();
 
             println!("Reader: Got final processor access");
             // This code is never reached due to deadlock
@@ -58,7 +58,7 @@ fn test_mixed_rwlock_mutex_condvar_deadlock() {
             std::thread::sleep(Duration::from_millis(10));
 
             // Writer takes control of processor
-            let mut processor_state = processor_mutex.lock();
+            let mut processor_state = processor_mutex.lock().unwrap();
             *processor_state = String::from("processing");
             println!("Writer: Set processor to 'processing' state");
 
@@ -73,7 +73,7 @@ fn test_mixed_rwlock_mutex_condvar_deadlock() {
             // Reader holds RwLock (read) and is trying to get processor_mutex (which we hold)
             // We hold processor_mutex and are trying to get RwLock (write) - blocked by reader
             println!("Writer: Trying to get write access to data...");
-            let _data_write_guard = shared_data.write();
+            let _data_write_guard = shared_data.write().unwrap();
 
             println!("Writer: Got write access to data");
             // This code is never reached due to deadlock
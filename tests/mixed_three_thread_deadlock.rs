@@ -25,12 +25,12 @@ fn test_mixed_three_thread_deadlock_mutex_rwlock_condvar() {
         let cv = Arc::clone(&cv);
         let ready = Arc::clone(&ready);
         thread::spawn(move || {
-            let mut g2 = m2.lock();
+            let mut g2 = m2.lock().unwrap();
             while !ready.load(Ordering::SeqCst) {
-                cv.wait(&mut g2);
+                cv.wait(&mut g2).unwrap();
             }
             // m2 reacquired here; now attempt to get write on rw (will block due to reader)
-            let _w = rw.write();
+            let _w = rw.write().unwrap();
             let _ = &mut g2;
         });
     }
@@ -40,9 +40,9 @@ fn test_mixed_three_thread_deadlock_mutex_rwlock_condvar() {
         let rw = Arc::clone(&rw);
         let m1 = Arc::clone(&m1);
         thread::spawn(move || {
-            let _r = rw.read();
+            let _r = rw.read().unwrap();
             std::thread::sleep(Duration::from_millis(30));
-            let _m1 = m1.lock();
+            let _m1 = m1.lock().unwrap();
             let _ = &_r;
         });
     }
@@ -54,14 +54,14 @@ fn test_mixed_three_thread_deadlock_mutex_rwlock_condvar() {
         let cv = Arc::clone(&cv);
         let ready = Arc::clone(&ready);
         thread::spawn(move || {
-            let _c = m1.lock();
+            let _c = m1.lock().unwrap();
             // Let A start waiting and B acquire read lock
             std::thread::sleep(Duration::from_millis(20));
             ready.store(true, Ordering::SeqCst);
             cv.notify_one();
             // Give A a moment to wake and reacquire m2, then we try to get m2
             std::thread::sleep(Duration::from_millis(20));
-            let _m2 = m2.lock();
+            let _m2 = m2.lock().unwrap();
             let _ = &_c;
         });
     }
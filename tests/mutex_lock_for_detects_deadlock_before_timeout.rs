@@ -0,0 +1,39 @@
+use deloxide::{DeadlockSource, Mutex as DMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+/// `lock_for`/`lock_until` must still report a genuine AB-BA deadlock through
+/// the same `acquire_slow` path as the plain blocking `lock()` - eagerly, as
+/// soon as the cycle closes, rather than silently sitting there until the
+/// (much longer) timeout elapses.
+#[test]
+fn test_lock_for_reports_deadlock_well_before_its_own_timeout() {
+    let harness = start_detector();
+
+    let lock1 = Arc::new(DMutex::new(()));
+    let lock2 = Arc::new(DMutex::new(()));
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    thread::spawn(move || {
+        let _g1 = l1.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        // A generous timeout: if this is what actually unblocks the thread,
+        // the detector's eager report below would arrive far too late.
+        let _g2 = l2.lock_for(Duration::from_secs(30));
+    });
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    thread::spawn(move || {
+        let _g2 = l2.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _g1 = l1.lock_for(Duration::from_secs(30));
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.source, DeadlockSource::WaitForGraph);
+    assert_eq!(info.thread_cycle.len(), 2);
+}
@@ -0,0 +1,71 @@
+use deloxide::{Mutex as DMutex, MutexGuard, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, NO_DEADLOCK_TIMEOUT, assert_no_deadlock, expect_deadlock, start_detector};
+
+#[test]
+fn test_map_projects_onto_a_field_and_releases_on_drop() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new((1, 2)));
+
+    {
+        let guard = m.lock().unwrap();
+        let mut mapped = MutexGuard::map(guard, |pair| &mut pair.0);
+        assert_eq!(*mapped, 1);
+        *mapped = 10;
+    }
+
+    assert_eq!(*m.lock().unwrap(), (10, 2));
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_try_map_succeeds_and_returns_original_guard_on_failure() {
+    let harness = start_detector();
+
+    let some = Arc::new(DMutex::new(Some(5)));
+    let guard = some.lock().unwrap();
+    match MutexGuard::try_map(guard, |opt| opt.as_mut()) {
+        Ok(mut mapped) => *mapped = 10,
+        Err(_) => panic!("mapping over Some should succeed"),
+    }
+    assert_eq!(*some.lock().unwrap(), Some(10));
+
+    let none = Arc::new(DMutex::new(None::<i32>));
+    let guard = none.lock().unwrap();
+    match MutexGuard::try_map(guard, |opt| opt.as_mut()) {
+        Ok(_) => panic!("mapping over None should fail"),
+        Err(guard) => assert_eq!(*guard, None),
+    }
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_mapped_guard_still_participates_in_deadlock_detection() {
+    let harness = start_detector();
+
+    let lock1 = Arc::new(DMutex::new((0, 0)));
+    let lock2 = Arc::new(DMutex::new(0));
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    thread::spawn(move || {
+        let guard = l1.lock().unwrap();
+        let _mapped = MutexGuard::map(guard, |pair| &mut pair.0);
+        thread::sleep(Duration::from_millis(100));
+        let _g2 = l2.lock().unwrap();
+    });
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    thread::spawn(move || {
+        let _g2 = l2.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _g1 = l1.lock().unwrap();
+    });
+
+    expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+}
@@ -0,0 +1,73 @@
+use deloxide::{Mutex as DMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, NO_DEADLOCK_TIMEOUT, assert_no_deadlock, expect_deadlock, start_detector};
+
+#[test]
+fn test_lock_owned_can_be_moved_into_a_spawned_thread() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(0));
+
+    // Unlike a plain `lock()` guard, an owned guard has no lifetime borrowed
+    // from `m`, so it can be acquired here and moved across the spawn boundary.
+    let guard = m.lock_owned().unwrap();
+    let handle = thread::spawn(move || {
+        let mut guard = guard;
+        *guard += 1;
+    });
+    handle.join().unwrap();
+
+    assert_eq!(*m.lock().unwrap(), 1);
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_try_lock_owned_succeeds_and_fails() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(42));
+
+    let guard = m.try_lock_owned().expect("lock should be free");
+    assert_eq!(*guard, 42);
+
+    assert!(
+        m.try_lock_owned().is_err(),
+        "a second try_lock_owned should fail while the first guard is held"
+    );
+
+    drop(guard);
+    assert!(
+        m.try_lock_owned().is_ok(),
+        "lock should be free again after drop"
+    );
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_lock_owned_still_detects_cross_thread_deadlock() {
+    let harness = start_detector();
+
+    let lock1 = Arc::new(DMutex::new(()));
+    let lock2 = Arc::new(DMutex::new(()));
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    thread::spawn(move || {
+        let _g1 = l1.lock_owned().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _g2 = l2.lock_owned().unwrap();
+    });
+
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    thread::spawn(move || {
+        let _g2 = l2.lock_owned().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _g1 = l1.lock_owned().unwrap();
+    });
+
+    expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+}
@@ -21,17 +21,17 @@ fn test_mutex_rwlock_deadlock() {
 
     // Thread 1: Lock Mutex, then try to lock RwLock (write)
     let _t1 = Thread::spawn(move || {
-        let _g1 = mutex1.lock();
+        let _g1 = mutex1.lock().unwrap();
         thread::sleep(Duration::from_millis(100));
-        let _g2 = rwlock1.write();
+        let _g2 = rwlock1.write().unwrap();
         false
     });
 
     // Thread 2: Lock RwLock (write), then try to lock Mutex
     let _t2 = Thread::spawn(move || {
-        let _g1 = rwlock2.write();
+        let _g1 = rwlock2.write().unwrap();
         thread::sleep(Duration::from_millis(100));
-        let _g2 = mutex2.lock();
+        let _g2 = mutex2.lock().unwrap();
         false
     });
 
@@ -0,0 +1,38 @@
+use deloxide::{DeadlockSource, LockHeldState, Mutex, thread};
+use std::sync::Arc;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+#[test]
+fn test_self_deadlock_on_non_reentrant_relock() {
+    let harness = start_detector();
+
+    let mutex = Arc::new(Mutex::new(0));
+    let m = Arc::clone(&mutex);
+
+    thread::spawn(move || {
+        let _g1 = m.lock().unwrap();
+        // Locking the same non-reentrant mutex again from the same thread
+        // must be flagged immediately, without ever blocking on anyone else.
+        let _g2 = m.lock().unwrap();
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.source, DeadlockSource::SelfDeadlock);
+    assert_eq!(info.thread_cycle.len(), 1);
+    assert_eq!(info.thread_waiting_for_locks.len(), 1);
+}
+
+#[test]
+fn test_held_state_reflects_ownership() {
+    let _harness = start_detector();
+
+    let mutex = Mutex::new(0);
+    assert_eq!(mutex.held_state(), LockHeldState::NotHeld);
+
+    let guard = mutex.lock().unwrap();
+    assert_eq!(mutex.held_state(), LockHeldState::HeldByCurrentThread);
+    drop(guard);
+
+    assert_eq!(mutex.held_state(), LockHeldState::NotHeld);
+}
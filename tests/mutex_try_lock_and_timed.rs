@@ -0,0 +1,103 @@
+use deloxide::{Mutex as DMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{
+    DEADLOCK_TIMEOUT, NO_DEADLOCK_TIMEOUT, assert_no_deadlock, expect_deadlock, start_detector,
+};
+
+#[test]
+fn test_try_lock_succeeds_and_fails() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(42));
+
+    let guard = m.try_lock().expect("lock should be free");
+    assert_eq!(*guard, 42);
+
+    assert!(
+        m.try_lock().is_err(),
+        "a second try_lock should fail while the first guard is held"
+    );
+
+    drop(guard);
+    assert!(m.try_lock().is_ok(), "lock should be free again after drop");
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_try_lock_spin_catches_cycle_against_blocking_waiter() {
+    let harness = start_detector();
+
+    let lock1 = Arc::new(DMutex::new(()));
+    let lock2 = Arc::new(DMutex::new(()));
+
+    // Thread 1 blocks normally: holds lock1, then waits (blocking) for lock2.
+    {
+        let lock1 = Arc::clone(&lock1);
+        let lock2 = Arc::clone(&lock2);
+        thread::spawn(move || {
+            let _g1 = lock1.lock().unwrap();
+            thread::sleep(Duration::from_millis(100));
+            let _g2 = lock2.lock().unwrap();
+        });
+    }
+
+    // Thread 2 never blocks: holds lock2, then spins on try_lock(lock1).
+    {
+        let lock1 = Arc::clone(&lock1);
+        let lock2 = Arc::clone(&lock2);
+        thread::spawn(move || {
+            let _g2 = lock2.lock().unwrap();
+            thread::sleep(Duration::from_millis(50));
+            loop {
+                if lock1.try_lock().is_ok() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+        });
+    }
+
+    expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_lock_for_times_out_when_contended() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(()));
+    let guard = m.lock().unwrap();
+
+    let result = m.lock_for(Duration::from_millis(50));
+    assert!(
+        result.is_err(),
+        "lock_for should time out while the mutex is held"
+    );
+
+    drop(guard);
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
+
+#[test]
+fn test_lock_for_succeeds_once_released_in_time() {
+    let harness = start_detector();
+
+    let m = Arc::new(DMutex::new(0));
+
+    {
+        let m = Arc::clone(&m);
+        thread::spawn(move || {
+            let mut g = m.lock().unwrap();
+            *g = 1;
+            thread::sleep(Duration::from_millis(30));
+        });
+    }
+
+    thread::sleep(Duration::from_millis(5));
+    let result = m.lock_for(Duration::from_secs(3));
+    assert!(result.is_ok(), "lock_for should succeed before its deadline");
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
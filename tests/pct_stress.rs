@@ -0,0 +1,44 @@
+#![cfg(feature = "stress-test")]
+
+use deloxide::{DeadlockInfo, Deloxide, Mutex, thread};
+use std::sync::{Arc, mpsc};
+use std::time::Duration;
+
+#[test]
+fn test_pct_stress_provokes_a_two_lock_deadlock() {
+    let (tx, rx) = mpsc::channel::<DeadlockInfo>();
+
+    Deloxide::new()
+        .with_pct_stress(3)
+        .callback(move |info| {
+            let _ = tx.send(info);
+        })
+        .start()
+        .expect("Failed to initialize detector");
+
+    let lock_a = Arc::new(Mutex::new(0));
+    let lock_b = Arc::new(Mutex::new(0));
+
+    let a1 = lock_a.clone();
+    let b1 = lock_b.clone();
+    let _h1 = thread::spawn(move || {
+        let _ga = a1.lock().unwrap();
+        let _gb = b1.lock().unwrap();
+    });
+
+    let a2 = lock_a.clone();
+    let b2 = lock_b.clone();
+    let _h2 = thread::spawn(move || {
+        let _gb = b2.lock().unwrap();
+        let _ga = a2.lock().unwrap();
+    });
+
+    let timeout = Duration::from_secs(5);
+    let info = rx
+        .recv_timeout(timeout)
+        .unwrap_or_else(|_| panic!("No deadlock detected within {timeout:?}"));
+
+    assert_eq!(info.thread_cycle.len(), 2, "Expected a 2-thread cycle");
+
+    // Threads remain deadlocked; we don't join them.
+}
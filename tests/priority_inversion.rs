@@ -0,0 +1,94 @@
+use deloxide::{DeadlockSource, Mutex, PriorityMutex, thread};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+#[test]
+fn test_classic_priority_inversion_reported() {
+    let harness = start_detector();
+
+    let lock_x = Arc::new(Mutex::new(0));
+    let lock_y = Arc::new(Mutex::new(0));
+
+    // Low-priority thread grabs X, then blocks trying to grab Y.
+    let low_x = Arc::clone(&lock_x);
+    let low_y = Arc::clone(&lock_y);
+    let low_handle = thread::spawn_with_priority(1, move || {
+        let _guard_x = low_x.lock().unwrap();
+        thread::sleep(Duration::from_millis(50));
+        let _guard_y = low_y.lock().unwrap();
+    });
+
+    thread::sleep(Duration::from_millis(10));
+
+    // Medium-priority thread grabs Y and holds it long enough for the
+    // low-priority thread to block behind it.
+    let med_y = Arc::clone(&lock_y);
+    let med_handle = thread::spawn_with_priority(5, move || {
+        let _guard_y = med_y.lock().unwrap();
+        thread::sleep(Duration::from_millis(300));
+    });
+
+    thread::sleep(Duration::from_millis(100));
+
+    // High-priority thread blocks on X, which the low-priority thread is
+    // still holding while itself stuck behind the medium-priority thread:
+    // a classic unbounded priority inversion.
+    let high_x = Arc::clone(&lock_x);
+    let high_handle = thread::spawn_with_priority(10, move || {
+        let _guard_x = high_x.lock().unwrap();
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.source, DeadlockSource::PriorityInversion);
+    assert_eq!(
+        info.priority_chain.iter().map(|&(_, p)| p).collect::<Vec<_>>(),
+        vec![10, 1, 5],
+        "chain should run high waiter -> low owner -> medium blocker"
+    );
+
+    low_handle.join().unwrap();
+    med_handle.join().unwrap();
+    high_handle.join().unwrap();
+}
+
+#[test]
+fn test_priority_mutex_grants_highest_priority_waiter_first() {
+    let mutex = Arc::new(PriorityMutex::new(0));
+    let order = Arc::new(StdMutex::new(Vec::new()));
+
+    let guard = mutex.lock().unwrap();
+
+    let low_mutex = Arc::clone(&mutex);
+    let low_order = Arc::clone(&order);
+    let low_handle = thread::spawn_with_priority(1, move || {
+        let _g = low_mutex.lock().unwrap();
+        low_order.lock().unwrap().push("low");
+    });
+
+    // Give the low-priority waiter time to register before the
+    // higher-priority one arrives, so arrival order is the opposite of
+    // priority order.
+    thread::sleep(Duration::from_millis(50));
+
+    let high_mutex = Arc::clone(&mutex);
+    let high_order = Arc::clone(&order);
+    let high_handle = thread::spawn_with_priority(10, move || {
+        let _g = high_mutex.lock().unwrap();
+        high_order.lock().unwrap().push("high");
+    });
+
+    // Let both waiters park before releasing the lock.
+    thread::sleep(Duration::from_millis(50));
+    drop(guard);
+
+    low_handle.join().unwrap();
+    high_handle.join().unwrap();
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec!["high", "low"],
+        "the higher-priority waiter should be granted the lock first, despite arriving second"
+    );
+}
@@ -0,0 +1,40 @@
+use deloxide::{DeadlockSource, ReentrantMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+/// The reentrant fast path must only skip detector reporting for a thread
+/// re-acquiring a lock it already owns; a genuine cross-thread AB-BA cycle
+/// through two different `ReentrantMutex`es still has to be caught.
+#[test]
+fn test_reentrant_mutex_still_detects_real_cross_thread_deadlock() {
+    let harness = start_detector();
+
+    let mutex_a = Arc::new(ReentrantMutex::new(0));
+    let mutex_b = Arc::new(ReentrantMutex::new(0));
+
+    let a1 = Arc::clone(&mutex_a);
+    let b1 = Arc::clone(&mutex_b);
+    let _t1 = thread::spawn(move || {
+        let _guard_a = a1.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_b = b1.lock().unwrap();
+    });
+
+    let a2 = Arc::clone(&mutex_a);
+    let b2 = Arc::clone(&mutex_b);
+    let _t2 = thread::spawn(move || {
+        let _guard_b = b2.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_a = a2.lock().unwrap();
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.source, DeadlockSource::WaitForGraph);
+    assert_eq!(
+        info.thread_cycle.len(),
+        2,
+        "Deadlock should involve the two threads blocked on each other's mutex"
+    );
+}
@@ -0,0 +1,30 @@
+use deloxide::{ReentrantMutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+#[test]
+fn test_reentrant_mutex_no_self_deadlock() {
+    let harness = start_detector();
+
+    let mutex = Arc::new(ReentrantMutex::new(0));
+    let mut handles = Vec::new();
+
+    for _ in 0..4 {
+        let mutex = Arc::clone(&mutex);
+        handles.push(thread::spawn(move || {
+            // Nested re-acquisition by the same thread must never self-deadlock.
+            let _g1 = mutex.lock().unwrap();
+            let _g2 = mutex.lock().unwrap();
+            let _g3 = mutex.lock().unwrap();
+            thread::sleep(Duration::from_millis(50));
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
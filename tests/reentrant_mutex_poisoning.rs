@@ -0,0 +1,31 @@
+use deloxide::{ReentrantMutex, thread};
+use std::sync::Arc;
+mod common;
+use common::start_detector;
+
+#[test]
+fn test_reentrant_mutex_poisons_on_panic_and_clears() {
+    let _harness = start_detector();
+
+    let mutex = Arc::new(ReentrantMutex::new(0));
+    let m = Arc::clone(&mutex);
+    let _ = thread::spawn(move || {
+        let _g1 = m.lock().unwrap();
+        let _g2 = m.lock().unwrap();
+        panic!("intentionally poisoning the reentrant mutex");
+    })
+    .join();
+
+    assert!(mutex.is_poisoned());
+    match mutex.lock() {
+        Ok(_) => panic!("expected a PoisonError"),
+        Err(poisoned) => {
+            let guard = poisoned.into_inner();
+            assert_eq!(*guard, 0);
+        }
+    }
+
+    mutex.clear_poison();
+    assert!(!mutex.is_poisoned());
+    assert!(mutex.lock().is_ok());
+}
@@ -24,14 +24,14 @@ fn test_guaranteed_three_thread_rwlock_deadlock() {
         let ready = Arc::clone(&ready_count);
         handles.push(thread::spawn(move || {
             // Each thread grabs read on i
-            let _ri = locks[i].read();
+            let _ri = locks[i].read().unwrap();
             // Signal ready and wait for all threads
             ready.fetch_add(1, Ordering::SeqCst);
             while ready.load(Ordering::SeqCst) < 3 {
                 std::thread::yield_now();
             }
             // Each tries to upgrade to write on (i+1)%3 (held for read by next thread)
-            let _wi_next = locks[(i + 1) % 3].write();
+            let _wi_next = locks[(i + 1) % 3].write().unwrap();
             // Never proceeds
         }));
     }
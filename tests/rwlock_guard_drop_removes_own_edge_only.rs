@@ -0,0 +1,44 @@
+use deloxide::{RwLock, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+/// A writer queued behind two readers depends on *both*; each reader's guard
+/// must remove only its own wait-for edge on drop, not the other reader's.
+/// Dropping the first reader alone must not let the writer through, and the
+/// writer should only proceed once the second reader also releases.
+#[test]
+fn test_rwlock_writer_waits_for_both_readers_independently() {
+    let harness = start_detector();
+
+    let lock = Arc::new(RwLock::new(0));
+
+    let r1_guard = lock.read().unwrap();
+    let r2_guard = lock.read().unwrap();
+
+    let writer_lock = Arc::clone(&lock);
+    let writer = thread::spawn(move || {
+        let mut g = writer_lock.write().unwrap();
+        *g += 1;
+    });
+
+    // Give the writer time to queue up behind both readers.
+    thread::sleep(Duration::from_millis(100));
+
+    // Dropping only one reader must not free the writer: it still depends on
+    // the other reader's still-held lock.
+    drop(r1_guard);
+    thread::sleep(Duration::from_millis(100));
+    assert!(
+        lock.try_read().is_err(),
+        "writer should still be queued, blocking new readers, while r2 is held"
+    );
+
+    // Now the second reader releases, and the writer should complete.
+    drop(r2_guard);
+    writer.join().unwrap();
+
+    assert_eq!(*lock.read().unwrap(), 1);
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
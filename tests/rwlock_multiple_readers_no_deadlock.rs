@@ -14,7 +14,7 @@ fn test_rwlock_multiple_readers_no_deadlock() {
     for _ in 0..4 {
         let lock = Arc::clone(&lock);
         handles.push(thread::spawn(move || {
-            let _g = lock.read();
+            let _g = lock.read().unwrap();
             thread::sleep(Duration::from_millis(50));
         }));
     }
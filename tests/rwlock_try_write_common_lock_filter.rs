@@ -0,0 +1,73 @@
+use deloxide::{RwLock, thread};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+mod common;
+use common::{NO_DEADLOCK_TIMEOUT, assert_no_deadlock, start_detector};
+
+/// Two threads that both already hold a common lock for read can never
+/// actually deadlock against each other over two other locks, even if their
+/// non-blocking `try_write` attempts happen to close a cycle in the
+/// wait-for graph: whichever one finishes first releases the common lock's
+/// read guard last, but neither is *blocked* waiting on the other, since
+/// `try_write` never blocks. `try_write`'s detector-side cycle check must
+/// run the same common-held-lock filter that `read`/`upgradable_read` do,
+/// or this shows up as a false-positive deadlock report.
+#[test]
+fn test_try_write_cycle_over_a_commonly_held_lock_is_not_a_deadlock() {
+    let harness = start_detector();
+
+    let common_lock = Arc::new(RwLock::new(0));
+    let lock1 = Arc::new(RwLock::new(0));
+    let lock2 = Arc::new(RwLock::new(0));
+    let ready_count = Arc::new(AtomicUsize::new(0));
+
+    let c1 = Arc::clone(&common_lock);
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    let ready1 = Arc::clone(&ready_count);
+    let t1 = thread::spawn(move || {
+        let _common = c1.read().unwrap();
+        let _r1 = l1.read().unwrap();
+
+        ready1.fetch_add(1, Ordering::SeqCst);
+        while ready1.load(Ordering::SeqCst) < 2 {
+            std::thread::yield_now();
+        }
+
+        // Busy-spin a handful of try_write attempts: lock2 is held for read
+        // by the other thread, so this keeps failing until it releases.
+        for _ in 0..50 {
+            if l2.try_write().is_ok() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    });
+
+    let c2 = Arc::clone(&common_lock);
+    let l1 = Arc::clone(&lock1);
+    let l2 = Arc::clone(&lock2);
+    let ready2 = Arc::clone(&ready_count);
+    let t2 = thread::spawn(move || {
+        let _common = c2.read().unwrap();
+        let _r2 = l2.read().unwrap();
+
+        ready2.fetch_add(1, Ordering::SeqCst);
+        while ready2.load(Ordering::SeqCst) < 2 {
+            std::thread::yield_now();
+        }
+
+        for _ in 0..50 {
+            if l1.try_write().is_ok() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    });
+
+    t1.join().unwrap();
+    t2.join().unwrap();
+
+    assert_no_deadlock(&harness, NO_DEADLOCK_TIMEOUT);
+}
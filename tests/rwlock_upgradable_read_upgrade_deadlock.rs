@@ -0,0 +1,49 @@
+use deloxide::{RwLock, thread};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+#[test]
+fn test_rwlock_upgradable_read_upgrade_deadlock() {
+    let harness = start_detector();
+
+    let lock1 = Arc::new(RwLock::new(0));
+    let lock2 = Arc::new(RwLock::new(0));
+    let ready_count = Arc::new(AtomicUsize::new(0));
+
+    let locks = [lock1, lock2];
+
+    let mut handles = Vec::new();
+
+    for i in 0..2 {
+        let locks = locks.clone();
+        let ready = Arc::clone(&ready_count);
+        handles.push(thread::spawn(move || {
+            // Each thread holds a read lock on its own lock...
+            let _ri = locks[i].read().unwrap();
+            // Signal ready and wait for all threads
+            ready.fetch_add(1, Ordering::SeqCst);
+            while ready.load(Ordering::SeqCst) < 2 {
+                std::thread::yield_now();
+            }
+            // ...then tries to upgrade the other lock (held for read by the other thread)
+            let upgradable = locks[(i + 1) % 2].upgradable_read().unwrap();
+            let _w = upgradable.upgrade().unwrap();
+            // Never proceeds past here
+        }));
+    }
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(
+        info.thread_cycle.len(),
+        2,
+        "Deadlock should involve 2 threads"
+    );
+    println!(
+        "✔ Detected RwLock upgradable-read upgrade deadlock: {:?}",
+        info.thread_cycle
+    );
+}
@@ -18,14 +18,14 @@ fn test_rwlock_upgrade_deadlock() {
         let lock = Arc::clone(&lock);
         let ready = Arc::clone(&ready_count);
         handles.push(Thread::spawn(move || {
-            let _r = lock.read();
+            let _r = lock.read().unwrap();
             // Signal ready and wait for all threads
             ready.fetch_add(1, Ordering::SeqCst);
             while ready.load(Ordering::SeqCst) < 2 {
                 std::thread::yield_now();
             }
             // Both threads attempt to upgrade at the same time: classic cycle!
-            let _w = lock.write();
+            let _w = lock.write().unwrap();
             // Never proceeds past here
         }));
     }
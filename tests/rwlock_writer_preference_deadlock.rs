@@ -0,0 +1,50 @@
+use deloxide::{RwLock, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+/// A deadlock that only forms because a plain `read()` respects
+/// writer-preference: a reader waits behind a writer that is merely queued
+/// (not yet holding the lock), closing a 3-thread cycle that a reader-vs-
+/// current-writer check alone would miss.
+#[test]
+fn test_rwlock_writer_preference_deadlock() {
+    let harness = start_detector();
+
+    let lock_a = Arc::new(RwLock::new(0));
+    let lock_b = Arc::new(RwLock::new(0));
+
+    // T1: holds read(A), then reads B (must wait behind T2's queued write)
+    let a1 = Arc::clone(&lock_a);
+    let b1 = Arc::clone(&lock_b);
+    let _t1 = thread::spawn(move || {
+        let _ra = a1.read().unwrap();
+        thread::sleep(Duration::from_millis(150));
+        let _rb = b1.read().unwrap();
+    });
+
+    // T3: holds read(B), then writes A (must wait for T1's read)
+    let a3 = Arc::clone(&lock_a);
+    let b3 = Arc::clone(&lock_b);
+    let _t3 = thread::spawn(move || {
+        let _rb = b3.read().unwrap();
+        thread::sleep(Duration::from_millis(150));
+        let _wa = a3.write().unwrap();
+    });
+
+    thread::sleep(Duration::from_millis(50));
+
+    // T2: queues a write on B behind T3's read, never completing
+    let b2 = Arc::clone(&lock_b);
+    let _t2 = thread::spawn(move || {
+        let _wb = b2.write().unwrap();
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(
+        info.thread_cycle.len(),
+        3,
+        "Deadlock should involve all 3 threads"
+    );
+}
@@ -14,7 +14,7 @@ fn test_rwlock_writer_waits_for_readers_no_deadlock() {
 
     // One thread grabs a read lock for a while
     let reader = thread::spawn(move || {
-        let _g = l1.read();
+        let _g = l1.read().unwrap();
         thread::sleep(Duration::from_millis(100));
     });
 
@@ -23,7 +23,7 @@ fn test_rwlock_writer_waits_for_readers_no_deadlock() {
 
     // Writer will block until reader is done (but not a deadlock!)
     let writer = thread::spawn(move || {
-        let _g = l2.write();
+        let _g = l2.write().unwrap();
         // Should succeed after reader is done
     });
 
@@ -18,13 +18,13 @@ fn test_simple_two_thread_deadlock() {
 
     // Thread 1: Lock A, then try to lock B
     let _thread1 = thread::spawn(move || {
-        let _guard_a = mutex_a.lock();
+        let _guard_a = mutex_a.lock().unwrap();
 
         // Give thread 2 time to acquire lock B
         thread::sleep(Duration::from_millis(100));
 
         // This will cause a deadlock
-        let _guard_b = mutex_b.lock();
+        let _guard_b = mutex_b.lock().unwrap();
 
         // We shouldn't reach here if deadlock is detected
         false
@@ -32,13 +32,13 @@ fn test_simple_two_thread_deadlock() {
 
     // Thread 2: Lock B, then try to lock A
     let _thread2 = thread::spawn(move || {
-        let _guard_b = mutex_b_clone.lock();
+        let _guard_b = mutex_b_clone.lock().unwrap();
 
         // Give thread 1 time to acquire lock A
         thread::sleep(Duration::from_millis(100));
 
         // This will cause a deadlock
-        let _guard_a = mutex_a_clone.lock();
+        let _guard_a = mutex_a_clone.lock().unwrap();
 
         // We shouldn't reach here if deadlock is detected
         false
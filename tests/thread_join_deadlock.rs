@@ -0,0 +1,40 @@
+use deloxide::{Mutex, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+/// `JoinHandle::join` registers a wait-for edge just like lock contention
+/// does, so a thread blocked in `join()` on another thread that is itself
+/// stuck waiting for a lock the joiner holds must be caught as a deadlock,
+/// even though the two threads never contend on the same lock "at once" in
+/// the usual AB-BA sense.
+#[test]
+fn test_join_waiting_on_a_thread_blocked_on_the_joiners_own_lock() {
+    let harness = start_detector();
+
+    let lock = Arc::new(Mutex::new("Resource"));
+
+    // Thread B: waits to grab `lock` after thread A has had time to take it.
+    let lock_b = Arc::clone(&lock);
+    let thread_b = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        let _guard = lock_b.lock().unwrap();
+        // We shouldn't reach here if deadlock is detected.
+        false
+    });
+
+    // Thread A: takes the lock, then blocks joining B - but B can only ever
+    // finish once A releases the lock it's holding right now.
+    let lock_a = Arc::clone(&lock);
+    let _thread_a = thread::spawn(move || {
+        let _guard = lock_a.lock().unwrap();
+        thread::sleep(Duration::from_millis(150));
+        let _ = thread_b.join();
+        // We shouldn't reach here if deadlock is detected.
+        false
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.thread_cycle.len(), 2);
+}
@@ -0,0 +1,44 @@
+use deloxide::{Mutex, blockers_of, in_cycle, reachable_from, thread};
+use std::sync::Arc;
+use std::time::Duration;
+mod common;
+use common::{DEADLOCK_TIMEOUT, expect_deadlock, start_detector};
+
+/// A classic 2-thread A/B deadlock, queried live through the graph-query
+/// API rather than just the returned `DeadlockInfo`.
+#[test]
+fn test_queries_reflect_live_cycle() {
+    let harness = start_detector();
+
+    let mutex_a = Arc::new(Mutex::new("A"));
+    let mutex_b = Arc::new(Mutex::new("B"));
+
+    let a = Arc::clone(&mutex_a);
+    let b = Arc::clone(&mutex_b);
+    thread::spawn(move || {
+        let _guard_a = a.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_b = b.lock().unwrap();
+    });
+
+    let a = Arc::clone(&mutex_a);
+    let b = Arc::clone(&mutex_b);
+    thread::spawn(move || {
+        let _guard_b = b.lock().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let _guard_a = a.lock().unwrap();
+    });
+
+    let info = expect_deadlock(&harness, DEADLOCK_TIMEOUT);
+    assert_eq!(info.thread_cycle.len(), 2);
+    let t1 = info.thread_cycle[0];
+    let t2 = info.thread_cycle[1];
+
+    assert!(in_cycle(t1));
+    assert!(in_cycle(t2));
+
+    assert_eq!(reachable_from(t1), vec![t2]);
+    assert_eq!(blockers_of(t1), vec![t2]);
+    assert_eq!(reachable_from(t2), vec![t1]);
+    assert_eq!(blockers_of(t2), vec![t1]);
+}
@@ -0,0 +1,47 @@
+use deloxide::{Condvar, DeadlockSource, Deloxide, Mutex, thread};
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// A thread waiting on a condvar that is never notified has no lock-attempt
+/// event to trigger the usual reactive cycle check, so only the background
+/// watchdog can surface it.
+#[test]
+fn test_watchdog_reports_a_thread_stalled_on_a_lost_condvar_notification() {
+    let (tx, rx) = mpsc::channel();
+
+    Deloxide::new()
+        .with_watchdog(Duration::from_millis(50))
+        .callback(move |info| {
+            let _ = tx.send(info);
+        })
+        .start()
+        .expect("Failed to initialize detector");
+
+    let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    let pair2 = Arc::clone(&pair);
+    thread::spawn(move || {
+        let (mutex, condvar) = (&pair2.0, &pair2.1);
+        let mut ready = mutex.lock().unwrap();
+        // No other thread ever notifies this condvar, so the wait never ends.
+        while !*ready {
+            condvar.wait(&mut ready).unwrap();
+        }
+    });
+
+    let info = rx
+        .recv_timeout(Duration::from_secs(3))
+        .expect("Watchdog should have reported the stalled thread");
+
+    assert_eq!(info.source, DeadlockSource::Watchdog);
+    assert!(
+        !info.stalled_threads.is_empty(),
+        "Expected at least one stalled thread to be reported"
+    );
+    assert!(
+        info.stalled_threads
+            .iter()
+            .all(|stall| stall.blocked_ms > 0),
+        "Stalled threads should report a non-zero blocked duration"
+    );
+}